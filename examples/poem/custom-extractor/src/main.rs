@@ -1,14 +1,14 @@
 use poem::{
-    get, handler, http::StatusCode, listener::TcpListener, Error, FromRequest, Request,
-    RequestBody, Result, Route, Server,
+    get, handler, http::StatusCode, listener::TcpListener, Error, FromRequestParts, Request,
+    Result, Route, Server,
 };
 
 struct Token(String);
 
 // Implements a token extractor
 #[poem::async_trait]
-impl<'a> FromRequest<'a> for Token {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for Token {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         let token = req
             .headers()
             .get("MyToken")
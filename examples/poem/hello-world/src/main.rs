@@ -14,7 +14,7 @@ async fn main() -> Result<(), std::io::Error> {
     }
     tracing_subscriber::fmt::init();
 
-    let app = Route::new().at("/hello/:name", get(hello)).with(Tracing);
+    let app = Route::new().at("/hello/:name", get(hello)).with(Tracing::new());
     Server::new(TcpListener::bind("127.0.0.1:3000"))
         .name("hello-world")
         .run(app)
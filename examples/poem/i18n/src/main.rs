@@ -36,7 +36,7 @@ async fn main() -> Result<(), std::io::Error> {
     let app = Route::new()
         .at("/", get(index))
         .at("/welcome/:name", get(welcome))
-        .with(Tracing)
+        .with(Tracing::new())
         .data(resources);
     Server::new(TcpListener::bind("127.0.0.1:3000"))
         .name("hello-world")
@@ -64,7 +64,12 @@ fn generate_handler(internal: bool, input: TokenStream) -> Result<TokenStream> {
             let id = quote::format_ident!("p{}", idx);
             args.push(id.clone());
             extractors.push(quote! {
-                let #id = <#ty as #crate_name::FromRequest>::from_request(&req, &mut body).await?;
+                let #id = match <#ty as #crate_name::FromRequest>::from_request(&req, &mut body).await {
+                    ::std::result::Result::Ok(value) => value,
+                    ::std::result::Result::Err(err) => {
+                        return #crate_name::error::RejectionHandler::reject(&req, err);
+                    }
+                };
             });
         }
     }
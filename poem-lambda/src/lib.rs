@@ -11,11 +11,11 @@ use std::{io::ErrorKind, ops::Deref, sync::Arc};
 
 pub use lambda_http::lambda_runtime::Error;
 use lambda_http::{handler, lambda_runtime, Body as LambdaBody, Request as LambdaRequest};
-use poem::{Body, Endpoint, EndpointExt, FromRequest, IntoEndpoint, Request, RequestBody, Result};
+use poem::{Body, Endpoint, EndpointExt, FromRequestParts, IntoEndpoint, Request, Result};
 
 /// The Lambda function execution context.
 ///
-/// It implements [`poem::FromRequest`], so it can be used as an extractor.
+/// It implements [`poem::FromRequestParts`], so it can be used as an extractor.
 ///
 /// # Example
 ///
@@ -113,8 +113,8 @@ fn from_lambda_request(req: LambdaRequest) -> Request {
 }
 
 #[poem::async_trait]
-impl<'a> FromRequest<'a> for &'a Context {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a Context {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         let ctx = match req.extensions().get::<Context>() {
             Some(ctx) => ctx,
             None => panic!("Lambda runtime is required."),
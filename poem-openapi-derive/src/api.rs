@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use darling::{util::SpannedValue, FromMeta};
 use indexmap::IndexMap;
 use proc_macro2::{Ident, TokenStream};
@@ -8,11 +10,12 @@ use syn::{
 };
 
 use crate::{
-    common_args::{APIMethod, DefaultValue, ExternalDocument},
+    common_args::{APIMethod, DefaultValue, Extension, ExternalDocument, RenameRule},
     error::GeneratorResult,
     utils::{
-        convert_oai_path, get_crate_name, get_description, get_summary_and_description,
-        optional_literal, parse_oai_attrs, remove_description, remove_oai_attrs, RemoveLifetime,
+        convert_oai_path, get_crate_name, get_description, get_param_in,
+        get_summary_and_description, is_array_query_param, optional_literal, parse_oai_attrs,
+        remove_description, remove_oai_attrs, RemoveLifetime,
     },
     validators::Validators,
 };
@@ -25,6 +28,8 @@ struct APIArgs {
     prefix_path: Option<SpannedValue<String>>,
     #[darling(default, multiple, rename = "tag")]
     common_tags: Vec<Path>,
+    #[darling(default)]
+    operation_id_style: Option<RenameRule>,
 }
 
 #[derive(FromMeta)]
@@ -41,6 +46,12 @@ struct APIOperation {
     operation_id: Option<String>,
     #[darling(default)]
     external_docs: Option<ExternalDocument>,
+    #[darling(default)]
+    max_body_size: Option<usize>,
+    #[darling(default)]
+    sunset: Option<SpannedValue<String>>,
+    #[darling(default, multiple, rename = "extension")]
+    extensions: Vec<Extension>,
 }
 
 #[derive(FromMeta, Default)]
@@ -53,6 +64,8 @@ struct APIOperationParam {
     #[darling(default)]
     default: Option<DefaultValue>,
     #[darling(default)]
+    explode: Option<bool>,
+    #[darling(default)]
     validator: Option<Validators>,
 
     // for oauth
@@ -64,6 +77,7 @@ struct Context {
     add_routes: IndexMap<String, IndexMap<APIMethod, TokenStream>>,
     operations: IndexMap<String, Vec<TokenStream>>,
     register_items: Vec<TokenStream>,
+    operation_ids: HashSet<String>,
 }
 
 pub(crate) fn generate(
@@ -80,6 +94,7 @@ pub(crate) fn generate(
         add_routes: Default::default(),
         operations: Default::default(),
         register_items: Default::default(),
+        operation_ids: Default::default(),
     };
 
     for item in &mut item_impl.items {
@@ -101,6 +116,7 @@ pub(crate) fn generate(
         add_routes,
         operations,
         register_items,
+        ..
     } = ctx;
 
     let paths = {
@@ -169,7 +185,20 @@ fn generate_operation(
         transform,
         operation_id,
         external_docs,
+        max_body_size,
+        sunset,
+        extensions,
     } = args;
+    if let Some(sunset) = &sunset {
+        if httpdate::parse_http_date(sunset).is_err() {
+            return Err(Error::new(
+                sunset.span(),
+                "The `sunset` attribute must be an HTTP-date, such as `Sat, 31 Dec 2022 23:59:59 GMT`.",
+            )
+            .into());
+        }
+    }
+    let deprecated = deprecated || sunset.is_some();
     let http_method = method.to_http_method();
     let fn_ident = &item_method.sig.ident;
     let (summary, description) = get_summary_and_description(&item_method.attrs)?;
@@ -212,7 +241,9 @@ fn generate_operation(
     let mut parse_args = Vec::new();
     let mut use_args = Vec::new();
     let mut request_meta = Vec::new();
+    let mut validation_responses = Vec::new();
     let mut params_meta = Vec::new();
+    let mut param_names = HashSet::new();
     let mut security = Vec::new();
 
     for i in 1..item_method.sig.inputs.len() {
@@ -245,6 +276,16 @@ fn generate_operation(
             .unwrap_or_else(|| arg_ident.unraw().to_string());
         use_args.push(pname.clone());
 
+        if let Some(param_in) = get_param_in(&arg_ty) {
+            if !param_names.insert((param_name.clone(), param_in)) {
+                return Err(Error::new_spanned(
+                    &arg_ident,
+                    format!("duplicate parameter `{}` in {}", param_name, param_in),
+                )
+                .into());
+            }
+        }
+
         // register
         ctx.register_items.push(quote! {
             <#arg_ty as #crate_name::ApiExtractor>::register(registry);
@@ -270,6 +311,14 @@ fn generate_operation(
             }
             None => quote!(::std::option::Option::None),
         };
+        if operation_param.explode.is_some() && !is_array_query_param(&arg_ty) {
+            return Err(Error::new_spanned(
+                &arg_ident,
+                "The `explode` attribute can only be used with array query parameters.",
+            )
+            .into());
+        }
+        let explode = operation_param.explode.unwrap_or(true);
 
         // validator
         let validator = operation_param.validator.clone().unwrap_or_default();
@@ -289,6 +338,7 @@ fn generate_operation(
             let mut param_opts = #crate_name::ExtractParamOptions {
                 name: #param_name,
                 default_value: #default_value,
+                explode: #explode,
             };
 
             let #pname = match <#arg_ty as #crate_name::ApiExtractor>::from_request(&request, &mut body, param_opts).await {
@@ -324,7 +374,13 @@ fn generate_operation(
                     description: #param_desc,
                     required: <#arg_ty as #crate_name::ApiExtractor>::PARAM_IS_REQUIRED && !#has_default,
                     deprecated: #deprecated,
+                    explode: #explode,
                 };
+                // The duplicates are rejected at compile time, unless the type of
+                // the parameter is an alias.
+                if ::std::iter::Iterator::any(&mut params.iter(), |param: &#crate_name::registry::MetaOperationParam| param.name == meta_param.name && param.in_type == meta_param.in_type) {
+                    ::std::panic!("duplicate parameter `{}` in {:?}", meta_param.name, meta_param.in_type);
+                }
                 params.push(meta_param);
             }
         });
@@ -335,6 +391,11 @@ fn generate_operation(
                 request = <#arg_ty as #crate_name::ApiExtractor>::request_meta();
             }
         });
+        validation_responses.push(quote! {
+            if <#arg_ty as #crate_name::ApiExtractor>::TYPE == #crate_name::ApiExtractorType::RequestObject {
+                #crate_name::__private::add_validation_error_response::<#arg_ty>(&mut responses);
+            }
+        });
 
         // security meta
         let scopes = &operation_param.scopes;
@@ -350,12 +411,36 @@ fn generate_operation(
     ctx.register_items
         .push(quote!(<#res_ty as #crate_name::ApiResponse>::register(registry);));
 
+    // Deprecated operations announce it with the `Deprecation` header, and the
+    // `Sunset` header if the date of the removal is known.
+    // The error responses have the headers too, so the endpoint is converted
+    // to always return a response.
+    let deprecation_headers = deprecated.then(|| {
+        let sunset = sunset
+            .as_deref()
+            .map(|sunset| quote!(.overriding("sunset", #sunset)));
+        quote! {
+            let ep = #crate_name::__private::poem::EndpointExt::with(
+                #crate_name::__private::poem::EndpointExt::to_response(ep),
+                #crate_name::__private::poem::middleware::SetHeader::new()
+                    .overriding("deprecation", "true")
+                    #sunset,
+            );
+        }
+    });
+
     let transform = transform.map(|transform| {
         quote! {
             let ep = #transform(ep);
         }
     });
 
+    let max_body_size = match max_body_size {
+        Some(max_body_size) => quote!(::std::option::Option::Some(#max_body_size)),
+        None => quote!(::std::option::Option::None),
+    };
+    let extensions = Extension::to_token_stream(&extensions, crate_name);
+
     if ctx.add_routes.entry(new_path).or_default().insert(*method, quote! {
         method(#crate_name::__private::poem::http::Method::#http_method, {
             let api_obj = ::std::clone::Clone::clone(&api_obj);
@@ -363,12 +448,14 @@ fn generate_operation(
                 let api_obj = ::std::clone::Clone::clone(&api_obj);
                 async move {
                     let (request, mut body) = request.split();
+                    #crate_name::__private::check_body_size(&request, &mut body, #max_body_size).await?;
                     #(#parse_args)*
                     let res = api_obj.#fn_ident(#(#use_args),*).await;
                     let res = #crate_name::__private::poem::error::IntoResult::into_result(res);
                     ::std::result::Result::map(res, #crate_name::__private::poem::IntoResponse::into_response)
                 }
             });
+            #deprecation_headers
             #transform
             ep
         })
@@ -382,6 +469,20 @@ fn generate_operation(
             .push(quote!(#crate_name::Tags::register(&#tag, registry);));
         tag_names.push(quote!(#crate_name::Tags::name(&#tag)));
     }
+    let operation_id = operation_id.or_else(|| {
+        api_args
+            .operation_id_style
+            .map(|rule| rule.rename(fn_ident.unraw().to_string()))
+    });
+    if let Some(operation_id) = &operation_id {
+        if !ctx.operation_ids.insert(operation_id.clone()) {
+            return Err(Error::new_spanned(
+                fn_ident,
+                format!("duplicate operation id `{}`", operation_id),
+            )
+            .into());
+        }
+    }
     let operation_id = optional_literal(&operation_id);
     let external_docs = match external_docs {
         Some(external_docs) => {
@@ -408,7 +509,11 @@ fn generate_operation(
                 #(#request_meta)*
                 request
             },
-            responses: <#res_ty as #crate_name::ApiResponse>::meta(),
+            responses: {
+                let mut responses = <#res_ty as #crate_name::ApiResponse>::meta();
+                #(#validation_responses)*
+                responses
+            },
             deprecated: #deprecated,
             security: {
                 let mut security = ::std::vec![];
@@ -416,6 +521,8 @@ fn generate_operation(
                 security
             },
             operation_id: #operation_id,
+            max_body_size: #max_body_size,
+            extensions: #extensions,
         }
     });
 
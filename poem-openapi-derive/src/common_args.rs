@@ -1,8 +1,14 @@
+use std::collections::HashSet;
+
 use darling::FromMeta;
 use inflector::Inflector;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Lit, Meta, NestedMeta, Path};
+use syn::{
+    ext::IdentExt, Error, GenericArgument, Lit, Meta, NestedMeta, Path, PathArguments, Type,
+};
+
+use crate::{error::GeneratorResult, utils::optional_literal};
 
 #[derive(Debug, Copy, Clone, FromMeta)]
 pub(crate) enum RenameRule {
@@ -18,6 +24,12 @@ pub(crate) enum RenameRule {
     Snake,
     #[darling(rename = "SCREAMING_SNAKE_CASE")]
     ScreamingSnake,
+    #[darling(rename = "kebab-case")]
+    Kebab,
+    #[darling(rename = "SCREAMING-KEBAB-CASE")]
+    ScreamingKebab,
+    #[darling(rename = "Train-Case")]
+    Train,
 }
 
 impl RenameRule {
@@ -29,10 +41,33 @@ impl RenameRule {
             Self::Camel => name.as_ref().to_camel_case(),
             Self::Snake => name.as_ref().to_snake_case(),
             Self::ScreamingSnake => name.as_ref().to_screaming_snake_case(),
+            Self::Kebab => name.as_ref().to_kebab_case(),
+            Self::ScreamingKebab => name.as_ref().to_kebab_case().to_uppercase(),
+            Self::Train => name.as_ref().to_train_case(),
         }
     }
 }
 
+impl RenameRule {
+    /// Returns a `fn(&str) -> String` that applies the rule at runtime.
+    pub(crate) fn to_fn_tokens(self, crate_name: &TokenStream) -> TokenStream {
+        let method = match self {
+            Self::Lower => return quote!(|name: &str| <str>::to_lowercase(name)),
+            Self::Upper => return quote!(|name: &str| <str>::to_uppercase(name)),
+            Self::Pascal => quote!(to_pascal_case),
+            Self::Camel => quote!(to_camel_case),
+            Self::Snake => quote!(to_snake_case),
+            Self::ScreamingSnake => quote!(to_screaming_snake_case),
+            Self::Kebab => quote!(to_kebab_case),
+            Self::ScreamingKebab => {
+                return quote!(|name: &str| <str>::to_uppercase(&#crate_name::__private::inflector::Inflector::to_kebab_case(name)))
+            }
+            Self::Train => quote!(to_train_case),
+        };
+        quote!(|name: &str| #crate_name::__private::inflector::Inflector::#method(name))
+    }
+}
+
 pub(crate) trait RenameRuleExt {
     fn rename(&self, name: impl AsRef<str>) -> String;
 }
@@ -46,24 +81,133 @@ impl RenameRuleExt for Option<RenameRule> {
     }
 }
 
+/// An example, specified with `example = "path"` or with
+/// `example(name = "...", value = "...", summary = "...")`.
+pub(crate) struct Example {
+    pub(crate) name: Option<String>,
+    pub(crate) summary: Option<String>,
+    pub(crate) value: Path,
+}
+
+impl FromMeta for Example {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        Ok(Example {
+            name: None,
+            summary: None,
+            value: syn::parse_str(value)?,
+        })
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct NamedExample {
+            name: String,
+            #[darling(default)]
+            summary: Option<String>,
+            value: Path,
+        }
+
+        let NamedExample {
+            name,
+            summary,
+            value,
+        } = NamedExample::from_list(items)?;
+        Ok(Example {
+            name: Some(name),
+            summary,
+            value,
+        })
+    }
+}
+
+impl Example {
+    /// Generates an `Option<MetaExample>`, an unnamed example is named
+    /// `default`, and it is `None` if the value is not serialized.
+    pub(crate) fn to_meta(&self, crate_name: &TokenStream) -> TokenStream {
+        let name = self.name.as_deref().unwrap_or("default");
+        let summary = optional_literal(&self.summary);
+        let value = &self.value;
+        quote! {
+            ::std::option::Option::map(#crate_name::types::ToJSON::to_json(&#value()), |value| {
+                #crate_name::registry::MetaExample {
+                    name: #name,
+                    summary: #summary,
+                    value,
+                }
+            })
+        }
+    }
+
+    /// Returns an error if two examples have the same name.
+    pub(crate) fn check_names<'a>(
+        examples: impl IntoIterator<Item = &'a Example>,
+    ) -> GeneratorResult<()> {
+        let mut names = HashSet::new();
+        for example in examples {
+            let name = example.name.as_deref().unwrap_or("default");
+            if !names.insert(name) {
+                return Err(Error::new_spanned(
+                    &example.value,
+                    format!("Duplicate example name `{}`.", name),
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+}
+
 #[derive(FromMeta)]
 pub(crate) struct ConcreteType {
-    pub(crate) name: String,
+    #[darling(default)]
+    pub(crate) name: Option<String>,
     pub(crate) params: PathList,
     #[darling(default)]
     pub(crate) example: Option<Path>,
 }
 
+impl ConcreteType {
+    /// Returns the explicit name, or `base` followed by the names of the
+    /// params, e.g. `Page<Vec<User>>` is named `PageVecUser`.
+    pub(crate) fn type_name(&self, base: &str) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => {
+                let mut name = base.to_string();
+                for path in &self.params.0 {
+                    push_path_name(&mut name, path);
+                }
+                name
+            }
+        }
+    }
+}
+
+fn push_path_name(name: &mut String, path: &Path) {
+    if let Some(segment) = path.segments.last() {
+        name.push_str(&segment.ident.unraw().to_string().to_pascal_case());
+        if let PathArguments::AngleBracketed(args) = &segment.arguments {
+            for arg in &args.args {
+                if let GenericArgument::Type(Type::Path(ty)) = arg {
+                    push_path_name(name, &ty.path);
+                }
+            }
+        }
+    }
+}
+
 pub(crate) struct PathList(pub(crate) Vec<Path>);
 
 impl FromMeta for PathList {
     fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
         let mut res = Vec::new();
         for item in items {
-            if let NestedMeta::Meta(Meta::Path(p)) = item {
-                res.push(p.clone());
-            } else {
-                return Err(darling::Error::custom("Invalid path list"));
+            match item {
+                NestedMeta::Meta(Meta::Path(p)) => res.push(p.clone()),
+                NestedMeta::Lit(Lit::Str(s)) => res.push(s.parse().map_err(|err| {
+                    darling::Error::custom(format!("Invalid path: {}", err)).with_span(s)
+                })?),
+                _ => return Err(darling::Error::custom("Invalid path list")),
             }
         }
         Ok(PathList(res))
@@ -168,3 +312,49 @@ impl ExternalDocument {
         }
     }
 }
+
+/// A vendor extension, specified with `extension(name = "x-...", value =
+/// "...")`, the value is a JSON literal.
+pub(crate) struct Extension {
+    name: String,
+    value: String,
+}
+
+impl FromMeta for Extension {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct Inner {
+            name: String,
+            value: String,
+        }
+
+        let Inner { name, value } = Inner::from_list(items)?;
+        if !name.starts_with("x-") {
+            return Err(darling::Error::custom(
+                "The name of an extension must start with `x-`.",
+            ));
+        }
+        if let Err(err) = serde_json::from_str::<serde_json::Value>(&value) {
+            return Err(darling::Error::custom(format!(
+                "Invalid JSON in the value of the extension: {}",
+                err
+            )));
+        }
+        Ok(Extension { name, value })
+    }
+}
+
+impl Extension {
+    /// Generates a `Vec<(&'static str, Value)>` of the extensions.
+    pub(crate) fn to_token_stream(
+        extensions: &[Extension],
+        crate_name: &TokenStream,
+    ) -> TokenStream {
+        let items = extensions.iter().map(|Extension { name, value }| {
+            quote! {
+                (#name, #crate_name::__private::serde_json::from_str(#value).unwrap())
+            }
+        });
+        quote!(::std::vec![#(#items),*])
+    }
+}
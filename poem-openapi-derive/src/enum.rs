@@ -5,7 +5,7 @@ use darling::{
 };
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{ext::IdentExt, Attribute, DeriveInput, Error, Path};
+use syn::{ext::IdentExt, Attribute, DeriveInput, Error, Meta, NestedMeta, Path};
 
 use crate::{
     common_args::{ExternalDocument, RenameRule, RenameRuleExt},
@@ -17,6 +17,7 @@ use crate::{
 #[darling(attributes(oai), forward_attrs(doc))]
 struct EnumItem {
     ident: Ident,
+    attrs: Vec<Attribute>,
     fields: Fields<Ignored>,
 
     #[darling(default)]
@@ -24,7 +25,7 @@ struct EnumItem {
 }
 
 #[derive(FromDeriveInput)]
-#[darling(attributes(oai), forward_attrs(doc))]
+#[darling(attributes(oai), forward_attrs(doc, repr))]
 struct EnumArgs {
     ident: Ident,
     attrs: Vec<Attribute>,
@@ -42,6 +43,10 @@ struct EnumArgs {
     deprecated: bool,
     #[darling(default)]
     external_docs: Option<ExternalDocument>,
+    #[darling(default)]
+    inline: bool,
+    #[darling(default)]
+    integer: bool,
 }
 
 pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
@@ -55,9 +60,25 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         _ => return Err(Error::new_spanned(ident, "Enum can only be applied to an enum.").into()),
     };
 
+    let int_format = if args.integer {
+        match get_int_repr_format(&args.attrs)? {
+            Some(format) => Some(format),
+            None => {
+                return Err(Error::new_spanned(
+                    ident,
+                    "The `integer` attribute requires an integer representation such as `#[repr(i32)]`.",
+                )
+                .into())
+            }
+        }
+    } else {
+        None
+    };
     let mut enum_items = Vec::new();
     let mut ident_to_item = Vec::new();
     let mut item_to_ident = Vec::new();
+    let mut int_to_ident = Vec::new();
+    let mut item_descriptions = Vec::new();
 
     for variant in e {
         if !variant.fields.is_empty() {
@@ -72,11 +93,40 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         }
 
         let item_ident = &variant.ident;
+        let item_description = get_description(&variant.attrs)?;
+
+        if int_format.is_some() {
+            if variant.rename.is_some() {
+                return Err(Error::new_spanned(
+                    item_ident,
+                    "The items of an integer enum cannot be renamed.",
+                )
+                .into());
+            }
+
+            if let Some(item_description) = item_description {
+                item_descriptions.push((item_ident.unraw().to_string(), item_description));
+            }
+
+            enum_items
+                .push(quote!(#crate_name::types::ToJSON::to_json(&#ident::#item_ident).unwrap()));
+            ident_to_item.push(quote!(#ident::#item_ident => #ident::#item_ident as i64));
+            int_to_ident.push(quote! {
+                if value == #ident::#item_ident as i64 {
+                    return ::std::result::Result::Ok(#ident::#item_ident);
+                }
+            });
+            continue;
+        }
+
         let oai_item_name = variant
             .rename
             .clone()
             .unwrap_or_else(|| args.rename_all.rename(variant.ident.unraw().to_string()));
 
+        if let Some(item_description) = item_description {
+            item_descriptions.push((oai_item_name.clone(), item_description));
+        }
         enum_items.push(quote!(#crate_name::types::ToJSON::to_json(&#ident::#item_ident).unwrap()));
         ident_to_item.push(quote!(#ident::#item_ident => #oai_item_name));
         item_to_ident
@@ -117,7 +167,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     } else {
         None
     };
-    let description = optional_literal(&description);
+    let description = optional_literal(&merge_item_descriptions(description, &item_descriptions));
     let deprecated = args.deprecated;
     let external_docs = match &args.external_docs {
         Some(external_docs) => {
@@ -127,6 +177,82 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         None => quote!(::std::option::Option::None),
     };
 
+    let base_schema = match int_format {
+        Some(format) => {
+            quote!(#crate_name::registry::MetaSchema::new_with_format("integer", #format))
+        }
+        None => quote!(#crate_name::registry::MetaSchema::new("string")),
+    };
+    let (parse_json, parse_parameter, to_json) = if int_format.is_some() {
+        (
+            quote! {
+                match value.as_i64() {
+                    ::std::option::Option::Some(value) => {
+                        #(#int_to_ident)*
+                        ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(#crate_name::__private::serde_json::Value::from(value)))
+                    }
+                    ::std::option::Option::None => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
+                }
+            },
+            quote! {
+                let value: i64 = value.parse().map_err(|_| #crate_name::types::ParseError::custom("Expect a valid enumeration value."))?;
+                #(#int_to_ident)*
+                ::std::result::Result::Err(#crate_name::types::ParseError::custom("Expect a valid enumeration value."))
+            },
+            quote! {
+                let value = match self {
+                    #(#ident_to_item),*
+                };
+                ::std::option::Option::Some(#crate_name::__private::serde_json::Value::from(value))
+            },
+        )
+    } else {
+        (
+            quote! {
+                match &value {
+                    #crate_name::__private::serde_json::Value::String(item) => match item.as_str() {
+                        #(#item_to_ident,)*
+                        _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
+                    }
+                    _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
+                }
+            },
+            quote! {
+                match value {
+                    #(#item_to_ident,)*
+                    _ => ::std::result::Result::Err(#crate_name::types::ParseError::custom("Expect a valid enumeration value.")),
+                }
+            },
+            quote! {
+                let name = match self {
+                    #(#ident_to_item),*
+                };
+                ::std::option::Option::Some(#crate_name::__private::serde_json::Value::String(::std::string::ToString::to_string(name)))
+            },
+        )
+    };
+
+    let meta = quote! {
+        #crate_name::registry::MetaSchema {
+            description: #description,
+            external_docs: #external_docs,
+            deprecated: #deprecated,
+            enum_items: ::std::vec![#(#enum_items),*],
+            ..#base_schema
+        }
+    };
+    let (fn_schema_ref, fn_register) = if args.inline {
+        (
+            quote!(#crate_name::registry::MetaSchemaRef::Inline(::std::boxed::Box::new(#meta))),
+            quote!(),
+        )
+    } else {
+        (
+            quote!(#crate_name::registry::MetaSchemaRef::Reference(#oai_typename)),
+            quote!(registry.create_schema::<Self, _>(#oai_typename, |registry| #meta);),
+        )
+    };
+
     let expanded = quote! {
         impl #crate_name::types::Type for #ident {
             const IS_REQUIRED: bool = true;
@@ -144,17 +270,11 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             }
 
             fn schema_ref() -> #crate_name::registry::MetaSchemaRef {
-                #crate_name::registry::MetaSchemaRef::Reference(#oai_typename)
+                #fn_schema_ref
             }
 
             fn register(registry: &mut #crate_name::registry::Registry) {
-                registry.create_schema::<Self, _>(#oai_typename, |registry| #crate_name::registry::MetaSchema {
-                    description: #description,
-                    external_docs: #external_docs,
-                    deprecated: #deprecated,
-                    enum_items: ::std::vec![#(#enum_items),*],
-                    ..#crate_name::registry::MetaSchema::new("string")
-                });
+                #fn_register
             }
 
             fn raw_element_iter<'a>(&'a self) -> ::std::boxed::Box<dyn ::std::iter::Iterator<Item = &'a Self::RawElementValueType> + 'a> {
@@ -165,31 +285,19 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         impl #crate_name::types::ParseFromJSON for #ident {
             fn parse_from_json(value: ::std::option::Option<#crate_name::__private::serde_json::Value>) -> #crate_name::types::ParseResult<Self> {
                 let value = value.unwrap_or_default();
-                match &value {
-                    #crate_name::__private::serde_json::Value::String(item) => match item.as_str() {
-                        #(#item_to_ident,)*
-                        _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
-                    }
-                    _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
-                }
+                #parse_json
             }
         }
 
         impl #crate_name::types::ParseFromParameter for #ident {
             fn parse_from_parameter(value: &str) -> #crate_name::types::ParseResult<Self> {
-                match value {
-                    #(#item_to_ident,)*
-                    _ => ::std::result::Result::Err(#crate_name::types::ParseError::custom("Expect a valid enumeration value.")),
-                }
+                #parse_parameter
             }
         }
 
         impl #crate_name::types::ToJSON for #ident {
             fn to_json(&self) -> ::std::option::Option<#crate_name::__private::serde_json::Value> {
-                let name = match self {
-                    #(#ident_to_item),*
-                };
-                ::std::option::Option::Some(#crate_name::__private::serde_json::Value::String(::std::string::ToString::to_string(name)))
+                #to_json
             }
         }
 
@@ -212,3 +320,62 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
 
     Ok(expanded)
 }
+
+/// Appends the descriptions of the items to the enum description as a
+/// markdown list.
+fn merge_item_descriptions(
+    description: Option<String>,
+    item_descriptions: &[(String, String)],
+) -> Option<String> {
+    if item_descriptions.is_empty() {
+        return description;
+    }
+
+    let mut full_docs = description.unwrap_or_default();
+    if !full_docs.is_empty() {
+        full_docs.push_str("\n\n");
+    }
+    for (idx, (name, item_description)) in item_descriptions.iter().enumerate() {
+        if idx > 0 {
+            full_docs.push('\n');
+        }
+        full_docs.push_str(&format!("- `{}`:", name));
+        for (line_idx, line) in item_description.lines().enumerate() {
+            if line_idx > 0 {
+                full_docs.push('\n');
+            }
+            if !line.is_empty() {
+                full_docs.push_str(if line_idx > 0 { "  " } else { " " });
+                full_docs.push_str(line);
+            }
+        }
+    }
+    Some(full_docs)
+}
+
+/// Returns the format of the integer schema if the enum has an integer
+/// `#[repr(...)]`.
+fn get_int_repr_format(attrs: &[Attribute]) -> GeneratorResult<Option<&'static str>> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Meta::List(list) = attr.parse_meta()? {
+            for item in &list.nested {
+                if let NestedMeta::Meta(Meta::Path(path)) = item {
+                    let format = match path.get_ident().map(ToString::to_string).as_deref() {
+                        Some("i8" | "i16" | "i32" | "u8" | "u16") => "int32",
+                        Some("i64" | "u32" | "isize") => "int64",
+                        Some("u64" | "usize" | "i128" | "u128") => {
+                            return Err(Error::new_spanned(
+                                path,
+                                "The integer representation must fit in `i64`.",
+                            )
+                            .into());
+                        }
+                        _ => continue,
+                    };
+                    return Ok(Some(format));
+                }
+            }
+        }
+    }
+    Ok(None)
+}
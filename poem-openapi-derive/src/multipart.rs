@@ -270,6 +270,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     content: ::std::vec![#crate_name::registry::MetaMediaType {
                         content_type: <Self as #crate_name::payload::Payload>::CONTENT_TYPE,
                         schema: <Self as #crate_name::payload::Payload>::schema_ref(),
+                        examples: ::std::vec![],
                     }],
                     required: <Self as #crate_name::payload::ParsePayload>::IS_REQUIRED,
                 })
@@ -85,7 +85,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         Some(quote! {
             impl #crate_name::types::ParseFromJSON for #ident {
                 fn parse_from_json(value: ::std::option::Option<#crate_name::__private::serde_json::Value>) -> #crate_name::types::ParseResult<Self> {
-                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromJSON>::parse_from_json(value), poem_openapi::types::ParseError::propagate)?;
+                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromJSON>::parse_from_json(value), #crate_name::types::ParseError::propagate)?;
                     ::std::result::Result::Ok(#ident(value))
                 }
             }
@@ -98,14 +98,14 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         Some(quote! {
             impl #crate_name::types::ParseFromParameter for #ident {
                 fn parse_from_parameter(value: &str) -> #crate_name::types::ParseResult<Self> {
-                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromParameter>::parse_from_parameter(value), poem_openapi::types::ParseError::propagate)?;
+                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromParameter>::parse_from_parameter(value), #crate_name::types::ParseError::propagate)?;
                     ::std::result::Result::Ok(#ident(value))
                 }
 
                 fn parse_from_parameters<I: ::std::iter::IntoIterator<Item = A>, A: ::std::convert::AsRef<str>>(
                     iter: I,
                 ) -> #crate_name::types::ParseResult<Self> {
-                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromParameter>::parse_from_parameters(iter), poem_openapi::types::ParseError::propagate)?;
+                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromParameter>::parse_from_parameters(iter), #crate_name::types::ParseError::propagate)?;
                     ::std::result::Result::Ok(#ident(value))
                 }
             }
@@ -119,12 +119,12 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             #[#crate_name::__private::poem::async_trait]
             impl #crate_name::types::ParseFromMultipartField for #ident {
                 async fn parse_from_multipart(field: ::std::option::Option<#crate_name::__private::poem::web::Field>) -> #crate_name::types::ParseResult<Self> {
-                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromMultipartField>::parse_from_multipart(field).await, poem_openapi::types::ParseError::propagate)?;
+                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromMultipartField>::parse_from_multipart(field).await, #crate_name::types::ParseError::propagate)?;
                     ::std::result::Result::Ok(#ident(value))
                 }
 
                 async fn parse_from_repeated_field(self, field: #crate_name::__private::poem::web::Field) -> #crate_name::types::ParseResult<Self> {
-                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromMultipartField>::parse_from_repeated_field(self.0, field).await, poem_openapi::types::ParseError::propagate)?;
+                    let value = ::std::result::Result::map_err(<#inner_ty as #crate_name::types::ParseFromMultipartField>::parse_from_repeated_field(self.0, field).await, #crate_name::types::ParseError::propagate)?;
                     ::std::result::Result::Ok(#ident(value))
                 }
             }
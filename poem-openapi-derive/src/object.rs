@@ -1,47 +1,90 @@
+use std::collections::HashSet;
+
 use darling::{
-    ast::Data,
+    ast::{Data, Style},
     util::{Ignored, SpannedValue},
-    FromDeriveInput, FromField,
+    FromDeriveInput, FromField, FromMeta,
 };
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::{ext::IdentExt, Attribute, DeriveInput, Error, Generics, Path, Type};
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, spanned::Spanned, Attribute, DeriveInput, Error, Generics, Lit, Meta,
+    NestedMeta, Path, Type, Visibility,
+};
 
 use crate::{
-    common_args::{ConcreteType, DefaultValue, ExternalDocument, RenameRule, RenameRuleExt},
+    common_args::{
+        ConcreteType, DefaultValue, Example, Extension, ExternalDocument, RenameRule, RenameRuleExt,
+    },
     error::GeneratorResult,
-    utils::{get_crate_name, get_description, optional_literal},
+    utils::{
+        get_crate_name, get_description, get_option_inner_type, is_map_type, is_optional_type,
+        optional_literal,
+    },
     validators::Validators,
 };
 
 #[derive(FromField)]
-#[darling(attributes(oai), forward_attrs(doc))]
+#[darling(attributes(oai), forward_attrs(doc, serde))]
 struct ObjectField {
     ident: Option<Ident>,
     ty: Type,
+    vis: Visibility,
     attrs: Vec<Attribute>,
 
     #[darling(default)]
     skip: bool,
+    #[darling(default)]
+    skip_serializing: bool,
+    #[darling(default)]
+    skip_deserializing: bool,
 
     #[darling(default)]
     rename: Option<String>,
     #[darling(default)]
     default: Option<DefaultValue>,
     #[darling(default)]
+    default_with: Option<Path>,
+    #[darling(default)]
     write_only: bool,
     #[darling(default)]
     read_only: bool,
     #[darling(default)]
+    read_only_lenient: bool,
+    #[darling(default)]
     validator: Option<Validators>,
     #[darling(default)]
     flatten: bool,
+    #[darling(default)]
+    nullable: bool,
+    #[darling(default)]
+    deprecated: bool,
+    #[darling(default)]
+    coerce: bool,
+    #[darling(default)]
+    const_value: Option<Lit>,
+    #[darling(default)]
+    title: Option<String>,
+    #[darling(default)]
+    format: Option<String>,
+    #[darling(default)]
+    schema_ref: Option<SpannedValue<String>>,
+    #[darling(default)]
+    required_if: Option<RequiredIf>,
+}
+
+/// The condition of `#[oai(required_if(field = "...", equals = "..."))]`.
+#[derive(FromMeta)]
+struct RequiredIf {
+    field: String,
+    equals: String,
 }
 
 #[derive(FromDeriveInput)]
 #[darling(attributes(oai), forward_attrs(doc))]
 struct ObjectArgs {
     ident: Ident,
+    vis: Visibility,
     generics: Generics,
     attrs: Vec<Attribute>,
     data: Data<Ignored, ObjectField>,
@@ -62,16 +105,92 @@ struct ObjectArgs {
     read_only_all: bool,
     #[darling(default)]
     write_only_all: bool,
+    #[darling(default, multiple, rename = "example")]
+    examples: Vec<SpannedValue<Example>>,
+    #[darling(default)]
+    example_value: Option<SpannedValue<String>>,
     #[darling(default)]
-    example: Option<SpannedValue<Path>>,
+    example_from_default: SpannedValue<bool>,
     #[darling(default)]
     deny_unknown_fields: bool,
     #[darling(default)]
     external_docs: Option<ExternalDocument>,
+    #[darling(default)]
+    serde_compat: bool,
+    #[darling(default)]
+    title: Option<String>,
+    #[darling(default)]
+    namespace: Option<String>,
+    #[darling(default)]
+    patch: Option<Ident>,
+    #[darling(default)]
+    flatten_all_of: bool,
+    #[darling(default)]
+    flatten_rename_all: bool,
+    #[darling(default, multiple, rename = "extension")]
+    extensions: Vec<Extension>,
 }
 
-pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
-    let args: ObjectArgs = ObjectArgs::from_derive_input(&args)?;
+/// The subset of serde field attributes understood with `#[oai(serde_compat)]`.
+#[derive(Default)]
+struct SerdeFieldArgs {
+    skip: bool,
+    skip_serializing: bool,
+    skip_deserializing: bool,
+    rename: Option<String>,
+    default: Option<DefaultValue>,
+}
+
+impl SerdeFieldArgs {
+    fn parse(attrs: &[Attribute]) -> GeneratorResult<Self> {
+        let mut args = SerdeFieldArgs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                _ => continue,
+            };
+
+            for item in &list.nested {
+                match item {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                        args.skip = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip_serializing") => {
+                        args.skip_serializing = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip_deserializing") => {
+                        args.skip_deserializing = true;
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default") => {
+                        args.default = Some(DefaultValue::from_word()?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                        if let Lit::Str(name) = &nv.lit {
+                            args.rename = Some(name.value());
+                        }
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("default") => {
+                        args.default = Some(DefaultValue::from_value(&nv.lit)?);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+pub(crate) fn generate(input: DeriveInput) -> GeneratorResult<TokenStream> {
+    generate_object(input, &[])
+}
+
+/// Generates the implementation of an object, an explicit `null` is rejected
+/// for the fields in `deny_null`, which is used by the companion object of
+/// `#[oai(patch)]`.
+fn generate_object(input: DeriveInput, deny_null: &[Ident]) -> GeneratorResult<TokenStream> {
+    let args: ObjectArgs = ObjectArgs::from_derive_input(&input)?;
     let crate_name = get_crate_name(args.internal);
     let (impl_generics, ty_generics, where_clause) = args.generics.split_for_impl();
     let ident = &args.ident;
@@ -84,6 +203,13 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         }
     };
     let oai_typename = args.rename.clone().unwrap_or_else(|| ident.to_string());
+    let oai_typename = match &args.namespace {
+        Some(namespace) => format!("{}.{}", namespace, oai_typename),
+        None => oai_typename,
+    };
+    if s.style == Style::Tuple {
+        return generate_tuple(&input, &args, &s.fields, &oai_typename, &crate_name);
+    }
     let description = get_description(&args.attrs)?;
     let mut deserialize_fields = Vec::new();
     let mut serialize_fields = Vec::new();
@@ -91,6 +217,33 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     let mut fields = Vec::new();
     let mut meta_fields = Vec::new();
     let mut required_fields = Vec::new();
+    let mut all_of_fields = Vec::new();
+    let mut patch_fields = Vec::new();
+    let mut deserialize_map_fields = Vec::new();
+    let mut required_if_checkers = Vec::new();
+    let mut property_idents = Vec::new();
+    let mut additional_properties = None;
+    let mut partial_fields = Vec::new();
+    let mut default_with_fields = Vec::new();
+
+    let flatten_rename = match (args.flatten_rename_all, args.rename_all) {
+        (true, Some(rule)) => Some(rule.to_fn_tokens(&crate_name)),
+        (true, None) => {
+            return Err(Error::new_spanned(
+                ident,
+                "The `flatten_rename_all` attribute requires `rename_all`.",
+            )
+            .into());
+        }
+        (false, _) => None,
+    };
+    if flatten_rename.is_some() && !args.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            ident,
+            "The `flatten_rename_all` attribute cannot be used with generic objects.",
+        )
+        .into());
+    }
 
     if *args.inline && !args.concretes.is_empty() {
         return Err(Error::new(
@@ -100,21 +253,98 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         .into());
     }
 
-    if args.example.is_some() && !args.concretes.is_empty() {
+    if let (Some(example), false) = (args.examples.first(), args.concretes.is_empty()) {
         return Err(Error::new(
-            args.example.as_ref().unwrap().span(),
+            example.span(),
             "The example should be specified with the `concretes.example` attribute.",
         )
         .into());
     }
 
+    Example::check_names(args.examples.iter().map(|example| &**example))?;
+
+    if *args.example_from_default && (!args.examples.is_empty() || args.example_value.is_some()) {
+        return Err(Error::new(
+            args.example_from_default.span(),
+            "The `example_from_default` attribute cannot be used with `example` or `example_value`.",
+        )
+        .into());
+    }
+
+    if let Some(example_value) = &args.example_value {
+        if !args.examples.is_empty() {
+            return Err(Error::new(
+                example_value.span(),
+                "The `example` and `example_value` attributes cannot be used together.",
+            )
+            .into());
+        }
+
+        if !args.concretes.is_empty() {
+            return Err(Error::new(
+                example_value.span(),
+                "The example should be specified with the `concretes.example` attribute.",
+            )
+            .into());
+        }
+
+        if let Err(err) = serde_json::from_str::<serde_json::Value>(example_value) {
+            return Err(Error::new(
+                example_value.span(),
+                format!("Invalid JSON in `example_value`: {}", err),
+            )
+            .into());
+        }
+    }
+
+    // A flattened map captures the properties that are left after the other
+    // fields are parsed.
+    let map_fields = s
+        .fields
+        .iter()
+        .filter(|field| field.flatten && !field.skip && is_map_type(&field.ty))
+        .collect::<Vec<_>>();
+    if let [_, field, ..] = map_fields.as_slice() {
+        return Err(Error::new_spanned(
+            field.ident.as_ref().unwrap(),
+            "Only one map can be flattened.",
+        )
+        .into());
+    }
+    let has_map_field = !map_fields.is_empty();
+
     for field in &s.fields {
         let field_ident = field.ident.as_ref().unwrap();
         let field_ty = &field.ty;
-        let read_only = args.read_only_all || field.read_only;
-        let write_only = args.write_only_all || field.write_only;
+        let is_map_field = field.flatten && is_map_type(field_ty);
+        let flatten_name = field_ident.unraw().to_string();
+        let serde_args = if args.serde_compat {
+            SerdeFieldArgs::parse(&field.attrs)?
+        } else {
+            SerdeFieldArgs::default()
+        };
+        let default = field.default.as_ref().or(serde_args.default.as_ref());
+        // A field that is skipped in one direction is documented as
+        // `readOnly` or `writeOnly`, and the value sent by the client is
+        // ignored.
+        let skip_serializing = field.skip_serializing || serde_args.skip_serializing;
+        let skip_deserializing = field.skip_deserializing || serde_args.skip_deserializing;
+        let read_only_lenient = field.read_only_lenient || skip_deserializing;
+        let read_only = args.read_only_all || field.read_only || read_only_lenient;
+        let write_only = args.write_only_all || field.write_only || skip_serializing;
+        let compose_ref = args.flatten_all_of && !read_only && !write_only;
+        // The renamed properties are created once and kept in a static.
+        let flatten_renamer = flatten_rename.as_ref().filter(|_| !is_map_field).map(|rename| {
+            quote!({
+                #crate_name::__private::lazy_static::lazy_static! {
+                    static ref RENAMER: ::std::option::Option<#crate_name::__private::FlattenRenamer> =
+                        #crate_name::__private::FlattenRenamer::new::<#field_ty>(#compose_ref, #rename);
+                }
+                ::std::option::Option::as_ref(&*RENAMER)
+            })
+        });
 
-        if field.skip {
+        if field.skip || serde_args.skip || (skip_serializing && skip_deserializing) {
             deserialize_fields.push(quote! {
                 let #field_ident: #field_ty = ::std::default::Default::default();
             });
@@ -123,9 +353,28 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         }
 
         if read_only && write_only {
+            let message = if skip_serializing && !(field.write_only || args.write_only_all) {
+                "The `skip_serializing` attribute cannot be used with `read_only` or `read_only_all`."
+            } else if skip_deserializing && !(field.read_only || args.read_only_all) {
+                "The `skip_deserializing` attribute cannot be used with `write_only` or `write_only_all`."
+            } else {
+                "The `write_only` and `read_only` attributes cannot be enabled both."
+            };
+            return Err(Error::new_spanned(field_ident, message).into());
+        }
+
+        if let (Some(schema_ref), true) = (&field.schema_ref, field.flatten) {
+            return Err(Error::new(
+                schema_ref.span(),
+                "The `schema_ref` and `flatten` attributes cannot be used together.",
+            )
+            .into());
+        }
+
+        if default.is_some() && field.default_with.is_some() {
             return Err(Error::new_spanned(
                 field_ident,
-                "The `write_only` and `read_only` attributes cannot be enabled both.",
+                "The `default` and `default_with` attributes cannot be used together.",
             )
             .into());
         }
@@ -133,42 +382,163 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         let field_name = field
             .rename
             .clone()
+            .or(serde_args.rename)
             .unwrap_or_else(|| args.rename_all.rename(field_ident.unraw().to_string()));
         let field_description = get_description(&field.attrs)?;
         let field_description = optional_literal(&field_description);
+        let set_format = field
+            .format
+            .as_ref()
+            .map(|format| quote!(schema.format = ::std::option::Option::Some(#format);));
+        // A string that is explicitly marked as write-only is usually a secret,
+        // so it is masked by the UIs unless the format is specified.
+        let set_password_format = field.write_only.then(|| {
+            quote! {
+                if let #crate_name::registry::MetaSchemaRef::Inline(original_schema) = &original_schema {
+                    if original_schema.ty == "string" && original_schema.format.is_none() {
+                        schema.format = ::std::option::Option::Some("password");
+                    }
+                }
+            }
+        });
+        let set_title = field
+            .title
+            .as_ref()
+            .map(|title| quote!(schema.title = ::std::option::Option::Some(::std::string::ToString::to_string(#title));));
         let validators = field.validator.clone().unwrap_or_default();
         let validators_checker = validators.create_obj_field_checker(&crate_name, &field_name)?;
         let validators_update_meta = validators.create_update_meta(&crate_name)?;
+        let nullable = field.nullable;
+        let field_deprecated = field.deprecated;
+        let const_value = field
+            .const_value
+            .as_ref()
+            .map(|value| quote!(#crate_name::__private::serde_json::json!(#value)));
+
+        let set_required_if = field.required_if.as_ref().map(|RequiredIf { field, equals }| {
+            quote! {
+                schema.extensions.push(("x-required-if", #crate_name::__private::serde_json::json!({
+                    "field": #field,
+                    "equals": #equals,
+                })));
+            }
+        });
 
         fields.push(field_ident);
+        if !field.flatten {
+            property_idents.push((field_name.clone(), field_ident));
+        }
+        partial_fields.push(field);
+        if let Some(required_if) = &field.required_if {
+            if !is_optional_type(field_ty) {
+                return Err(Error::new_spanned(
+                    field_ty,
+                    "The `required_if` attribute can only be used on `Option<T>` or `MaybeUndefined<T>` fields.",
+                )
+                .into());
+            }
+            required_if_checkers.push((field_ident, field_name.clone(), required_if));
+        }
+
+        if !read_only && !field.flatten && const_value.is_none() {
+            patch_fields.push((field, field_name.clone()));
+        }
 
-        if read_only {
+        if read_only_lenient {
+            deserialize_fields.push(quote! {
+                #[allow(non_snake_case)]
+                let #field_ident: #field_ty = {
+                    obj.remove(#field_name);
+                    ::std::default::Default::default()
+                };
+            });
+        } else if read_only {
             deserialize_fields.push(quote! {
                 #[allow(non_snake_case)]
                 let #field_ident: #field_ty = {
                     if obj.contains_key(#field_name) {
-                        return Err(#crate_name::types::ParseError::custom(format!("properties `{}` is read only.", #field_name)));
+                        return Err(#crate_name::types::ParseError::custom(format!("properties `{}` is read only.", #field_name)).with_field(#field_name, "read_only"));
                     }
                     ::std::default::Default::default()
                 };
             });
+        } else if let Some(const_value) = &const_value {
+            deserialize_fields.push(quote! {
+                #[allow(non_snake_case)]
+                let #field_ident: #field_ty = {
+                    let value = obj.remove(#field_name);
+                    if value.as_ref() != ::std::option::Option::Some(&#const_value) {
+                        return Err(#crate_name::types::ParseError::custom(format!("properties `{}` must be `{}`.", #field_name, #const_value)).with_field(#field_name, "const"));
+                    }
+                    #crate_name::types::ParseFromJSON::parse_from_json(value)
+                        .map_err(|err| #crate_name::types::ParseError::propagate_field(err, #field_name))?
+                };
+            });
         } else if !field.flatten {
-            match &field.default {
-                Some(default_value) => {
-                    let default_value = match default_value {
-                        DefaultValue::Default => {
-                            quote!(<#field_ty as ::std::default::Default>::default())
-                        }
-                        DefaultValue::Function(func_name) => quote!(#func_name()),
-                    };
+            if deny_null.contains(field_ident) {
+                deserialize_fields.push(quote! {
+                    if let ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Null) = obj.get(#field_name) {
+                        return Err(#crate_name::types::ParseError::custom(format!("properties `{}` can not be null.", #field_name)).with_field(#field_name, "null"));
+                    }
+                });
+            }
+
+            // A coerced field also accepts numbers and booleans encoded as
+            // strings.
+            let parse_from_json = if field.coerce {
+                quote!(#crate_name::__private::parse_coerced_json)
+            } else {
+                quote!(#crate_name::types::ParseFromJSON::parse_from_json)
+            };
+            // The default value of `default_with` is computed after the other
+            // fields are parsed.
+            let default_value = match (default, &field.default_with) {
+                (Some(DefaultValue::Default), _) => {
+                    Some(quote!(<#field_ty as ::std::default::Default>::default()))
+                }
+                // The default function of an `Option<T>` field may return `T`,
+                // which is wrapped in `Some`.
+                (Some(DefaultValue::Function(func_name)), _)
+                    if get_option_inner_type(field_ty).is_some() =>
+                {
+                    Some(quote!(::std::convert::Into::<#field_ty>::into(#func_name())))
+                }
+                (Some(DefaultValue::Function(func_name)), _) => Some(quote!(#func_name())),
+                (None, Some(_)) => None,
+                (None, None) => None,
+            };
+
+            let missing = if nullable {
+                quote!(::std::option::Option::None)
+            } else {
+                quote!(::std::option::Option::Some(#crate_name::__private::serde_json::Value::Null) | ::std::option::Option::None)
+            };
 
+            match default_value {
+                _ if field.default_with.is_some() => {
+                    default_with_fields.push(field);
+                    deserialize_fields.push(quote! {
+                        #[allow(non_snake_case)]
+                        let #field_ident: ::std::option::Option<#field_ty> = {
+                            match obj.remove(#field_name) {
+                                #missing => ::std::option::Option::None,
+                                value => {
+                                    let value = #parse_from_json(value).map_err(|err| #crate_name::types::ParseError::propagate_field(err, #field_name))?;
+                                    #validators_checker
+                                    ::std::option::Option::Some(value)
+                                }
+                            }
+                        };
+                    });
+                }
+                Some(default_value) => {
                     deserialize_fields.push(quote! {
                         #[allow(non_snake_case)]
                         let #field_ident: #field_ty = {
                             match obj.remove(#field_name) {
-                                ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Null) | ::std::option::Option::None => #default_value,
+                                #missing => #default_value,
                                 value => {
-                                    let value = #crate_name::types::ParseFromJSON::parse_from_json(value).map_err(#crate_name::types::ParseError::propagate)?;
+                                    let value = #parse_from_json(value).map_err(|err| #crate_name::types::ParseError::propagate_field(err, #field_name))?;
                                     #validators_checker
                                     value
                                 }
@@ -177,28 +547,102 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     });
                 }
                 _ => {
+                    let check_present = if nullable {
+                        Some(quote! {
+                            if !obj.contains_key(#field_name) {
+                                return Err(#crate_name::types::ParseError::custom(format!("properties `{}` is required.", #field_name)).with_field(#field_name, "expected_input"));
+                            }
+                        })
+                    } else {
+                        None
+                    };
+
                     deserialize_fields.push(quote! {
                         #[allow(non_snake_case)]
                         let #field_ident: #field_ty = {
-                            let value = #crate_name::types::ParseFromJSON::parse_from_json(obj.remove(#field_name))
-                                .map_err(#crate_name::types::ParseError::propagate)?;
+                            #check_present
+                            let value = #parse_from_json(obj.remove(#field_name))
+                                .map_err(|err| #crate_name::types::ParseError::propagate_field(err, #field_name))?;
                             #validators_checker
                             value
                         };
                     });
                 }
             };
+        } else if is_map_field {
+            deserialize_map_fields.push(quote! {
+                #[allow(non_snake_case)]
+                let #field_ident: #field_ty = {
+                    #crate_name::types::ParseFromJSON::parse_from_json(::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(::std::mem::take(&mut obj))))
+                        .map_err(|err| #crate_name::types::ParseError::propagate_flatten(err, #flatten_name))?
+                };
+            });
         } else {
+            let flatten_obj = match &flatten_renamer {
+                Some(flatten_renamer) => quote! {
+                    match #flatten_renamer {
+                        ::std::option::Option::Some(renamer) => renamer.to_original(&obj),
+                        ::std::option::Option::None => ::std::clone::Clone::clone(&obj),
+                    }
+                },
+                None => quote!(::std::clone::Clone::clone(&obj)),
+            };
+            // The properties of the flattened object are not captured by the
+            // flattened map.
+            let remove_properties = if has_map_field {
+                let renamer = match &flatten_renamer {
+                    Some(flatten_renamer) => flatten_renamer.clone(),
+                    None => {
+                        quote!(::std::option::Option::None::<&#crate_name::__private::FlattenRenamer>)
+                    }
+                };
+                let property_names = quote!({
+                    let renamer = #renamer;
+                    let mut registry = #crate_name::registry::Registry::new();
+                    registry
+                        .create_fake_schema::<#field_ty>()
+                        .properties
+                        .into_iter()
+                        .map(|(name, _)| renamer.map(|renamer| renamer.rename(name)).unwrap_or(name))
+                        .collect::<::std::vec::Vec<&'static str>>()
+                });
+                if args.generics.params.is_empty() {
+                    Some(quote! {
+                        #crate_name::__private::lazy_static::lazy_static! {
+                            static ref PROPERTY_NAMES: ::std::vec::Vec<&'static str> = #property_names;
+                        }
+                        for name in PROPERTY_NAMES.iter() {
+                            obj.remove(*name);
+                        }
+                    })
+                } else {
+                    Some(quote! {
+                        for name in #property_names {
+                            obj.remove(name);
+                        }
+                    })
+                }
+            } else {
+                None
+            };
             deserialize_fields.push(quote! {
                 #[allow(non_snake_case)]
                 let #field_ident: #field_ty = {
-                    #crate_name::types::ParseFromJSON::parse_from_json(::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(::std::clone::Clone::clone(&obj))))
-                        .map_err(#crate_name::types::ParseError::propagate)?
+                    let value = #crate_name::types::ParseFromJSON::parse_from_json(::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(#flatten_obj)))
+                        .map_err(|err| #crate_name::types::ParseError::propagate_flatten(err, #flatten_name))?;
+                    #remove_properties
+                    value
                 };
             });
         }
 
-        if !field.flatten {
+        if let Some(const_value) = &const_value {
+            if !write_only {
+                serialize_fields.push(quote! {
+                    object.insert(::std::string::ToString::to_string(#field_name), #const_value);
+                });
+            }
+        } else if !field.flatten {
             if !write_only {
                 serialize_fields.push(quote! {
                     if let ::std::option::Option::Some(value) = #crate_name::types::ToJSON::to_json(&self.#field_ident) {
@@ -207,14 +651,23 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                 });
             }
         } else {
+            let flatten_obj = match &flatten_renamer {
+                Some(flatten_renamer) => quote! {
+                    match #flatten_renamer {
+                        ::std::option::Option::Some(renamer) => renamer.to_renamed(obj),
+                        ::std::option::Option::None => obj,
+                    }
+                },
+                None => quote!(obj),
+            };
             serialize_fields.push(quote! {
                 if let ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(obj)) = #crate_name::types::ToJSON::to_json(&self.#field_ident) {
-                    object.extend(obj);
+                    object.extend(#flatten_obj);
                 }
             });
         }
 
-        let field_meta_default = match &field.default {
+        let field_meta_default = match default {
             Some(DefaultValue::Default) => {
                 quote!(#crate_name::types::ToJSON::to_json(&<#field_ty as ::std::default::Default>::default()))
             }
@@ -224,21 +677,42 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             None => quote!(::std::option::Option::None),
         };
 
+        let set_enum_items = const_value
+            .as_ref()
+            .map(|const_value| quote!(schema.enum_items = ::std::vec![#const_value];));
+
         if !field.flatten {
-            register_types
-                .push(quote!(<#field_ty as #crate_name::types::Type>::register(registry);));
+            // A field with `schema_ref` refers to a schema that is registered elsewhere.
+            let original_schema = match &field.schema_ref {
+                Some(name) => {
+                    let name = &**name;
+                    quote!(#crate_name::registry::MetaSchemaRef::Reference(#name))
+                }
+                None => {
+                    register_types
+                        .push(quote!(<#field_ty as #crate_name::types::Type>::register(registry);));
+                    quote!(<#field_ty as #crate_name::types::Type>::schema_ref())
+                }
+            };
 
             meta_fields.push(quote! {{
-                let original_schema = <#field_ty as #crate_name::types::Type>::schema_ref();
+                let original_schema = #original_schema;
                 let patch_schema = {
                     let mut schema = #crate_name::registry::MetaSchema::ANY;
                     schema.default = #field_meta_default;
+                    schema.nullable = #nullable;
+                    schema.deprecated = #field_deprecated;
+                    #set_enum_items
                     schema.read_only = #read_only;
                     schema.write_only = #write_only;
 
                     if let ::std::option::Option::Some(field_description) = #field_description {
                         schema.description = ::std::option::Option::Some(field_description);
                     }
+                    #set_title
+                    #set_password_format
+                    #set_format
+                    #set_required_if
                     #validators_update_meta
                     schema
                 };
@@ -246,24 +720,186 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                 fields.push((#field_name, original_schema.merge(patch_schema)));
             }});
 
-            let has_default = field.default.is_some();
+            let has_default = default.is_some() || field.default_with.is_some();
             required_fields.push(quote! {
-                if <#field_ty>::IS_REQUIRED && !#has_default {
+                if (<#field_ty>::IS_REQUIRED || #nullable) && !#has_default {
                     fields.push(#field_name);
                 }
             });
         } else {
-            meta_fields.push(quote! {
-                fields.extend(registry.create_fake_schema::<#field_ty>().properties);
-            });
-            required_fields.push(quote! {
-                fields.extend(registry.create_fake_schema::<#field_ty>().required);
+            // With `flatten_rename_all`, the `rename_all` rule of this object
+            // applies to the properties of the flattened field.
+            let (flatten_properties, flatten_required) = match &flatten_renamer {
+                Some(flatten_renamer) => (
+                    quote! {{
+                        let renamer = #flatten_renamer;
+                        ::std::iter::Iterator::map(::std::iter::IntoIterator::into_iter(flatten_schema.properties), move |(name, schema)| {
+                            (renamer.as_ref().map(|renamer| renamer.rename(name)).unwrap_or(name), schema)
+                        })
+                    }},
+                    quote! {{
+                        let renamer = #flatten_renamer;
+                        ::std::iter::Iterator::map(::std::iter::IntoIterator::into_iter(flatten_schema.required), move |name| {
+                            renamer.as_ref().map(|renamer| renamer.rename(name)).unwrap_or(name)
+                        })
+                    }},
+                ),
+                None => (
+                    quote!(::std::iter::IntoIterator::into_iter(
+                        flatten_schema.properties
+                    )),
+                    quote!(flatten_schema.required),
+                ),
+            };
+
+            // The `read_only` and `write_only` flags of the flattened field apply
+            // to all of its properties.
+            let flatten_properties = if read_only || write_only {
+                quote! {
+                    ::std::iter::Iterator::map(#flatten_properties, |(name, schema)| {
+                        let mut patch_schema = #crate_name::registry::MetaSchema::ANY;
+                        patch_schema.read_only = #read_only;
+                        patch_schema.write_only = #write_only;
+                        (name, schema.merge(patch_schema))
+                    })
+                }
+            } else {
+                flatten_properties
+            };
+
+            // A flattened union can not be merged into the properties, so it is
+            // composed with `allOf` instead. With `flatten_all_of`, the same is
+            // done for the flattened objects that are registered as components.
+            let compose = quote! {
+                (!flatten_schema.any_of.is_empty() || !flatten_schema.one_of.is_empty()
+                    || (#compose_ref && ::std::matches!(
+                        <#field_ty as #crate_name::types::Type>::schema_ref(),
+                        #crate_name::registry::MetaSchemaRef::Reference(_)
+                    )))
+            };
+            meta_fields.push(quote! {{
+                let flatten_schema = registry.create_fake_schema::<#field_ty>();
+                if !#compose {
+                    fields.extend(#flatten_properties);
+                }
+            }});
+            required_fields.push(quote! {{
+                let flatten_schema = registry.create_fake_schema::<#field_ty>();
+                if !#compose {
+                    fields.extend(#flatten_required);
+                }
+            }});
+            if is_map_field {
+                additional_properties = Some(quote! {
+                    registry.create_fake_schema::<#field_ty>().additional_properties
+                });
+            }
+            all_of_fields.push(quote! {{
+                let flatten_schema = registry.create_fake_schema::<#field_ty>();
+                if #compose {
+                    <#field_ty as #crate_name::types::Type>::register(registry);
+                    all_of.push(<#field_ty as #crate_name::types::Type>::schema_ref());
+                }
+            }});
+        }
+    }
+
+    // The default values of `default_with` are computed from the other fields,
+    // which are passed as a partial object.
+    let mut partial = None;
+    let mut default_with_values = Vec::new();
+    if let Some(field) = default_with_fields.first() {
+        if !args.generics.params.is_empty() {
+            return Err(Error::new_spanned(
+                &field.ident,
+                "The `default_with` attribute cannot be used with generic objects.",
+            )
+            .into());
+        }
+
+        let partial_ident = format_ident!("{}Partial", ident);
+        let partial_fields = partial_fields
+            .iter()
+            .filter(|field| !default_with_fields.iter().any(|f| f.ident == field.ident))
+            .collect::<Vec<_>>();
+        let partial_idents = partial_fields
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap())
+            .collect::<Vec<_>>();
+        let partial_defs = partial_fields.iter().map(|field| {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_vis = &field.vis;
+            let field_ty = &field.ty;
+            let docs = field.attrs.iter().filter(|attr| attr.path.is_ident("doc"));
+            quote! {
+                #(#docs)*
+                #field_vis #field_ident: &'a #field_ty
+            }
+        });
+        let lifetime = if partial_fields.is_empty() {
+            None
+        } else {
+            Some(quote!(<'a>))
+        };
+        let vis = &args.vis;
+        let description = format!(
+            "The fields of [`{}`] that are passed to the `default_with` functions.",
+            ident
+        );
+        partial = Some(quote! {
+            #[doc = #description]
+            #vis struct #partial_ident #lifetime {
+                #(#partial_defs),*
+            }
+        });
+
+        for field in &default_with_fields {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_ty = &field.ty;
+            let func_name = field.default_with.as_ref().unwrap();
+            default_with_values.push(quote! {
+                #[allow(non_snake_case)]
+                let #field_ident: #field_ty = match #field_ident {
+                    ::std::option::Option::Some(value) => value,
+                    ::std::option::Option::None => #func_name(&#partial_ident { #(#partial_idents: &#partial_idents),* }),
+                };
             });
         }
     }
 
+    // The conditions of `required_if` are checked after all fields are parsed.
+    let required_if_checkers = required_if_checkers
+        .into_iter()
+        .map(|(field_ident, field_name, RequiredIf { field, equals })| {
+            let other_ident = match property_idents.iter().find(|(name, _)| name == field) {
+                Some((_, ident)) => ident,
+                None => {
+                    return Err(Error::new_spanned(
+                        field_ident,
+                        format!("The field `{}` referenced by `required_if` is not found.", field),
+                    ));
+                }
+            };
+            Ok(quote! {
+                if #crate_name::__private::json_value_equals(#crate_name::types::ToJSON::to_json(&#other_ident), #equals)
+                    && #crate_name::types::Type::as_raw_value(&#field_ident).is_none() {
+                    return Err(#crate_name::types::ParseError::custom(format!("properties `{}` is required when `{}` is `{}`.", #field_name, #field, #equals)).with_field(#field_name, "required_if"));
+                }
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     let description = optional_literal(&description);
+    let additional_properties =
+        additional_properties.unwrap_or_else(|| quote!(::std::option::Option::None));
+    let title = match &args.title {
+        Some(title) => {
+            quote!(::std::option::Option::Some(::std::string::ToString::to_string(#title)))
+        }
+        None => quote!(::std::option::Option::None),
+    };
     let deprecated = args.deprecated;
+    let extensions = Extension::to_token_stream(&args.extensions, &crate_name);
     let external_docs = match &args.external_docs {
         Some(external_docs) => {
             let s = external_docs.to_token_stream(&crate_name);
@@ -273,6 +909,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     };
     let meta = quote! {
         #crate_name::registry::MetaSchema {
+            title: #title,
             description: #description,
             external_docs: #external_docs,
             required: {
@@ -286,14 +923,22 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                 #(#meta_fields)*
                 fields
             },
+            all_of: {
+                #[allow(unused_mut)]
+                let mut all_of = ::std::vec::Vec::new();
+                #(#all_of_fields)*
+                all_of
+            },
+            additional_properties: #additional_properties,
             deprecated: #deprecated,
+            extensions: #extensions,
             ..#crate_name::registry::MetaSchema::new("object")
         }
     };
     let deny_unknown_fields = if args.deny_unknown_fields {
         Some(quote! {
             if let ::std::option::Option::Some((field_name, _)) = std::iter::Iterator::next(&mut ::std::iter::IntoIterator::into_iter(obj)) {
-                return Err(#crate_name::types::ParseError::custom(format!("unknown field `{}`.", field_name)));
+                return Err(#crate_name::types::ParseError::custom(format!("unknown field `{}`.", field_name)).with_field(&field_name, "unknown_field"));
             }
         })
     } else {
@@ -301,12 +946,33 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     };
 
     let expanded = if args.concretes.is_empty() {
-        let example = match &args.example {
-            Some(path) => {
-                let path = &**path;
+        // The schema has the first example as `example`, the named examples
+        // are added to the media types that contain this object.
+        let example = match (args.examples.first(), &args.example_value) {
+            (Some(example), _) => {
+                let path = &example.value;
                 quote! { <Self as #impl_generics #crate_name::types::ToJSON>::to_json(&#path()) }
             }
-            None => quote!(::std::option::Option::None),
+            (None, Some(value)) => {
+                let value = &**value;
+                quote! { #crate_name::__private::serde_json::from_str(#value).ok() }
+            }
+            (None, None) if *args.example_from_default => {
+                quote! { <Self as #impl_generics #crate_name::types::ToJSON>::to_json(&<Self as ::std::default::Default>::default()) }
+            }
+            (None, None) => quote!(::std::option::Option::None),
+        };
+        let fn_examples = match args.examples.as_slice() {
+            [] => None,
+            [example] if example.name.is_none() => None,
+            examples => {
+                let examples = examples.iter().map(|example| example.to_meta(&crate_name));
+                Some(quote! {
+                    fn examples() -> ::std::vec::Vec<#crate_name::registry::MetaExample> {
+                        ::std::vec![#(#examples),*].into_iter().flatten().collect()
+                    }
+                })
+            }
         };
 
         let (fn_schema_ref, fn_register) = if *args.inline {
@@ -334,6 +1000,13 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             )
         };
 
+        let multipart_field = generate_multipart_field(
+            quote!(#impl_generics),
+            quote!(#ident #ty_generics),
+            quote!(#where_clause),
+            &crate_name,
+        );
+
         quote! {
             impl #impl_generics #crate_name::types::Type for #ident #ty_generics #where_clause {
                 const IS_REQUIRED: bool = true;
@@ -354,6 +1027,8 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     #fn_register
                 }
 
+                #fn_examples
+
                 fn as_raw_value(&self) -> ::std::option::Option<&Self::RawValueType> {
                     ::std::option::Option::Some(self)
                 }
@@ -369,6 +1044,9 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     match value {
                         #crate_name::__private::serde_json::Value::Object(mut obj) => {
                             #(#deserialize_fields)*
+                            #(#deserialize_map_fields)*
+                            #(#default_with_values)*
+                            #(#required_if_checkers)*
                             #deny_unknown_fields
                             ::std::result::Result::Ok(Self { #(#fields),* })
                         }
@@ -384,6 +1062,8 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(object))
                 }
             }
+
+            #multipart_field
         }
     } else {
         let mut code = Vec::new();
@@ -403,6 +1083,9 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     match value {
                         #crate_name::__private::serde_json::Value::Object(mut obj) => {
                             #(#deserialize_fields)*
+                            #(#deserialize_map_fields)*
+                            #(#default_with_values)*
+                            #(#required_if_checkers)*
                             #deny_unknown_fields
                             ::std::result::Result::Ok(Self { #(#fields),* })
                         }
@@ -411,21 +1094,44 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                 }
 
                 fn __internal_to_json(&self) -> #crate_name::__private::serde_json::Value where Self: #crate_name::types::Type {
-                    let mut object = ::serde_json::Map::new();
+                    let mut object = #crate_name::__private::serde_json::Map::new();
                     #(#serialize_fields)*
                     #crate_name::__private::serde_json::Value::Object(object)
                 }
             }
         });
 
+        let mut concrete_names = HashSet::new();
         for concrete in &args.concretes {
-            let oai_typename = &concrete.name;
+            let oai_typename = concrete.type_name(&oai_typename);
             let params = &concrete.params.0;
+            if !concrete_names.insert(oai_typename.clone()) {
+                return Err(Error::new(
+                    params
+                        .first()
+                        .map(Spanned::span)
+                        .unwrap_or_else(|| ident.span()),
+                    format!(
+                        "duplicate concrete type name `{}`, specify a different `name`",
+                        oai_typename
+                    ),
+                )
+                .into());
+            }
             let concrete_type = quote! { #ident<#(#params),*> };
+            let multipart_field = generate_multipart_field(
+                TokenStream::new(),
+                concrete_type.clone(),
+                TokenStream::new(),
+                &crate_name,
+            );
             let example = match &concrete.example {
                 Some(path) => {
                     quote! { <Self as #crate_name::types::ToJSON>::to_json(&#path()) }
                 }
+                None if *args.example_from_default => {
+                    quote! { <Self as #crate_name::types::ToJSON>::to_json(&<Self as ::std::default::Default>::default()) }
+                }
                 None => quote!(::std::option::Option::None),
             };
 
@@ -471,6 +1177,8 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                         ::std::option::Option::Some(Self::__internal_to_json(self))
                     }
                 }
+
+                #multipart_field
             };
             code.push(expanded);
         }
@@ -478,5 +1186,365 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         quote!(#(#code)*)
     };
 
-    Ok(expanded)
+    let patch = match &args.patch {
+        Some(patch_ident) => Some(generate_patch(
+            &input,
+            &args,
+            patch_ident,
+            &patch_fields,
+            &crate_name,
+        )?),
+        None => None,
+    };
+
+    Ok(quote! {
+        #expanded
+        #partial
+        #patch
+    })
+}
+
+/// Generates the implementation for a tuple struct, which is represented as a
+/// JSON array with one item for each field.
+fn generate_tuple(
+    input: &DeriveInput,
+    args: &ObjectArgs,
+    fields: &[ObjectField],
+    oai_typename: &str,
+    crate_name: &TokenStream,
+) -> GeneratorResult<TokenStream> {
+    let ident = &args.ident;
+    let (impl_generics, ty_generics, where_clause) = args.generics.split_for_impl();
+
+    let unsupported = [
+        ("rename_all", args.rename_all.is_some()),
+        ("concrete", !args.concretes.is_empty()),
+        ("read_only_all", args.read_only_all),
+        ("write_only_all", args.write_only_all),
+        ("example", !args.examples.is_empty()),
+        ("example_value", args.example_value.is_some()),
+        ("example_from_default", *args.example_from_default),
+        ("deny_unknown_fields", args.deny_unknown_fields),
+        ("serde_compat", args.serde_compat),
+        ("patch", args.patch.is_some()),
+        ("flatten_all_of", args.flatten_all_of),
+        ("flatten_rename_all", args.flatten_rename_all),
+    ];
+    if let Some((name, _)) = unsupported.iter().find(|(_, used)| *used) {
+        return Err(Error::new_spanned(
+            ident,
+            format!("Tuple structs cannot have the `{}` attribute.", name),
+        )
+        .into());
+    }
+
+    if let syn::Data::Struct(s) = &input.data {
+        if let Some(attr) = s
+            .fields
+            .iter()
+            .flat_map(|field| &field.attrs)
+            .find(|attr| attr.path.is_ident("oai"))
+        {
+            return Err(Error::new_spanned(
+                attr,
+                "The fields of tuple structs cannot have the `oai` attribute.",
+            )
+            .into());
+        }
+    }
+
+    let description = optional_literal(&get_description(&args.attrs)?);
+    let title = match &args.title {
+        Some(title) => {
+            quote!(::std::option::Option::Some(::std::string::ToString::to_string(#title)))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+    let deprecated = args.deprecated;
+    let external_docs = match &args.external_docs {
+        Some(external_docs) => {
+            let s = external_docs.to_token_stream(crate_name);
+            quote!(::std::option::Option::Some(#s))
+        }
+        None => quote!(::std::option::Option::None),
+    };
+    let extensions = Extension::to_token_stream(&args.extensions, crate_name);
+    let field_count = fields.len();
+    let field_types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let indexes = (0..field_count).map(syn::Index::from).collect::<Vec<_>>();
+    let index_names = (0..field_count).map(|idx| idx.to_string());
+
+    // OpenAPI 3.0 can not describe the schema of each position, so the items are
+    // any of the field schemas.
+    let items = quote! {{
+        let mut schemas: ::std::vec::Vec<#crate_name::registry::MetaSchemaRef> = ::std::vec::Vec::new();
+        #(
+            let schema_ref = <#field_types as #crate_name::types::Type>::schema_ref();
+            if !schemas.contains(&schema_ref) {
+                schemas.push(schema_ref);
+            }
+        )*
+        if schemas.len() == 1 {
+            schemas.remove(0)
+        } else {
+            #crate_name::registry::MetaSchemaRef::Inline(::std::boxed::Box::new(#crate_name::registry::MetaSchema {
+                one_of: schemas,
+                ..#crate_name::registry::MetaSchema::ANY
+            }))
+        }
+    }};
+
+    let meta = quote! {
+        #crate_name::registry::MetaSchema {
+            title: #title,
+            description: #description,
+            external_docs: #external_docs,
+            items: ::std::option::Option::Some(::std::boxed::Box::new(#items)),
+            min_items: ::std::option::Option::Some(#field_count),
+            max_items: ::std::option::Option::Some(#field_count),
+            deprecated: #deprecated,
+            extensions: #extensions,
+            ..#crate_name::registry::MetaSchema::new("array")
+        }
+    };
+
+    let (fn_schema_ref, fn_register) = if *args.inline {
+        (
+            quote!(#crate_name::registry::MetaSchemaRef::Inline(::std::boxed::Box::new(#meta))),
+            quote! {
+                #(<#field_types as #crate_name::types::Type>::register(registry);)*
+            },
+        )
+    } else {
+        (
+            quote!(#crate_name::registry::MetaSchemaRef::Reference(#oai_typename)),
+            quote! {
+                registry.create_schema::<Self, _>(#oai_typename, |registry| {
+                    #(<#field_types as #crate_name::types::Type>::register(registry);)*
+                    #meta
+                })
+            },
+        )
+    };
+
+    let multipart_field = generate_multipart_field(
+        quote!(#impl_generics),
+        quote!(#ident #ty_generics),
+        quote!(#where_clause),
+        crate_name,
+    );
+
+    Ok(quote! {
+        impl #impl_generics #crate_name::types::Type for #ident #ty_generics #where_clause {
+            const IS_REQUIRED: bool = true;
+
+            type RawValueType = Self;
+
+            type RawElementValueType = Self;
+
+            fn name() -> ::std::borrow::Cow<'static, str> {
+                ::std::convert::Into::into(#oai_typename)
+            }
+
+            fn schema_ref() -> #crate_name::registry::MetaSchemaRef {
+                #fn_schema_ref
+            }
+
+            fn register(registry: &mut #crate_name::registry::Registry) {
+                #fn_register
+            }
+
+            fn as_raw_value(&self) -> ::std::option::Option<&Self::RawValueType> {
+                ::std::option::Option::Some(self)
+            }
+
+            fn raw_element_iter<'a>(&'a self) -> ::std::boxed::Box<dyn ::std::iter::Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+                ::std::boxed::Box::new(::std::iter::IntoIterator::into_iter(self.as_raw_value()))
+            }
+        }
+
+        impl #impl_generics #crate_name::types::ParseFromJSON for #ident #ty_generics #where_clause {
+            fn parse_from_json(value: ::std::option::Option<#crate_name::__private::serde_json::Value>) -> ::std::result::Result<Self, #crate_name::types::ParseError<Self>> {
+                let value = value.unwrap_or_default();
+                match value {
+                    #crate_name::__private::serde_json::Value::Array(items) if items.len() == #field_count => {
+                        let mut items = ::std::iter::IntoIterator::into_iter(items);
+                        ::std::result::Result::Ok(Self(#(
+                            #crate_name::types::ParseFromJSON::parse_from_json(::std::iter::Iterator::next(&mut items))
+                                .map_err(|err| #crate_name::types::ParseError::propagate_field(err, #index_names))?
+                        ),*))
+                    }
+                    _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
+                }
+            }
+        }
+
+        impl #impl_generics #crate_name::types::ToJSON for #ident #ty_generics #where_clause {
+            fn to_json(&self) -> ::std::option::Option<#crate_name::__private::serde_json::Value> {
+                ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Array(::std::vec![#(
+                    ::std::option::Option::unwrap_or_default(#crate_name::types::ToJSON::to_json(&self.#indexes))
+                ),*]))
+            }
+        }
+
+        #multipart_field
+    })
+}
+
+/// Generates the implementation of `ParseFromMultipartField`, the content of
+/// the field is parsed as JSON.
+fn generate_multipart_field(
+    impl_generics: TokenStream,
+    self_ty: TokenStream,
+    where_clause: TokenStream,
+    crate_name: &TokenStream,
+) -> TokenStream {
+    quote! {
+        #[#crate_name::__private::poem::async_trait]
+        impl #impl_generics #crate_name::types::ParseFromMultipartField for #self_ty #where_clause {
+            async fn parse_from_multipart(field: ::std::option::Option<#crate_name::__private::poem::web::Field>) -> #crate_name::types::ParseResult<Self> {
+                #crate_name::__private::parse_json_multipart_field(field).await
+            }
+        }
+    }
+}
+
+/// Generates the companion struct of `#[oai(patch = "...")]`. Every field is
+/// wrapped in `MaybeUndefined`, so that an absent key can be told apart from
+/// an explicit `null`, the read-only, flattened and constant fields are left
+/// out.
+fn generate_patch(
+    input: &DeriveInput,
+    args: &ObjectArgs,
+    patch_ident: &Ident,
+    patch_fields: &[(&ObjectField, String)],
+    crate_name: &TokenStream,
+) -> GeneratorResult<TokenStream> {
+    if !args.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            patch_ident,
+            "The `patch` attribute cannot be used with generic objects.",
+        )
+        .into());
+    }
+
+    let ident = &args.ident;
+    let vis = &args.vis;
+    let mut oai_args = Vec::new();
+    if args.internal {
+        oai_args.push(quote!(internal));
+    }
+    if let Some(namespace) = &args.namespace {
+        oai_args.push(quote!(namespace = #namespace));
+    }
+    let mut patch_defs = Vec::new();
+    let mut patch_attrs = Vec::new();
+    let mut patch_apply = Vec::new();
+    let mut deny_null = Vec::new();
+
+    for (field, field_name) in patch_fields {
+        let field_ident = field.ident.as_ref().unwrap();
+        let field_vis = &field.vis;
+        let docs = field.attrs.iter().filter(|attr| attr.path.is_ident("doc"));
+        let schema_ref = field.schema_ref.as_ref().map(|name| {
+            let name = &**name;
+            quote!(, schema_ref = #name)
+        });
+        // The validators are copied as they are written on the field, darling
+        // does not forward the `oai` attributes it has parsed.
+        let validators = match &input.data {
+            syn::Data::Struct(s) => s
+                .fields
+                .iter()
+                .find(|f| f.ident.as_ref() == Some(field_ident))
+                .map(|f| f.attrs.as_slice())
+                .unwrap_or_default(),
+            _ => &[],
+        };
+        let validators = validators
+            .iter()
+            .filter(|attr| attr.path.is_ident("oai"))
+            .filter_map(|attr| match attr.parse_meta() {
+                Ok(Meta::List(list)) => Some(list.nested),
+                _ => None,
+            })
+            .flatten()
+            .filter(|meta| {
+                matches!(meta, NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("validator"))
+            })
+            .collect::<Vec<_>>();
+
+        match get_option_inner_type(&field.ty) {
+            Some(inner_ty) => {
+                patch_defs.push(quote! {
+                    #(#docs)*
+                    #field_vis #field_ident: #crate_name::types::MaybeUndefined<#inner_ty>
+                });
+                patch_attrs
+                    .push(quote!(#[oai(rename = #field_name #schema_ref #(, #validators)*)]));
+                patch_apply.push(quote! {
+                    match self.#field_ident {
+                        #crate_name::types::MaybeUndefined::Value(value) => target.#field_ident = ::std::option::Option::Some(value),
+                        #crate_name::types::MaybeUndefined::Null => target.#field_ident = ::std::option::Option::None,
+                        #crate_name::types::MaybeUndefined::Undefined => {}
+                    }
+                });
+            }
+            None => {
+                let field_ty = &field.ty;
+                patch_defs.push(quote! {
+                    #(#docs)*
+                    #field_vis #field_ident: #crate_name::types::MaybeUndefined<#field_ty>
+                });
+                patch_attrs
+                    .push(quote!(#[oai(rename = #field_name #schema_ref #(, #validators)*)]));
+                deny_null.push(field_ident.clone());
+                patch_apply.push(quote! {
+                    if let #crate_name::types::MaybeUndefined::Value(value) = self.#field_ident {
+                        target.#field_ident = value;
+                    }
+                });
+            }
+        }
+    }
+
+    let description = format!(
+        "A partial update of [`{}`], generated by `#[oai(patch)]`.",
+        ident
+    );
+
+    // The object is generated directly, so that an explicit `null` can be
+    // rejected for the required fields without a public attribute.
+    let object = generate_object(
+        syn::parse2(quote! {
+            #[doc = #description]
+            #[oai(#(#oai_args),*)]
+            #vis struct #patch_ident {
+                #(#patch_attrs #patch_defs),*
+            }
+        })?,
+        &deny_null,
+    )?;
+
+    Ok(quote! {
+        #[doc = #description]
+        #[derive(::std::default::Default)]
+        #vis struct #patch_ident {
+            #(#patch_defs),*
+        }
+
+        #object
+
+        impl #patch_ident {
+            /// Applies the patch to `target`.
+            ///
+            /// The fields that are absent are left unchanged, and an explicit
+            /// `null` resets an `Option` field to `None`. The read-only,
+            /// flattened and constant fields are never changed.
+            #[allow(unused_variables)]
+            #vis fn apply(self, target: &mut #ident) {
+                #(#patch_apply)*
+            }
+        }
+    })
 }
@@ -97,6 +97,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     #crate_name::registry::MetaMediaType {
                         content_type: #content_type,
                         schema: <#payload_ty as #crate_name::payload::Payload>::schema_ref(),
+                        examples: <#payload_ty as #crate_name::payload::Payload>::examples(),
                     }
                 });
                 schemas.push(payload_ty);
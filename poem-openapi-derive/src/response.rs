@@ -1,13 +1,14 @@
 use darling::{
     ast::{Data, Fields},
     util::Ignored,
-    FromDeriveInput, FromField, FromVariant,
+    FromDeriveInput, FromField, FromMeta, FromVariant,
 };
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
 use syn::{Attribute, DeriveInput, Error, Generics, Path, Type};
 
 use crate::{
+    common_args::Example,
     error::GeneratorResult,
     utils::{get_crate_name, get_description, optional_literal},
 };
@@ -24,6 +25,17 @@ struct ResponseField {
     deprecated: bool,
 }
 
+/// A header that is only declared in the spec, it is set by the handler.
+#[derive(FromMeta)]
+struct DeclaredHeader {
+    name: String,
+    ty: Path,
+    #[darling(default)]
+    description: Option<String>,
+    #[darling(default)]
+    deprecated: bool,
+}
+
 #[derive(FromVariant)]
 #[darling(attributes(oai), forward_attrs(doc))]
 struct ResponseItem {
@@ -35,6 +47,10 @@ struct ResponseItem {
     status: Option<u16>,
     #[darling(default)]
     content_type: Option<String>,
+    #[darling(default, multiple, rename = "example")]
+    examples: Vec<Example>,
+    #[darling(default, multiple, rename = "header")]
+    headers: Vec<DeclaredHeader>,
 }
 
 #[derive(FromDeriveInput)]
@@ -70,6 +86,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
 
     for variant in e {
         let item_ident = &variant.ident;
+        Example::check_names(&variant.examples)?;
         let item_description = get_description(&variant.attrs)?;
         let item_description = optional_literal(&item_description);
         let (values, headers) = parse_fields(&variant.fields)?;
@@ -103,6 +120,23 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             });
         }
 
+        for header in &variant.headers {
+            let header_name = header.name.to_uppercase();
+            let header_ty = &header.ty;
+            let header_desc = optional_literal(&header.description);
+            let deprecated = header.deprecated;
+
+            meta_headers.push(quote! {
+                #crate_name::registry::MetaHeader {
+                    name: #header_name,
+                    description: #header_desc,
+                    required: <#header_ty as #crate_name::types::Type>::IS_REQUIRED,
+                    deprecated: #deprecated,
+                    schema: <#header_ty as #crate_name::types::Type>::schema_ref(),
+                }
+            });
+        }
+
         fn update_content_type(
             crate_name: &TokenStream,
             content_type: Option<&str>,
@@ -129,6 +163,20 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             (update_response_content_type, update_meta_content_type)
         }
 
+        let meta_examples = variant
+            .examples
+            .iter()
+            .map(|example| example.to_meta(&crate_name));
+        let update_meta_examples = if !variant.examples.is_empty() {
+            Some(quote! {
+                for mt in &mut content {
+                    mt.examples = ::std::vec![#(#meta_examples),*].into_iter().flatten().collect();
+                }
+            })
+        } else {
+            None
+        };
+
         match values.len() {
             2 => {
                 // Item(StatusCode, media)
@@ -151,6 +199,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                         content: {
                             let mut content = <#media_ty as #crate_name::ResponseContent>::media_types();
                             #update_meta_content_type
+                            #update_meta_examples
                             content
                         },
                         headers: ::std::vec![#(#meta_headers),*],
@@ -181,6 +230,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                         content: {
                             let mut content = <#media_ty as #crate_name::ResponseContent>::media_types();
                             #update_meta_content_type
+                            #update_meta_examples
                             content
                         },
                         headers: ::std::vec![#(#meta_headers),*],
@@ -191,6 +241,13 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
             0 => {
                 // #[oai(status = 200)]
                 // Item
+                if update_meta_examples.is_some() {
+                    return Err(Error::new_spanned(
+                        &variant.ident,
+                        "Examples cannot be specified for a response without content.",
+                    )
+                    .into());
+                }
                 let status = get_status(variant.ident.span(), variant.status)?;
                 let item = if !headers.is_empty() {
                     quote!(#ident::#item_ident(#(#match_headers),*))
@@ -85,6 +85,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     #crate_name::registry::MetaMediaType {
                         content_type: #content_type,
                         schema: <#item_ty as #crate_name::payload::Payload>::schema_ref(),
+                        examples: <#item_ty as #crate_name::payload::Payload>::examples(),
                     }
                 });
                 schemas.push(item_ty);
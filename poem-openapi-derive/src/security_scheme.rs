@@ -385,6 +385,20 @@ impl SecuritySchemeArgs {
         Ok(ts)
     }
 
+    fn generate_authorization_error(&self, crate_name: &TokenStream) -> TokenStream {
+        match self.ty {
+            AuthType::ApiKey => {
+                quote!(#crate_name::error::AuthorizationError::new())
+            }
+            AuthType::Basic => {
+                quote!(#crate_name::error::AuthorizationError::with_challenge("Basic"))
+            }
+            AuthType::Bearer | AuthType::OAuth2 | AuthType::OpenIdConnect => {
+                quote!(#crate_name::error::AuthorizationError::with_challenge("Bearer"))
+            }
+        }
+    }
+
     fn generate_from_request(&self, crate_name: &TokenStream) -> TokenStream {
         match self.ty {
             AuthType::ApiKey => {
@@ -441,9 +455,10 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     let register_security_scheme =
         args.generate_register_security_scheme(&crate_name, &oai_typename)?;
     let from_request = args.generate_from_request(&crate_name);
+    let authorization_error = args.generate_authorization_error(&crate_name);
     let checker = args.checker.as_ref().map(|path| {
         quote! {
-            let output = ::std::option::Option::ok_or(#path(&req, output).await, #crate_name::error::AuthorizationError)?;
+            let output = ::std::option::Option::ok_or_else(#path(&req, output).await, || #authorization_error)?;
         }
     });
 
@@ -5,7 +5,7 @@ use darling::{
 };
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{Attribute, DeriveInput, Error, Type};
+use syn::{ext::IdentExt, Attribute, DeriveInput, Error, Type};
 
 use crate::{
     common_args::ExternalDocument,
@@ -41,6 +41,8 @@ struct UnionArgs {
     #[darling(default)]
     discriminator_name: Option<String>,
     #[darling(default)]
+    content_name: Option<String>,
+    #[darling(default)]
     external_docs: Option<ExternalDocument>,
 }
 
@@ -52,6 +54,15 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     let description = get_description(&args.attrs)?;
     let description = optional_literal(&description);
     let discriminator_name = &args.discriminator_name;
+    let content_name = &args.content_name;
+
+    if content_name.is_some() && discriminator_name.is_none() {
+        return Err(Error::new_spanned(
+            ident,
+            "The `content_name` attribute requires the `discriminator_name` attribute.",
+        )
+        .into());
+    }
 
     let e = match &args.data {
         Data::Enum(e) => e,
@@ -64,6 +75,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     let mut mapping = Vec::new();
     let mut names = Vec::new();
     let mut schemas = Vec::new();
+    let mut variant_registers = Vec::new();
 
     let required = match &args.discriminator_name {
         Some(discriminator_name) => quote!(::std::vec![#discriminator_name]),
@@ -86,7 +98,16 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
 
                 types.push(object_ty);
 
-                if discriminator_name.is_some() {
+                if let Some(content_name) = content_name {
+                    from_json.push(quote! {
+                        if ::std::matches!(discriminator_name, ::std::option::Option::Some(discriminator_name) if discriminator_name == #mapping_name) {
+                            let content = value.as_object().and_then(|obj| obj.get(#content_name)).cloned();
+                            return <#object_ty as #crate_name::types::ParseFromJSON>::parse_from_json(content)
+                                .map(Self::#item_ident)
+                                .map_err(|err| #crate_name::types::ParseError::propagate_field(err, #content_name));
+                        }
+                    });
+                } else if discriminator_name.is_some() {
                     from_json.push(quote! {
                         if ::std::matches!(discriminator_name, ::std::option::Option::Some(discriminator_name) if discriminator_name == #mapping_name) {
                             return <#object_ty as #crate_name::types::ParseFromJSON>::parse_from_json(::std::option::Option::Some(value))
@@ -117,7 +138,20 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     });
                 }
 
-                if let Some(discriminator_name) = &discriminator_name {
+                if let (Some(discriminator_name), Some(content_name)) =
+                    (discriminator_name, content_name)
+                {
+                    to_json.push(quote! {
+                        Self::#item_ident(obj) => {
+                            let mut map = #crate_name::__private::serde_json::Map::new();
+                            map.insert(::std::convert::Into::into(#discriminator_name), ::std::convert::Into::into(#mapping_name));
+                            if let ::std::option::Option::Some(value) = <#object_ty as #crate_name::types::ToJSON>::to_json(obj) {
+                                map.insert(::std::convert::Into::into(#content_name), value);
+                            }
+                            ::std::option::Option::Some(#crate_name::__private::serde_json::Value::Object(map))
+                        }
+                    });
+                } else if let Some(discriminator_name) = &discriminator_name {
                     to_json.push(quote! {
                         Self::#item_ident(obj) => {
                             let mut value = <#object_ty as #crate_name::types::ToJSON>::to_json(obj);
@@ -133,13 +167,64 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
                     });
                 }
 
-                if variant.mapping.is_some() {
+                // The variants of an adjacently tagged union are registered as
+                // components, so that all of them are listed in the mapping.
+                let variant_schema_name = format!("{}{}", oai_typename, item_ident.unraw());
+                if content_name.is_some() {
+                    // The mapping needs a static name, the name of the type is
+                    // kept in a static if it is not specified.
+                    let mapping_key = match &variant.mapping {
+                        Some(mapping) => quote!(#mapping),
+                        None => quote!({
+                            #crate_name::__private::lazy_static::lazy_static! {
+                                static ref MAPPING_NAME: ::std::string::String =
+                                    ::std::string::ToString::to_string(&<#object_ty as #crate_name::types::Type>::name());
+                            }
+                            ::std::string::String::as_str(&*MAPPING_NAME)
+                        }),
+                    };
+                    mapping.push(quote! {
+                        (#mapping_key, ::std::string::ToString::to_string(::std::concat!("#/components/schemas/", #variant_schema_name)))
+                    });
+                } else if variant.mapping.is_some() {
                     mapping.push(quote! {
                         (#mapping_name, format!("#/components/schemas/{}", <#object_ty as #crate_name::types::Type>::schema_ref().unwrap_reference()))
                     });
                 }
 
-                if let Some(discriminator_name) = &args.discriminator_name {
+                if let (Some(discriminator_name), Some(content_name)) =
+                    (discriminator_name, content_name)
+                {
+                    schemas.push(quote! {
+                        #crate_name::registry::MetaSchemaRef::Reference(#variant_schema_name)
+                    });
+                    variant_registers.push(quote! {
+                        registry.create_schema::<Self, _>(#variant_schema_name, |_| #crate_name::registry::MetaSchema {
+                            title: ::std::option::Option::Some(::std::string::ToString::to_string(#mapping_name)),
+                            required: {
+                                let mut required = ::std::vec![#discriminator_name];
+                                if <#object_ty as #crate_name::types::Type>::IS_REQUIRED {
+                                    required.push(#content_name);
+                                }
+                                required
+                            },
+                            properties: ::std::vec![
+                                (
+                                    #discriminator_name,
+                                    #crate_name::registry::MetaSchemaRef::merge(
+                                        <::std::string::String as #crate_name::types::Type>::schema_ref(),
+                                        #crate_name::registry::MetaSchema {
+                                            example: ::std::option::Option::Some(::std::convert::Into::into(#mapping_name)),
+                                            ..#crate_name::registry::MetaSchema::ANY
+                                        }
+                                    )
+                                ),
+                                (#content_name, <#object_ty as #crate_name::types::Type>::schema_ref()),
+                            ],
+                            ..#crate_name::registry::MetaSchema::new("object")
+                        });
+                    });
+                } else if let Some(discriminator_name) = &args.discriminator_name {
                     schemas.push(quote! {
                         #crate_name::registry::MetaSchemaRef::Inline(::std::boxed::Box::new(#crate_name::registry::MetaSchema {
                             required: #required,
@@ -193,7 +278,12 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         Some(discriminator_name) => quote! {
             let discriminator_name = value.as_object().and_then(|obj| obj.get(#discriminator_name));
             #(#from_json)*
-            ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value))
+            match discriminator_name {
+                ::std::option::Option::Some(#crate_name::__private::serde_json::Value::String(discriminator_value)) => {
+                    ::std::result::Result::Err(#crate_name::types::ParseError::custom(::std::format!("unknown value `{}` of the discriminator `{}`.", discriminator_value, #discriminator_name)))
+                }
+                _ => ::std::result::Result::Err(#crate_name::types::ParseError::expected_type(value)),
+            }
         },
         // anyof
         None if !args.one_of => quote! {
@@ -231,9 +321,17 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         quote!(::std::vec![])
     };
 
+    // Without a discriminator, the variants are not necessarily objects, for
+    // example a string or an integer.
+    let ty = if args.discriminator_name.is_some() {
+        "object"
+    } else {
+        ""
+    };
+
     let meta = quote! {
         #crate_name::registry::MetaSchema {
-            ty: "object",
+            ty: #ty,
             description: #description,
             external_docs: #external_docs,
             one_of: #one_of,
@@ -248,6 +346,7 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
         let fn_register = quote! {
             registry.create_schema::<Self, _>(#oai_typename, |registry| {
                 #(<#types as #crate_name::types::Type>::register(registry);)*
+                #(#variant_registers)*
                 #meta
             });
         };
@@ -255,7 +354,10 @@ pub(crate) fn generate(args: DeriveInput) -> GeneratorResult<TokenStream> {
     } else {
         let fn_schema_ref =
             quote! { #crate_name::registry::MetaSchemaRef::Inline(Box::new(#meta)) };
-        let fn_register = quote! { #(<#types as #crate_name::types::Type>::register(registry);)* };
+        let fn_register = quote! {
+            #(<#types as #crate_name::types::Type>::register(registry);)*
+            #(#variant_registers)*
+        };
         (fn_schema_ref, fn_register)
     };
 
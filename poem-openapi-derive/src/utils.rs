@@ -74,6 +74,100 @@ pub(crate) fn optional_literal(s: &Option<impl AsRef<str>>) -> TokenStream {
     }
 }
 
+/// Returns `T` if the type is written as `Option<T>`.
+pub(crate) fn get_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let path = match ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => &ty.path,
+        _ => return None,
+    };
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns `true` if the type is written as `Option<T>` or `MaybeUndefined<T>`.
+pub(crate) fn is_optional_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "Option" || segment.ident == "MaybeUndefined")
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
+
+/// Returns where the parameter is located if the type is written as one of the
+/// parameter extractors, such as `Query<T>`.
+pub(crate) fn get_param_in(ty: &syn::Type) -> Option<&'static str> {
+    let segment = match ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty.path.segments.last()?,
+        _ => return None,
+    };
+    match segment.ident.to_string().as_str() {
+        "Query" => Some("query"),
+        "Path" => Some("path"),
+        "Header" => Some("header"),
+        "Cookie" | "CookiePrivate" | "CookieSigned" => Some("cookie"),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the type is written as `Query<T>`, where `T` is an array,
+/// a `Vec`, a `HashSet` or a `BTreeSet`, optionally wrapped in `Option`.
+pub(crate) fn is_array_query_param(ty: &syn::Type) -> bool {
+    if get_param_in(ty) != Some("query") {
+        return false;
+    }
+    let inner_ty = match ty {
+        syn::Type::Path(ty) => match &ty.path.segments.last().unwrap().arguments {
+            syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => {
+                match &args.args[0] {
+                    syn::GenericArgument::Type(ty) => ty,
+                    _ => return false,
+                }
+            }
+            _ => return false,
+        },
+        _ => return false,
+    };
+    match get_option_inner_type(inner_ty).unwrap_or(inner_ty) {
+        syn::Type::Array(_) => true,
+        syn::Type::Path(ty) if ty.qself.is_none() => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| {
+                segment.ident == "Vec" || segment.ident == "HashSet" || segment.ident == "BTreeSet"
+            })
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
+
+/// Returns `true` if the type is written as `HashMap<K, V>` or
+/// `BTreeMap<K, V>`.
+pub(crate) fn is_map_type(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident == "HashMap" || segment.ident == "BTreeMap")
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
+
 pub(crate) fn remove_oai_attrs(attrs: &mut Vec<Attribute>) {
     if let Some((idx, _)) = attrs
         .iter()
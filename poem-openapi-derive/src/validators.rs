@@ -2,7 +2,7 @@ use darling::{util::SpannedValue, FromMeta};
 use proc_macro2::TokenStream;
 use quote::quote;
 use regex::Regex;
-use syn::{Error, Expr, Type};
+use syn::{Error, Expr, Path, Type};
 
 use crate::{
     common_args::{MaximumValidator, MinimumValidator},
@@ -11,7 +11,9 @@ use crate::{
 
 struct ValidatorsTokenStream {
     container_validators: Vec<TokenStream>,
+    container_codes: Vec<&'static str>,
     elem_validators: Vec<TokenStream>,
+    elem_codes: Vec<&'static str>,
     custom_validators: Vec<TokenStream>,
 }
 
@@ -29,7 +31,21 @@ pub(crate) struct Validators {
     #[darling(default)]
     min_length: Option<SpannedValue<usize>>,
     #[darling(default)]
+    length_in_bytes: SpannedValue<bool>,
+    #[darling(default)]
     pattern: Option<SpannedValue<String>>,
+    #[darling(default)]
+    email: SpannedValue<bool>,
+    #[darling(default)]
+    uri: SpannedValue<bool>,
+    #[darling(default)]
+    hostname: SpannedValue<bool>,
+    #[darling(default)]
+    ipv4: SpannedValue<bool>,
+    #[darling(default)]
+    ipv6: SpannedValue<bool>,
+    #[darling(default)]
+    uuid: SpannedValue<bool>,
 
     // for containers
     #[darling(default)]
@@ -46,6 +62,8 @@ pub(crate) struct Validators {
     // custom validators for elements
     #[darling(default, multiple)]
     custom: Vec<SpannedValue<String>>,
+    #[darling(default, multiple)]
+    custom_fn: Vec<Path>,
 }
 
 impl Validators {
@@ -54,7 +72,9 @@ impl Validators {
         crate_name: &TokenStream,
     ) -> GeneratorResult<ValidatorsTokenStream> {
         let mut container_validators = Vec::new();
+        let mut container_codes = Vec::new();
         let mut elem_validators = Vec::new();
+        let mut elem_codes = Vec::new();
         let mut custom_validators = Vec::new();
 
         //////////////////////////////////////////////////////////////////////////////
@@ -72,28 +92,51 @@ impl Validators {
             }
             let value = &*value;
             elem_validators.push(quote!(#crate_name::validation::MultipleOf::new(#value)));
+            elem_codes.push("multiple_of");
         }
 
         if let Some(MaximumValidator { value, exclusive }) = self.maximum.as_deref() {
             // https://datatracker.ietf.org/doc/html/draft-wright-json-schema-validation-00#section-5.2
             elem_validators.push(quote!(#crate_name::validation::Maximum::new(#value, #exclusive)));
+            elem_codes.push("maximum");
         }
 
         if let Some(MinimumValidator { value, exclusive }) = self.minimum.as_deref() {
             // https://datatracker.ietf.org/doc/html/draft-wright-json-schema-validation-00#section-5.4
             elem_validators.push(quote!(#crate_name::validation::Minimum::new(#value, #exclusive)));
+            elem_codes.push("minimum");
+        }
+
+        if *self.length_in_bytes && self.max_length.is_none() && self.min_length.is_none() {
+            return Err(Error::new(
+                self.length_in_bytes.span(),
+                "The `length_in_bytes` validator requires `max_length` or `min_length`.",
+            )
+            .into());
         }
 
         if let Some(value) = self.max_length {
             // https://datatracker.ietf.org/doc/html/draft-wright-json-schema-validation-00#section-5.6
             let value = &*value;
-            elem_validators.push(quote!(#crate_name::validation::MaxLength::new(#value)));
+            let create = if *self.length_in_bytes {
+                quote!(bytes)
+            } else {
+                quote!(new)
+            };
+            elem_validators.push(quote!(#crate_name::validation::MaxLength::#create(#value)));
+            elem_codes.push("max_length");
         }
 
         if let Some(value) = self.min_length {
             // https://datatracker.ietf.org/doc/html/draft-wright-json-schema-validation-00#section-5.7
             let value = &*value;
-            elem_validators.push(quote!(#crate_name::validation::MinLength::new(#value)));
+            let create = if *self.length_in_bytes {
+                quote!(bytes)
+            } else {
+                quote!(new)
+            };
+            elem_validators.push(quote!(#crate_name::validation::MinLength::#create(#value)));
+            elem_codes.push("min_length");
         }
 
         if let Some(value) = &self.pattern {
@@ -107,6 +150,29 @@ impl Validators {
             }
             let value = &**value;
             elem_validators.push(quote!(#crate_name::validation::Pattern::new(#value)));
+            elem_codes.push("pattern");
+        }
+
+        let formats = [
+            (&self.email, quote!(email)),
+            (&self.uri, quote!(uri)),
+            (&self.hostname, quote!(hostname)),
+            (&self.ipv4, quote!(ipv4)),
+            (&self.ipv6, quote!(ipv6)),
+            (&self.uuid, quote!(uuid)),
+        ];
+        let mut formats = formats.iter().filter(|(enabled, _)| ***enabled);
+        if let Some((_, create)) = formats.next() {
+            // https://json-schema.org/draft/2020-12/json-schema-validation.html#rfc.section.7
+            if let Some((enabled, _)) = formats.next() {
+                return Err(Error::new(
+                    enabled.span(),
+                    "Only one format validator can be specified.",
+                )
+                .into());
+            }
+            elem_validators.push(quote!(#crate_name::validation::Format::#create()));
+            elem_codes.push("format");
         }
 
         //////////////////////////////////////////////////////////////////////////////
@@ -119,6 +185,10 @@ impl Validators {
             custom_validators.push(quote!(#create_custom_validator));
         }
 
+        for custom_fn in &self.custom_fn {
+            custom_validators.push(quote!(#crate_name::validation::FnValidator::new(#custom_fn)));
+        }
+
         //////////////////////////////////////////////////////////////////////////////
         // container validators
         //////////////////////////////////////////////////////////////////////////////
@@ -127,34 +197,41 @@ impl Validators {
             // https://datatracker.ietf.org/doc/html/draft-wright-json-schema-validation-00#section-5.10
             let value = &*value;
             container_validators.push(quote!(#crate_name::validation::MaxItems::new(#value)));
+            container_codes.push("max_items");
         }
 
         if let Some(value) = self.min_items {
             // https://datatracker.ietf.org/doc/html/draft-wright-json-schema-validation-00#section-5.11
             let value = &*value;
             container_validators.push(quote!(#crate_name::validation::MinItems::new(#value)));
+            container_codes.push("min_items");
         }
 
         if self.unique_items.map(|value| *value).unwrap_or_default() {
             // https://datatracker.ietf.org/doc/html/draft-wright-json-schema-validation-00#section-5.12
             container_validators.push(quote!(#crate_name::validation::UniqueItems::new()));
+            container_codes.push("unique_items");
         }
 
         if let Some(value) = self.max_properties {
             // https://json-schema.org/draft/2020-12/json-schema-validation.html#rfc.section.6.5.1
             let value = &*value;
             container_validators.push(quote!(#crate_name::validation::MaxProperties::new(#value)));
+            container_codes.push("max_properties");
         }
 
         if let Some(value) = self.min_properties {
             // https://json-schema.org/draft/2020-12/json-schema-validation.html#rfc.section.6.5.2
             let value = &*value;
             container_validators.push(quote!(#crate_name::validation::MinProperties::new(#value)));
+            container_codes.push("min_properties");
         }
 
         Ok(ValidatorsTokenStream {
             container_validators,
+            container_codes,
             elem_validators,
+            elem_codes,
             custom_validators,
         })
     }
@@ -166,17 +243,21 @@ impl Validators {
     ) -> GeneratorResult<TokenStream> {
         let ValidatorsTokenStream {
             container_validators,
+            container_codes,
             elem_validators,
+            elem_codes,
             custom_validators,
         } = self.create_validators(crate_name)?;
+        let custom_codes = vec!["custom"; custom_validators.len()];
+        let elem_codes = elem_codes.into_iter().chain(custom_codes);
         let elem_validators = elem_validators.into_iter().chain(custom_validators);
 
         Ok(quote! {
             #(
             for elem in #crate_name::types::Type::raw_element_iter(&value) {
                 let validator = #elem_validators;
-                if !#crate_name::validation::Validator::check(&validator, elem) {
-                    return Err(#crate_name::types::ParseError::<Self>::custom(format!("field `{}` verification failed. {}", #field_name, validator)));
+                if let ::std::result::Result::Err(message) = #crate_name::validation::Validator::validate(&validator, elem) {
+                    return Err(#crate_name::types::ParseError::<Self>::custom(format!("field `{}` verification failed. {}", #field_name, message)).with_field(#field_name, #elem_codes));
                 }
             }
             )*
@@ -184,8 +265,8 @@ impl Validators {
             #(
             if let ::std::option::Option::Some(value) = #crate_name::types::Type::as_raw_value(&value) {
                 let validator = #container_validators;
-                if !#crate_name::validation::Validator::check(&validator, value) {
-                    return Err(#crate_name::types::ParseError::<Self>::custom(format!("field `{}` verification failed. {}", #field_name, validator)));
+                if let ::std::result::Result::Err(message) = #crate_name::validation::Validator::validate(&validator, value) {
+                    return Err(#crate_name::types::ParseError::<Self>::custom(format!("field `{}` verification failed. {}", #field_name, message)).with_field(#field_name, #container_codes));
                 }
             }
             )*
@@ -202,6 +283,7 @@ impl Validators {
             container_validators,
             elem_validators,
             custom_validators,
+            ..
         } = self.create_validators(crate_name)?;
         let elem_validators = elem_validators.into_iter().chain(custom_validators);
 
@@ -209,10 +291,10 @@ impl Validators {
             #(
             if let ::std::option::Option::Some(value) = #crate_name::types::Type::as_raw_value(&value) {
                 let validator = #container_validators;
-                if !#crate_name::validation::Validator::check(&validator, value) {
+                if let ::std::result::Result::Err(message) = #crate_name::validation::Validator::validate(&validator, value) {
                     let err = #crate_name::error::ParseParamError {
                         name: #arg_name,
-                        reason: ::std::format!("verification failed. {}", validator),
+                        reason: ::std::format!("verification failed. {}", message),
                     };
 
                     if <#res_ty as #crate_name::ApiResponse>::BAD_REQUEST_HANDLER {
@@ -229,10 +311,10 @@ impl Validators {
             #(
             if let ::std::option::Option::Some(value) = #crate_name::types::Type::as_raw_value(&value) {
                 let validator = #elem_validators;
-                if !#crate_name::validation::Validator::check(&validator, value) {
+                if let ::std::result::Result::Err(message) = #crate_name::validation::Validator::validate(&validator, value) {
                     let err = #crate_name::error::ParseParamError {
                         name: #arg_name,
-                        reason: ::std::format!("verification failed. {}", validator),
+                        reason: ::std::format!("verification failed. {}", message),
                     };
 
                     if <#res_ty as #crate_name::ApiResponse>::BAD_REQUEST_HANDLER {
@@ -257,6 +339,7 @@ impl Validators {
             container_validators,
             elem_validators,
             custom_validators,
+            ..
         } = self.create_validators(crate_name)?;
         let elem_validators = elem_validators.into_iter().chain(custom_validators);
 
@@ -264,9 +347,9 @@ impl Validators {
             #(
             for item in #crate_name::types::Type::raw_element_iter(&value) {
                 let validator = #elem_validators;
-                if !#crate_name::validation::Validator::check(&validator, item) {
+                if let ::std::result::Result::Err(message) = #crate_name::validation::Validator::validate(&validator, item) {
                     return Err(::std::convert::Into::into(#crate_name::error::ParseMultipartError {
-                        reason: ::std::format!("field `{}` verification failed. {}", #field_name, validator),
+                        reason: ::std::format!("field `{}` verification failed. {}", #field_name, message),
                     }));
                 }
             }
@@ -275,9 +358,9 @@ impl Validators {
             #(
             if let ::std::option::Option::Some(value) = #crate_name::types::Type::as_raw_value(&value) {
                 let validator = #container_validators;
-                if !#crate_name::validation::Validator::check(&validator, value) {
+                if let ::std::result::Result::Err(message) = #crate_name::validation::Validator::validate(&validator, value) {
                     return Err(::std::convert::Into::into(#crate_name::error::ParseMultipartError {
-                        reason: ::std::format!("field `{}` verification failed. {}", #field_name, validator),
+                        reason: ::std::format!("field `{}` verification failed. {}", #field_name, message),
                     }));
                 }
             }
@@ -10,11 +10,11 @@ use syn::{
 };
 
 use crate::{
-    common_args::{APIMethod, DefaultValue, ExternalDocument},
+    common_args::{APIMethod, DefaultValue, Extension, ExternalDocument},
     error::GeneratorResult,
     utils::{
-        get_crate_name, get_description, get_summary_and_description, optional_literal,
-        parse_oai_attrs, remove_description, remove_oai_attrs, RemoveLifetime,
+        get_crate_name, get_description, get_summary_and_description, is_array_query_param,
+        optional_literal, parse_oai_attrs, remove_description, remove_oai_attrs, RemoveLifetime,
     },
     validators::Validators,
 };
@@ -40,6 +40,8 @@ struct WebhookOperation {
     operation_id: Option<String>,
     #[darling(default)]
     external_docs: Option<ExternalDocument>,
+    #[darling(default, multiple, rename = "extension")]
+    extensions: Vec<Extension>,
 }
 
 #[derive(FromMeta, Default)]
@@ -51,6 +53,8 @@ struct WebHookOperationParam {
     #[darling(default)]
     default: Option<DefaultValue>,
     #[darling(default)]
+    explode: Option<bool>,
+    #[darling(default)]
     validator: Option<Validators>,
 }
 
@@ -131,7 +135,9 @@ fn generate_operation(
         tags,
         operation_id,
         external_docs,
+        extensions,
     } = args;
+    let extensions = Extension::to_token_stream(&extensions, crate_name);
     let name = name.unwrap_or_else(|| trait_method.sig.ident.to_string());
     let http_method = method.to_http_method();
     let (summary, description) = get_summary_and_description(&trait_method.attrs)?;
@@ -221,6 +227,14 @@ fn generate_operation(
             .unwrap_or_else(|| arg_ident.unraw().to_string());
         let param_desc = optional_literal(&param_description);
         let deprecated = operation_param.deprecated;
+        if operation_param.explode.is_some() && !is_array_query_param(&arg_ty) {
+            return Err(Error::new_spanned(
+                arg_ident,
+                "The `explode` attribute can only be used with array query parameters.",
+            )
+            .into());
+        }
+        let explode = operation_param.explode.unwrap_or(true);
         params_meta.push(quote! {
             if <#arg_ty as #crate_name::ApiExtractor>::TYPE == #crate_name::ApiExtractorType::Parameter {
                 let mut original_schema = <#arg_ty as #crate_name::ApiExtractor>::param_schema_ref().unwrap();
@@ -239,6 +253,7 @@ fn generate_operation(
                     description: #param_desc,
                     required: <#arg_ty as #crate_name::ApiExtractor>::PARAM_IS_REQUIRED,
                     deprecated: #deprecated,
+                    explode: #explode,
                 };
                 params.push(meta_param);
             }
@@ -294,6 +309,8 @@ fn generate_operation(
                         deprecated: #deprecated,
                         security: ::std::vec![],
                         operation_id: #operation_id,
+                        max_body_size: ::std::option::Option::None,
+                        extensions: #extensions,
                     }
                 }
             },
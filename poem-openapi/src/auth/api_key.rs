@@ -22,7 +22,7 @@ impl ApiKeyAuthorization for ApiKey {
                 .get(name)
                 .cloned()
                 .map(|value| Self { key: value })
-                .ok_or_else(|| AuthorizationError.into()),
+                .ok_or_else(|| AuthorizationError::new().into()),
             MetaParamIn::Header => req
                 .headers()
                 .get(name)
@@ -30,7 +30,7 @@ impl ApiKeyAuthorization for ApiKey {
                 .map(|value| Self {
                     key: value.to_string(),
                 })
-                .ok_or_else(|| AuthorizationError.into()),
+                .ok_or_else(|| AuthorizationError::new().into()),
             MetaParamIn::Cookie => req
                 .cookie()
                 .get(name)
@@ -38,7 +38,7 @@ impl ApiKeyAuthorization for ApiKey {
                 .map(|cookie| Self {
                     key: cookie.value_str().to_string(),
                 })
-                .ok_or_else(|| AuthorizationError.into()),
+                .ok_or_else(|| AuthorizationError::new().into()),
             _ => unreachable!(),
         }
     }
@@ -34,6 +34,6 @@ impl BasicAuthorization for Basic {
             }
         }
 
-        Err(AuthorizationError.into())
+        Err(AuthorizationError::with_challenge("Basic").into())
     }
 }
@@ -21,6 +21,6 @@ impl BearerAuthorization for Bearer {
             }
         }
 
-        Err(AuthorizationError.into())
+        Err(AuthorizationError::with_challenge("Bearer").into())
     }
 }
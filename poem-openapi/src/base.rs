@@ -1,13 +1,17 @@
 use std::ops::Deref;
 
-use poem::{Error, FromRequest, Request, RequestBody, Result, Route};
+use poem::{
+    error::ReadBodyError, http::header, web::Field as PoemField, Error, FromRequest, Request,
+    RequestBody, Result, Route,
+};
 
 use crate::{
     payload::Payload,
     registry::{
         MetaApi, MetaMediaType, MetaOAuthScope, MetaParamIn, MetaRequest, MetaResponse,
-        MetaResponses, MetaSchemaRef, MetaWebhook, Registry,
+        MetaResponses, MetaSchema, MetaSchemaRef, MetaWebhook, Registry,
     },
+    types::{ParseError, ParseFromJSON, ParseResult, Type},
 };
 
 /// API extractor types.
@@ -52,6 +56,214 @@ impl UrlQuery {
     }
 }
 
+#[doc(hidden)]
+#[derive(Debug, Copy, Clone)]
+pub struct MaxBodySize(pub usize);
+
+/// Rejects the request with [`ReadBodyError::PayloadTooLarge`] if the body is
+/// larger than the limit of the operation, or the global limit of the
+/// service.
+///
+/// The body is read with the limit even if the `Content-Length` header is
+/// within the limit, because the header may not match the actual body.
+#[doc(hidden)]
+pub async fn check_body_size(
+    request: &Request,
+    body: &mut RequestBody,
+    max_body_size: Option<usize>,
+) -> Result<()> {
+    let max_body_size = match max_body_size
+        .or_else(|| request.extensions().get::<MaxBodySize>().map(|size| size.0))
+    {
+        Some(max_body_size) => max_body_size,
+        None => return Ok(()),
+    };
+
+    if let Some(len) = request
+        .header(header::CONTENT_LENGTH)
+        .and_then(|value| value.parse::<usize>().ok())
+    {
+        if len > max_body_size {
+            return Err(ReadBodyError::PayloadTooLarge.into());
+        }
+    }
+
+    let data = body.take()?.into_bytes_limit(max_body_size).await?;
+    *body = RequestBody::new(data.into());
+    Ok(())
+}
+
+/// Adds the `422 Unprocessable Entity` response with the structured errors
+/// returned by [`ParseJsonError`](crate::error::ParseJsonError) and
+/// [`ParseFormError`](crate::error::ParseFormError) if the request body has
+/// validators.
+///
+/// If the operation declares a `422` response itself, both are combined in the
+/// document.
+#[doc(hidden)]
+pub fn add_validation_error_response<'a, T: ApiExtractor<'a>>(responses: &mut MetaResponses) {
+    let request = match T::request_meta() {
+        Some(request) => request,
+        None => return,
+    };
+    let mut registry = Registry::new();
+    T::register(&mut registry);
+    if !request
+        .content
+        .iter()
+        .any(|content| registry.has_validators(&content.schema))
+    {
+        return;
+    }
+
+    let field_error = MetaSchema {
+        required: vec!["field", "code"],
+        properties: vec![
+            ("field", String::schema_ref()),
+            ("code", String::schema_ref()),
+        ],
+        ..MetaSchema::new("object")
+    };
+    let schema = MetaSchema {
+        required: vec!["errors"],
+        properties: vec![(
+            "errors",
+            MetaSchemaRef::Inline(Box::new(MetaSchema {
+                items: Some(Box::new(MetaSchemaRef::Inline(Box::new(field_error)))),
+                ..MetaSchema::new("array")
+            })),
+        )],
+        ..MetaSchema::new("object")
+    };
+    responses.responses.push(MetaResponse {
+        description: "Validation error",
+        status: Some(422),
+        content: vec![MetaMediaType {
+            content_type: "application/json",
+            schema: MetaSchemaRef::Inline(Box::new(schema)),
+            examples: vec![],
+        }],
+        headers: vec![],
+    });
+}
+
+/// Renames the properties of a flattened object with the `rename_all` rule of
+/// the parent object.
+///
+/// The derived object creates it once for each flattened field and keeps it in
+/// a static, so that the renamed properties can be used in the schema.
+#[doc(hidden)]
+pub struct FlattenRenamer {
+    names: Vec<(&'static str, String)>,
+}
+
+impl FlattenRenamer {
+    /// Returns `None` if the flattened type is composed with `allOf` instead
+    /// of being merged into the parent object.
+    pub fn new<T: Type>(compose_ref: bool, rename: fn(&str) -> String) -> Option<Self> {
+        let mut registry = Registry::new();
+        let schema = registry.create_fake_schema::<T>();
+        if !schema.any_of.is_empty()
+            || !schema.one_of.is_empty()
+            || (compose_ref && matches!(T::schema_ref(), MetaSchemaRef::Reference(_)))
+        {
+            return None;
+        }
+
+        let names = schema
+            .properties
+            .iter()
+            .map(|(name, _)| (*name, rename(name)))
+            .collect();
+        Some(Self { names })
+    }
+
+    #[allow(missing_docs)]
+    pub fn rename<'a>(&'a self, name: &'a str) -> &'a str {
+        self.names
+            .iter()
+            .find(|(original, _)| *original == name)
+            .map(|(_, renamed)| renamed.as_str())
+            .unwrap_or(name)
+    }
+
+    /// Converts the renamed properties of the parent object to the input of
+    /// the flattened type.
+    pub fn to_original(
+        &self,
+        obj: &serde_json::Map<String, serde_json::Value>,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        self.names
+            .iter()
+            .filter_map(|(original, renamed)| {
+                obj.get(renamed)
+                    .map(|value| (original.to_string(), value.clone()))
+            })
+            .collect()
+    }
+
+    /// Renames the properties serialized by the flattened type.
+    pub fn to_renamed(
+        &self,
+        obj: serde_json::Map<String, serde_json::Value>,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        obj.into_iter()
+            .map(|(name, value)| {
+                let name = match self.names.iter().find(|(original, _)| *original == name) {
+                    Some((_, renamed)) => renamed.clone(),
+                    None => name,
+                };
+                (name, value)
+            })
+            .collect()
+    }
+}
+
+/// Parses a field with `#[oai(coerce)]`, a string that fails to parse is
+/// parsed again as a number or a boolean.
+#[doc(hidden)]
+pub fn parse_coerced_json<T: ParseFromJSON>(value: Option<serde_json::Value>) -> ParseResult<T> {
+    match value {
+        Some(serde_json::Value::String(s)) => {
+            T::parse_from_json(Some(serde_json::Value::String(s.clone()))).or_else(|err| {
+                match serde_json::from_str(s.trim()) {
+                    Ok(value @ (serde_json::Value::Number(_) | serde_json::Value::Bool(_))) => {
+                        T::parse_from_json(Some(value)).map_err(|_| err)
+                    }
+                    _ => Err(err),
+                }
+            })
+        }
+        value => T::parse_from_json(value),
+    }
+}
+
+/// Checks the condition of `#[oai(required_if)]`, `expected` matches a string
+/// with the same content, or a value with the same JSON representation.
+#[doc(hidden)]
+pub fn json_value_equals(value: Option<serde_json::Value>, expected: &str) -> bool {
+    match value {
+        Some(serde_json::Value::String(s)) => s == expected,
+        Some(value) => serde_json::from_str::<serde_json::Value>(expected).ok() == Some(value),
+        None => false,
+    }
+}
+
+/// Parses a multipart field whose content is a JSON value.
+#[doc(hidden)]
+pub async fn parse_json_multipart_field<T: ParseFromJSON>(
+    field: Option<PoemField>,
+) -> ParseResult<T> {
+    let value = match field {
+        Some(field) => {
+            let data = field.bytes().await.map_err(ParseError::custom)?;
+            serde_json::from_slice(&data).map_err(ParseError::custom)?
+        }
+        None => serde_json::Value::Null,
+    };
+    T::parse_from_json(Some(value))
+}
+
 /// Options for the parameter extractor.
 pub struct ExtractParamOptions<T> {
     /// The name of this parameter.
@@ -59,6 +271,10 @@ pub struct ExtractParamOptions<T> {
 
     /// The default value of this parameter.
     pub default_value: Option<fn() -> T>,
+
+    /// When this is `false`, the values of an array parameter are separated
+    /// by commas, for example `a=1,2,3` instead of `a=1&a=2&a=3`.
+    pub explode: bool,
 }
 
 impl<T> Default for ExtractParamOptions<T> {
@@ -66,6 +282,7 @@ impl<T> Default for ExtractParamOptions<T> {
         Self {
             name: "",
             default_value: None,
+            explode: true,
         }
     }
 }
@@ -218,6 +435,7 @@ impl<T: Payload> ResponseContent for T {
         vec![MetaMediaType {
             content_type: T::CONTENT_TYPE,
             schema: T::schema_ref(),
+            examples: T::examples(),
         }]
     }
 
@@ -1,8 +1,14 @@
 //! Some common error types.
 
-use poem::{error::ResponseError, http::StatusCode};
+use poem::{
+    error::ResponseError,
+    http::{header, StatusCode},
+    Response,
+};
 use thiserror::Error;
 
+use crate::types::FieldError;
+
 /// Parameter error.
 #[derive(Debug, Error)]
 #[error("failed to parse parameter `{name}`: {reason}")]
@@ -20,18 +26,177 @@ impl ResponseError for ParseParamError {
     }
 }
 
+/// The position of a syntax error in a JSON document.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct JsonErrorPosition {
+    /// The line number, starting at 1.
+    pub line: usize,
+
+    /// The column number in bytes, starting at 1.
+    pub column: usize,
+
+    /// The byte offset from the start of the document.
+    pub offset: usize,
+}
+
 /// Parse JSON error.
+///
+/// If the JSON is malformed, `position` is where the syntax error occurs, and
+/// the reason mentions the line, column and byte offset.
+///
+/// If the JSON is well-formed but does not match the schema, `errors` contains
+/// the structured errors, the status is `422 Unprocessable Entity` and the
+/// response body will be a JSON object like this:
+///
+/// ```json
+/// { "errors": [ { "field": "/a/b", "code": "maximum" } ] }
+/// ```
 #[derive(Debug, Error)]
 #[error("parse JSON error: {reason}")]
+#[non_exhaustive]
 pub struct ParseJsonError {
     /// The reason for the error.
     pub reason: String,
+
+    /// The position of the syntax error.
+    pub position: Option<JsonErrorPosition>,
+
+    /// The structured errors for each failed field.
+    pub errors: Vec<FieldError>,
+}
+
+impl ParseJsonError {
+    /// Create a parse JSON error with the specified reason.
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            position: None,
+            errors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn syntax(err: serde_json::Error, data: &[u8]) -> Self {
+        if err.line() == 0 {
+            return Self::new(err.to_string());
+        }
+
+        let line_start: usize = data
+            .split(|b| *b == b'\n')
+            .take(err.line() - 1)
+            .map(|line| line.len() + 1)
+            .sum();
+        let position = JsonErrorPosition {
+            line: err.line(),
+            column: err.column(),
+            offset: (line_start + err.column().saturating_sub(1)).min(data.len()),
+        };
+        Self {
+            reason: format!("{} (byte offset {})", err, position.offset),
+            position: Some(position),
+            errors: Vec::new(),
+        }
+    }
 }
 
 impl ResponseError for ParseJsonError {
+    fn status(&self) -> StatusCode {
+        if self.errors.is_empty() {
+            StatusCode::BAD_REQUEST
+        } else {
+            StatusCode::UNPROCESSABLE_ENTITY
+        }
+    }
+
+    fn as_response(&self) -> Response {
+        if self.errors.is_empty() {
+            return Response::builder()
+                .status(self.status())
+                .body(self.to_string());
+        }
+
+        let body = serde_json::json!({ "errors": self.errors });
+        Response::builder()
+            .status(self.status())
+            .content_type("application/json")
+            .body(body.to_string())
+    }
+}
+
+/// Parse form error.
+///
+/// If the form is well-formed but does not match the schema, `errors` contains
+/// the structured errors, and the response is the same as
+/// [`ParseJsonError`].
+#[derive(Debug, Error)]
+#[error("parse form error: {reason}")]
+pub struct ParseFormError {
+    /// The reason for the error.
+    pub reason: String,
+
+    /// The structured errors for each failed field.
+    pub errors: Vec<FieldError>,
+}
+
+impl ResponseError for ParseFormError {
+    fn status(&self) -> StatusCode {
+        if self.errors.is_empty() {
+            StatusCode::BAD_REQUEST
+        } else {
+            StatusCode::UNPROCESSABLE_ENTITY
+        }
+    }
+
+    fn as_response(&self) -> Response {
+        if self.errors.is_empty() {
+            return Response::builder()
+                .status(self.status())
+                .body(self.to_string());
+        }
+
+        let body = serde_json::json!({ "errors": self.errors });
+        Response::builder()
+            .status(self.status())
+            .content_type("application/json")
+            .body(body.to_string())
+    }
+}
+
+/// Parse query string error.
+///
+/// If the query string does not match the schema, `errors` contains the
+/// structured errors and the response body will be a JSON object like this:
+///
+/// ```json
+/// { "errors": [ { "field": "/age", "code": "maximum" } ] }
+/// ```
+#[derive(Debug, Error)]
+#[error("parse query string error: {reason}")]
+pub struct ParseQueryError {
+    /// The reason for the error.
+    pub reason: String,
+
+    /// The structured errors for each failed field.
+    pub errors: Vec<FieldError>,
+}
+
+impl ResponseError for ParseQueryError {
     fn status(&self) -> StatusCode {
         StatusCode::BAD_REQUEST
     }
+
+    fn as_response(&self) -> Response {
+        if self.errors.is_empty() {
+            return Response::builder()
+                .status(self.status())
+                .body(self.to_string());
+        }
+
+        let body = serde_json::json!({ "errors": self.errors });
+        Response::builder()
+            .status(self.status())
+            .content_type("application/json")
+            .body(body.to_string())
+    }
 }
 
 /// Parse multipart error.
@@ -70,12 +235,41 @@ impl ResponseError for ContentTypeError {
 }
 
 /// Authorization error.
-#[derive(Debug, Error)]
+///
+/// If `challenge` is set, the response will include a `WWW-Authenticate`
+/// header with this value, for example `Bearer`.
+#[derive(Debug, Default, Error)]
 #[error("authorization error")]
-pub struct AuthorizationError;
+#[non_exhaustive]
+pub struct AuthorizationError {
+    /// The challenge for the `WWW-Authenticate` header.
+    pub challenge: Option<&'static str>,
+}
+
+impl AuthorizationError {
+    /// Create an authorization error without a challenge.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create an authorization error with the specified challenge.
+    pub fn with_challenge(challenge: &'static str) -> Self {
+        Self {
+            challenge: Some(challenge),
+        }
+    }
+}
 
 impl ResponseError for AuthorizationError {
     fn status(&self) -> StatusCode {
         StatusCode::UNAUTHORIZED
     }
+
+    fn as_response(&self) -> Response {
+        let mut resp = Response::builder().status(self.status());
+        if let Some(challenge) = self.challenge {
+            resp = resp.header(header::WWW_AUTHENTICATE, challenge);
+        }
+        resp.body(self.to_string())
+    }
 }
@@ -94,6 +94,7 @@
 //! | Feature    | Description |
 //! |------------|-----------------------------------------------------------------------|
 //! | chrono     | Integrate with the [`chrono` crate](https://crates.io/crates/chrono). |
+//! | time       | Integrate with the [`time` crate](https://crates.io/crates/time). |
 //! | swagger-ui | Add swagger UI support |
 //! | rapidoc    | Add RapiDoc UI support |
 //! | redoc      | Add Redoc UI support |
@@ -103,6 +104,7 @@
 //! | url        | Integrate with the [`url` crate](https://crates.io/crates/url) |
 //! | bson        | Integrate with the [`bson` crate](https://crates.io/crates/bson) |
 //! | static-files | Support for static file response |
+//! | preserve-order | Serialize the fields of objects in declaration order |
 
 #![doc(html_favicon_url = "https://raw.githubusercontent.com/poem-web/poem/master/favicon.ico")]
 #![doc(html_logo_url = "https://raw.githubusercontent.com/poem-web/poem/master/logo.png")]
@@ -164,10 +166,15 @@ pub use validation::Validator;
 
 #[doc(hidden)]
 pub mod __private {
+    pub use inflector;
+    pub use lazy_static;
     pub use mime;
     pub use poem;
     pub use serde;
     pub use serde_json;
 
-    pub use crate::base::UrlQuery;
+    pub use crate::base::{
+        add_validation_error_response, check_body_size, json_value_equals, parse_coerced_json,
+        parse_json_multipart_field, FlattenRenamer, MaxBodySize, UrlQuery,
+    };
 }
@@ -21,6 +21,7 @@ macro_rules! impl_apirequest_for_payload {
                     content: vec![$crate::registry::MetaMediaType {
                         content_type: <Self as $crate::payload::Payload>::CONTENT_TYPE,
                         schema: <Self as $crate::payload::Payload>::schema_ref(),
+                        examples: <Self as $crate::payload::Payload>::examples(),
                     }],
                     required: <Self as $crate::payload::ParsePayload>::IS_REQUIRED,
                 })
@@ -8,7 +8,7 @@ use poem::{
 };
 
 use crate::{
-    base::UrlQuery,
+    base::{MaxBodySize, UrlQuery},
     registry::{Document, MetaExternalDocument, MetaInfo, MetaLicense, MetaServer, Registry},
     OpenApi, Webhook,
 };
@@ -127,6 +127,7 @@ pub struct OpenApiService<T, W: ?Sized> {
     external_document: Option<MetaExternalDocument>,
     servers: Vec<MetaServer>,
     cookie_key: Option<CookieKey>,
+    max_body_size: Option<usize>,
 }
 
 impl<T> OpenApiService<T, ()> {
@@ -147,6 +148,7 @@ impl<T> OpenApiService<T, ()> {
             external_document: None,
             servers: Vec::new(),
             cookie_key: None,
+            max_body_size: None,
         }
     }
 }
@@ -161,6 +163,7 @@ impl<T, W: ?Sized> OpenApiService<T, W> {
             external_document: self.external_document,
             servers: self.servers,
             cookie_key: self.cookie_key,
+            max_body_size: self.max_body_size,
         }
     }
 
@@ -237,6 +240,20 @@ impl<T, W: ?Sized> OpenApiService<T, W> {
         }
     }
 
+    /// Sets the maximum size of the request body for all operations that do
+    /// not declare their own `max_body_size`.
+    ///
+    /// Larger requests are rejected with `413 Payload Too Large`, and the
+    /// limit is declared in the specification with the `x-maxBodySize`
+    /// extension of each operation.
+    #[must_use]
+    pub fn max_body_size(self, max_body_size: usize) -> Self {
+        Self {
+            max_body_size: Some(max_body_size),
+            ..self
+        }
+    }
+
     /// Create the Swagger UI endpoint.
     #[must_use]
     #[cfg(feature = "swagger-ui")]
@@ -245,7 +262,16 @@ impl<T, W: ?Sized> OpenApiService<T, W> {
         T: OpenApi,
         W: Webhook,
     {
-        crate::ui::swagger_ui::create_endpoint(&self.spec())
+        crate::ui::swagger_ui::create_endpoint(crate::ui::Spec::Document(&self.spec()))
+    }
+
+    /// Create the Swagger UI endpoint that fetches the specification from the
+    /// specified URL instead of embedding it, for example the path of
+    /// [`OpenApiService::spec_endpoint`].
+    #[must_use]
+    #[cfg(feature = "swagger-ui")]
+    pub fn swagger_ui_with_spec_url(&self, url: &str) -> impl Endpoint {
+        crate::ui::swagger_ui::create_endpoint(crate::ui::Spec::Url(url))
     }
 
     /// Create the Rapidoc endpoint.
@@ -256,7 +282,16 @@ impl<T, W: ?Sized> OpenApiService<T, W> {
         T: OpenApi,
         W: Webhook,
     {
-        crate::ui::rapidoc::create_endpoint(&self.spec())
+        crate::ui::rapidoc::create_endpoint(crate::ui::Spec::Document(&self.spec()))
+    }
+
+    /// Create the Rapidoc endpoint that fetches the specification from the
+    /// specified URL instead of embedding it, for example the path of
+    /// [`OpenApiService::spec_endpoint`].
+    #[must_use]
+    #[cfg(feature = "rapidoc")]
+    pub fn rapidoc_with_spec_url(&self, url: &str) -> impl Endpoint {
+        crate::ui::rapidoc::create_endpoint(crate::ui::Spec::Url(url))
     }
 
     /// Create the Redoc endpoint.
@@ -267,7 +302,16 @@ impl<T, W: ?Sized> OpenApiService<T, W> {
         T: OpenApi,
         W: Webhook,
     {
-        crate::ui::redoc::create_endpoint(&self.spec())
+        crate::ui::redoc::create_endpoint(crate::ui::Spec::Document(&self.spec()))
+    }
+
+    /// Create the Redoc endpoint that fetches the specification from the
+    /// specified URL instead of embedding it, for example the path of
+    /// [`OpenApiService::spec_endpoint`].
+    #[must_use]
+    #[cfg(feature = "redoc")]
+    pub fn redoc_with_spec_url(&self, url: &str) -> impl Endpoint {
+        crate::ui::redoc::create_endpoint(crate::ui::Spec::Url(url))
     }
 
     /// Create an endpoint to serve the open api specification.
@@ -291,12 +335,22 @@ impl<T, W: ?Sized> OpenApiService<T, W> {
         W: Webhook,
     {
         let mut registry = Registry::new();
-        let metadata = T::meta();
+        let mut metadata = T::meta();
         T::register(&mut registry);
         W::register(&mut registry);
 
         let webhooks = W::meta();
 
+        if let Some(max_body_size) = self.max_body_size {
+            for operation in metadata
+                .iter_mut()
+                .flat_map(|api| api.paths.iter_mut())
+                .flat_map(|path| path.operations.iter_mut())
+            {
+                operation.max_body_size.get_or_insert(max_body_size);
+            }
+        }
+
         let mut doc = Document {
             info: &self.info,
             servers: &self.servers,
@@ -339,11 +393,68 @@ impl<T: OpenApi, W: Webhook> IntoEndpoint for OpenApiService<T, W> {
             }
         }
 
+        let max_body_size = self.max_body_size;
+
         self.api
             .add_routes(Route::new())
             .with(cookie_jar_manager)
             .before(extract_query)
+            .before(move |mut req| async move {
+                if let Some(max_body_size) = max_body_size {
+                    req.extensions_mut().insert(MaxBodySize(max_body_size));
+                }
+                Ok(req)
+            })
             .map_to_response()
             .boxed()
     }
 }
+
+#[cfg(all(test, feature = "swagger-ui"))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn swagger_ui_with_spec_url() {
+        use poem::http::Uri;
+
+        struct Api;
+
+        #[crate::OpenApi(internal)]
+        impl Api {
+            #[oai(path = "/hello", method = "get")]
+            async fn hello(&self) {}
+        }
+
+        let api_service = OpenApiService::new(Api, "test", "1.0");
+        let app = Route::new()
+            .at("/openapi.json", api_service.spec_endpoint())
+            .nest(
+                "/docs",
+                api_service.swagger_ui_with_spec_url("/openapi.json"),
+            )
+            .nest("/", api_service);
+
+        let resp = app
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/openapi.json"))
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.content_type(), Some("application/json"));
+        let spec: serde_json::Value =
+            serde_json::from_str(&resp.into_body().into_string().await.unwrap()).unwrap();
+        assert!(spec["paths"]["/hello"].is_object());
+
+        let resp = app
+            .call(Request::builder().uri(Uri::from_static("/docs")).finish())
+            .await
+            .unwrap();
+        assert!(resp.content_type().unwrap().starts_with("text/html"));
+        let html = resp.into_body().into_string().await.unwrap();
+        assert!(html.contains(r#"let spec = "/openapi.json";"#));
+        assert!(html.contains("url: spec,"));
+    }
+}
@@ -3,8 +3,10 @@ mod cookie;
 mod header;
 mod path;
 mod query;
+mod query_object;
 
 pub use cookie::{Cookie, CookiePrivate, CookieSigned};
 pub use header::Header;
 pub use path::Path;
 pub use query::Query;
+pub use query_object::QueryObject;
@@ -10,6 +10,9 @@ use crate::{
 };
 
 /// Represents the parameters passed by the URI path.
+///
+/// It only reads the path, a query parameter with the same name is ignored,
+/// use [`Query`](crate::param::Query) to read it.
 pub struct Path<T>(pub T);
 
 impl<T> Deref for Path<T> {
@@ -11,6 +11,9 @@ use crate::{
 };
 
 /// Represents the parameters passed by the query string.
+///
+/// It only reads the query string, a path parameter with the same name is
+/// ignored, use [`Path`](crate::param::Path) to read it.
 pub struct Query<T>(pub T);
 
 impl<T> Deref for Query<T> {
@@ -61,6 +64,10 @@ impl<'a, T: ParseFromParameter> ApiExtractor<'a> for Query<T> {
             .get::<UrlQuery>()
             .unwrap()
             .get_all(param_opts.name)
+            .flat_map(|value| match param_opts.explode {
+                true => vec![value.as_str()],
+                false => value.split(',').collect(),
+            })
             .peekable();
 
         match &param_opts.default_value {
@@ -0,0 +1,99 @@
+use std::ops::{Deref, DerefMut};
+
+use poem::{FromRequestParts, Request, Result};
+
+use crate::{error::ParseQueryError, payload::FormFields, types::ParseFromJSON};
+
+/// An extractor that parses the whole query string into an
+/// [`Object`](crate::Object) and runs its validators.
+///
+/// The query string is converted to a JSON object in the same way as
+/// [`Form`](crate::payload::Form), so repeated keys of an `array` property are
+/// collected, the last value is used if any other key is repeated, and
+/// optional properties may be omitted.
+///
+/// It is an extractor of Poem rather than an operation parameter, so it can be
+/// used in the handlers of Poem along with `poem::web::Path`, if the query
+/// string is invalid it responds with `400 Bad Request` and the error of each
+/// field, see [`ParseQueryError`].
+///
+/// ```
+/// use poem::{
+///     get, handler,
+///     http::{StatusCode, Uri},
+///     Endpoint, Request, Route,
+/// };
+/// use poem_openapi::{param::QueryObject, Object};
+///
+/// #[derive(Object)]
+/// struct Search {
+///     #[oai(validator(max_length = 10))]
+///     q: String,
+///     page: Option<u32>,
+/// }
+///
+/// #[handler]
+/// fn search(QueryObject(search): QueryObject<Search>) -> String {
+///     format!("{}:{}", search.q, search.page.unwrap_or(1))
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let app = Route::new().at("/search", get(search));
+///
+/// let resp = app
+///     .call(
+///         Request::builder()
+///             .uri(Uri::from_static("/search?q=poem&page=2"))
+///             .finish(),
+///     )
+///     .await
+///     .unwrap();
+/// assert_eq!(resp.into_body().into_string().await.unwrap(), "poem:2");
+///
+/// let resp = app
+///     .get_response(
+///         Request::builder()
+///             .uri(Uri::from_static("/search?q=poem-openapi-rs"))
+///             .finish(),
+///     )
+///     .await;
+/// assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+/// # });
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct QueryObject<T>(pub T);
+
+impl<T> Deref for QueryObject<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for QueryObject<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[poem::async_trait]
+impl<'a, T: ParseFromJSON + 'static> FromRequestParts<'a> for QueryObject<T> {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
+        let pairs: Vec<(String, String)> =
+            serde_urlencoded::from_str(req.uri().query().unwrap_or_default()).map_err(|err| {
+                ParseQueryError {
+                    reason: err.to_string(),
+                    errors: Vec::new(),
+                }
+            })?;
+
+        let value = FormFields::get::<T>().to_json(pairs);
+
+        let value = T::parse_from_json(Some(value)).map_err(|err| ParseQueryError {
+            errors: vec![err.field_error()],
+            reason: err.into_message(),
+        })?;
+        Ok(Self(value))
+    }
+}
@@ -11,6 +11,11 @@ use crate::{
 
 /// A binary payload.
 ///
+/// It is sent as `application/octet-stream` and described as a string with
+/// the `binary` format. For specific media types such as `image/png`, set the
+/// `content_type` attribute on an item of
+/// [`ApiRequest`](crate::ApiRequest) or [`ApiResponse`](crate::ApiResponse).
+///
 /// # Examples
 ///
 /// ```rust
@@ -145,6 +150,7 @@ impl<T: Into<Body> + Send> ApiResponse for Binary<T> {
                 content: vec![MetaMediaType {
                     content_type: Self::CONTENT_TYPE,
                     schema: Self::schema_ref(),
+                    examples: vec![],
                 }],
                 headers: vec![],
             }],
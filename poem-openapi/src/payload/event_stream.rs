@@ -82,6 +82,7 @@ impl<T: Stream<Item = E> + Send + 'static, E: Type + ToJSON> ApiResponse for Eve
                 content: vec![MetaMediaType {
                     content_type: Self::CONTENT_TYPE,
                     schema: Self::schema_ref(),
+                    examples: vec![],
                 }],
                 headers: vec![],
             }],
@@ -0,0 +1,202 @@
+use std::{
+    any::TypeId,
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    sync::{Arc, RwLock},
+};
+
+use poem::{Request, RequestBody, Result};
+use serde_json::{Map, Value};
+
+use crate::{
+    error::ParseFormError,
+    payload::{ParsePayload, Payload},
+    registry::{MetaExample, MetaSchema, MetaSchemaRef, Registry},
+    types::{ParseFromJSON, Type},
+};
+
+/// A form payload, the content type is `application/x-www-form-urlencoded`.
+///
+/// The fields are converted to a JSON object according to the schema of `T`,
+/// for example a field of an `integer` property is converted to a number and
+/// repeated fields of an `array` property are collected, and then parsed
+/// with [`ParseFromJSON`], so the same [`Object`](crate::Object) can be
+/// accepted as JSON and as a form with [`ApiRequest`](crate::ApiRequest).
+/// The last value is used if any other field is repeated.
+///
+/// ```
+/// use poem_openapi::{
+///     payload::{Form, Json},
+///     ApiRequest, Object,
+/// };
+///
+/// #[derive(Object)]
+/// struct Pet {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// #[derive(ApiRequest)]
+/// enum CreatePet {
+///     Json(Json<Pet>),
+///     Form(Form<Pet>),
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Form<T>(pub T);
+
+impl<T> Deref for Form<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Form<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Type> Payload for Form<T> {
+    const CONTENT_TYPE: &'static str = "application/x-www-form-urlencoded";
+
+    fn schema_ref() -> MetaSchemaRef {
+        T::schema_ref()
+    }
+
+    #[allow(unused_variables)]
+    fn register(registry: &mut Registry) {
+        T::register(registry);
+    }
+
+    fn examples() -> Vec<MetaExample> {
+        T::examples()
+    }
+}
+
+#[poem::async_trait]
+impl<T: ParseFromJSON + 'static> ParsePayload for Form<T> {
+    const IS_REQUIRED: bool = true;
+
+    async fn from_request(_request: &Request, body: &mut RequestBody) -> Result<Self> {
+        let data = body.take()?.into_vec().await?;
+        let pairs: Vec<(String, String)> =
+            serde_urlencoded::from_bytes(&data).map_err(|err| ParseFormError {
+                reason: err.to_string(),
+                errors: Vec::new(),
+            })?;
+
+        let value = FormFields::get::<T>().to_json(pairs);
+
+        let value = T::parse_from_json(Some(value)).map_err(|err| ParseFormError {
+            errors: vec![err.field_error()],
+            reason: err.into_message(),
+        })?;
+        Ok(Self(value))
+    }
+}
+
+impl_apirequest_for_payload!(Form<T>, T: ParseFromJSON + 'static);
+
+fn resolve<'a>(registry: &'a Registry, schema: &'a MetaSchemaRef) -> Option<&'a MetaSchema> {
+    match schema {
+        MetaSchemaRef::Inline(schema) => Some(schema),
+        MetaSchemaRef::Reference(name) => registry.schemas.get(name),
+    }
+}
+
+/// The types of the properties of `T`, an array property is represented by
+/// the type of its items.
+pub(crate) struct FormFields {
+    fields: HashMap<&'static str, (&'static str, bool)>,
+}
+
+impl FormFields {
+    /// The fields are created once for each type.
+    pub(crate) fn get<T: Type + 'static>() -> Arc<FormFields> {
+        lazy_static::lazy_static! {
+            static ref FIELDS: RwLock<HashMap<TypeId, Arc<FormFields>>> = Default::default();
+        }
+
+        let key = TypeId::of::<T>();
+        if let Some(fields) = FIELDS.read().unwrap().get(&key) {
+            return fields.clone();
+        }
+
+        let fields = Arc::new(Self::create::<T>());
+        FIELDS.write().unwrap().entry(key).or_insert(fields).clone()
+    }
+
+    fn create<T: Type>() -> Self {
+        let mut registry = Registry::new();
+        T::register(&mut registry);
+        let schema_ref = T::schema_ref();
+        let fields = resolve(&registry, &schema_ref)
+            .map(|schema| {
+                schema
+                    .properties
+                    .iter()
+                    .filter_map(|(name, property)| {
+                        let property = resolve(&registry, property)?;
+                        if property.ty == "array" {
+                            let item = property
+                                .items
+                                .as_deref()
+                                .and_then(|items| resolve(&registry, items));
+                            Some((*name, (item.map(|item| item.ty).unwrap_or_default(), true)))
+                        } else {
+                            Some((*name, (property.ty, false)))
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self { fields }
+    }
+
+    /// Converts the fields to a JSON object, the last value is used if a
+    /// field that is not an array is repeated.
+    pub(crate) fn to_json(&self, pairs: Vec<(String, String)>) -> Value {
+        let mut obj = Map::new();
+
+        for (name, value) in pairs {
+            match self.fields.get(name.as_str()) {
+                Some((ty, true)) => {
+                    let value = convert_value(ty, value);
+                    match obj.entry(name).or_insert_with(|| Value::Array(Vec::new())) {
+                        Value::Array(values) => values.push(value),
+                        _ => unreachable!(),
+                    }
+                }
+                Some((ty, false)) => {
+                    let value = convert_value(ty, value);
+                    obj.insert(name, value);
+                }
+                None => {
+                    obj.insert(name, Value::String(value));
+                }
+            }
+        }
+
+        Value::Object(obj)
+    }
+}
+
+/// Converts the value of a field to the type of the schema, it is kept as a
+/// string if it can not be converted, so that the error is reported when it is
+/// parsed.
+fn convert_value(ty: &str, value: String) -> Value {
+    let converted = match ty {
+        "integer" => value
+            .parse::<i64>()
+            .map(Value::from)
+            .or_else(|_| value.parse::<u64>().map(Value::from))
+            .ok(),
+        "number" => value.parse::<f64>().ok().map(Value::from),
+        "boolean" => value.parse::<bool>().ok().map(Value::from),
+        _ => None,
+    };
+    converted.unwrap_or(Value::String(value))
+}
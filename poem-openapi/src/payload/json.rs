@@ -6,12 +6,26 @@ use serde_json::Value;
 use crate::{
     error::ParseJsonError,
     payload::{ParsePayload, Payload},
-    registry::{MetaMediaType, MetaResponse, MetaResponses, MetaSchemaRef, Registry},
+    registry::{MetaExample, MetaMediaType, MetaResponse, MetaResponses, MetaSchemaRef, Registry},
     types::{ParseFromJSON, ToJSON, Type},
     ApiResponse,
 };
 
 /// A JSON payload.
+///
+/// The content type is `application/json`, use the `content_type` attribute of
+/// [`ApiResponse`](crate::ApiResponse) to respond with a vendor specific media
+/// type, it is used for both the `Content-Type` header and the spec.
+///
+/// ```
+/// use poem_openapi::{payload::Json, ApiResponse};
+///
+/// #[derive(ApiResponse)]
+/// enum GetUserResponse {
+///     #[oai(status = 200, content_type = "application/vnd.myapi+json")]
+///     Ok(Json<String>),
+/// }
+/// ```
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Json<T>(pub T);
 
@@ -40,6 +54,10 @@ impl<T: Type> Payload for Json<T> {
     fn register(registry: &mut Registry) {
         T::register(registry);
     }
+
+    fn examples() -> Vec<MetaExample> {
+        T::examples()
+    }
 }
 
 #[poem::async_trait]
@@ -51,12 +69,12 @@ impl<T: ParseFromJSON> ParsePayload for Json<T> {
         let value = if data.is_empty() {
             Value::Null
         } else {
-            serde_json::from_slice(&data).map_err(|err| ParseJsonError {
-                reason: err.to_string(),
-            })?
+            serde_json::from_slice(&data).map_err(|err| ParseJsonError::syntax(err, &data))?
         };
 
         let value = T::parse_from_json(Some(value)).map_err(|err| ParseJsonError {
+            errors: vec![err.field_error()],
+            position: None,
             reason: err.into_message(),
         })?;
         Ok(Self(value))
@@ -78,6 +96,7 @@ impl<T: ToJSON> ApiResponse for Json<T> {
                 content: vec![MetaMediaType {
                     content_type: Self::CONTENT_TYPE,
                     schema: Self::schema_ref(),
+                    examples: Self::examples(),
                 }],
                 headers: vec![],
             }],
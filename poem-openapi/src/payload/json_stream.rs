@@ -0,0 +1,113 @@
+use std::io::{Error as IoError, ErrorKind};
+
+use bytes::Bytes;
+use futures_util::{stream, Stream, StreamExt};
+use poem::{http::header, Body, IntoResponse, Response};
+
+use crate::{
+    payload::Payload,
+    registry::{MetaMediaType, MetaResponse, MetaResponses, MetaSchema, MetaSchemaRef, Registry},
+    types::{ToJSON, Type},
+    ApiResponse,
+};
+
+/// A JSON array payload that is written incrementally from a stream.
+///
+/// The items are serialized as they are produced, so the whole array is never
+/// built in memory. If the stream yields an error, the body is aborted, and
+/// the client receives an incomplete array instead of a valid one.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::{stream::BoxStream, StreamExt};
+/// use poem_openapi::{payload::JsonStream, OpenApi};
+///
+/// struct Api;
+///
+/// #[OpenApi]
+/// impl Api {
+///     #[oai(path = "/numbers", method = "get")]
+///     async fn numbers(&self) -> JsonStream<BoxStream<'static, Result<i32, std::io::Error>>> {
+///         JsonStream::new(futures_util::stream::iter((0..1000).map(Ok)).boxed())
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct JsonStream<T>(pub T);
+
+impl<T> JsonStream<T> {
+    /// Create a JSON stream payload.
+    pub fn new(stream: T) -> Self {
+        Self(stream)
+    }
+}
+
+impl<T, E, Err> Payload for JsonStream<T>
+where
+    T: Stream<Item = Result<E, Err>> + Send + 'static,
+    E: Type + ToJSON,
+    Err: std::error::Error + Send + Sync + 'static,
+{
+    const CONTENT_TYPE: &'static str = "application/json";
+
+    fn schema_ref() -> MetaSchemaRef {
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            items: Some(Box::new(E::schema_ref())),
+            ..MetaSchema::new("array")
+        }))
+    }
+
+    fn register(registry: &mut Registry) {
+        E::register(registry);
+    }
+}
+
+impl<T, E, Err> IntoResponse for JsonStream<T>
+where
+    T: Stream<Item = Result<E, Err>> + Send + 'static,
+    E: Type + ToJSON,
+    Err: std::error::Error + Send + Sync + 'static,
+{
+    fn into_response(self) -> Response {
+        let items = self.0.enumerate().map(|(idx, item)| {
+            let value = item.map_err(|err| IoError::new(ErrorKind::Other, err))?;
+            let mut data = if idx == 0 { Vec::new() } else { vec![b','] };
+            serde_json::to_writer(&mut data, &value.to_json())?;
+            Ok::<_, IoError>(Bytes::from(data))
+        });
+        let body = stream::once(async { Ok(Bytes::from_static(b"[")) })
+            .chain(items)
+            .chain(stream::once(async { Ok(Bytes::from_static(b"]")) }));
+
+        Response::builder()
+            .header(header::CONTENT_TYPE, Self::CONTENT_TYPE)
+            .body(Body::from_bytes_stream(body))
+    }
+}
+
+impl<T, E, Err> ApiResponse for JsonStream<T>
+where
+    T: Stream<Item = Result<E, Err>> + Send + 'static,
+    E: Type + ToJSON,
+    Err: std::error::Error + Send + Sync + 'static,
+{
+    fn meta() -> MetaResponses {
+        MetaResponses {
+            responses: vec![MetaResponse {
+                description: "",
+                status: Some(200),
+                content: vec![MetaMediaType {
+                    content_type: Self::CONTENT_TYPE,
+                    schema: Self::schema_ref(),
+                    examples: vec![],
+                }],
+                headers: vec![],
+            }],
+        }
+    }
+
+    fn register(registry: &mut Registry) {
+        E::register(registry);
+    }
+}
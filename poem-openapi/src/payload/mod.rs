@@ -3,18 +3,21 @@
 mod attachment;
 mod binary;
 mod event_stream;
+mod form;
 mod html;
 mod json;
+mod json_stream;
 mod plain_text;
 mod response;
 
 use poem::{Request, RequestBody, Result};
 
+pub(crate) use self::form::FormFields;
 pub use self::{
-    attachment::Attachment, binary::Binary, event_stream::EventStream, html::Html, json::Json,
-    plain_text::PlainText, response::Response,
+    attachment::Attachment, binary::Binary, event_stream::EventStream, form::Form, html::Html,
+    json::Json, json_stream::JsonStream, plain_text::PlainText, response::Response,
 };
-use crate::registry::{MetaSchemaRef, Registry};
+use crate::registry::{MetaExample, MetaSchemaRef, Registry};
 
 /// Represents a payload type.
 pub trait Payload: Send {
@@ -27,6 +30,11 @@ pub trait Payload: Send {
     /// Register the schema contained in this payload to the registry.
     #[allow(unused_variables)]
     fn register(registry: &mut Registry) {}
+
+    /// Returns the named examples of this payload.
+    fn examples() -> Vec<MetaExample> {
+        Vec::new()
+    }
 }
 
 /// Represents a payload that can parse from HTTP request.
@@ -59,6 +59,7 @@ impl<T: Into<String> + Send> ApiResponse for PlainText<T> {
                 content: vec![MetaMediaType {
                     content_type: Self::CONTENT_TYPE,
                     schema: Self::schema_ref(),
+                    examples: vec![],
                 }],
                 headers: vec![],
             }],
@@ -20,6 +20,10 @@ const fn is_false(value: &bool) -> bool {
     !*value
 }
 
+const fn is_true(value: &bool) -> bool {
+    *value
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MetaDiscriminatorObject {
@@ -60,6 +64,8 @@ pub struct MetaSchema {
     pub external_docs: Option<MetaExternalDocument>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default: Option<Value>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub nullable: bool,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub required: Vec<&'static str>,
     #[serde(
@@ -116,6 +122,20 @@ pub struct MetaSchema {
     pub max_properties: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub min_properties: Option<usize>,
+
+    #[serde(flatten, serialize_with = "serialize_extensions")]
+    pub extensions: Vec<(&'static str, Value)>,
+}
+
+fn serialize_extensions<S: Serializer>(
+    extensions: &[(&'static str, Value)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut s = serializer.serialize_map(Some(extensions.len()))?;
+    for (name, value) in extensions {
+        s.serialize_entry(name, value)?;
+    }
+    s.end()
 }
 
 fn serialize_properties<S: Serializer>(
@@ -138,6 +158,7 @@ impl MetaSchema {
         description: None,
         external_docs: None,
         default: None,
+        nullable: false,
         required: vec![],
         properties: vec![],
         items: None,
@@ -164,6 +185,7 @@ impl MetaSchema {
         unique_items: None,
         max_properties: None,
         min_properties: None,
+        extensions: vec![],
     };
 
     pub fn new(ty: &'static str) -> Self {
@@ -182,11 +204,30 @@ impl MetaSchema {
         self == &Self::ANY
     }
 
+    /// Merges `other` into this schema, this is how the attributes of an
+    /// object field are applied to the schema of the field type.
+    ///
+    /// - `format`, `default`, `title`, `description`, `external_docs`,
+    ///   `example` and all the validators such as `maximum` or `pattern` are
+    ///   overridden if they are set in `other`.
+    /// - `enum` is replaced if it is not empty in `other`, the values are not
+    ///   intersected with the values of this schema, so `const_value` can
+    ///   narrow a field to a single value.
+    /// - The vendor extensions of `other` are appended.
+    /// - `nullable`, `deprecated`, `read_only` and `write_only` are enabled if
+    ///   they are enabled in either schema.
+    /// - `items` and `additionalProperties` are merged recursively, a
+    ///   reference in `other` is combined with `anyOf`.
+    /// - All other fields, such as `type`, `properties` and `required`, are
+    ///   kept from this schema.
     #[must_use]
     pub fn merge(
         mut self,
         MetaSchema {
+            format,
             default,
+            nullable,
+            deprecated,
             read_only,
             write_only,
             title,
@@ -194,6 +235,7 @@ impl MetaSchema {
             external_docs,
             items,
             additional_properties,
+            enum_items,
             example,
             multiple_of,
             maximum,
@@ -208,9 +250,12 @@ impl MetaSchema {
             unique_items,
             max_properties,
             min_properties,
+            extensions,
             ..
         }: MetaSchema,
     ) -> Self {
+        self.nullable |= nullable;
+        self.deprecated |= deprecated;
         self.read_only |= read_only;
         self.write_only |= write_only;
 
@@ -225,6 +270,7 @@ impl MetaSchema {
         }
 
         merge_optional!(
+            format,
             default,
             title,
             description,
@@ -245,6 +291,12 @@ impl MetaSchema {
             min_properties
         );
 
+        if !enum_items.is_empty() {
+            self.enum_items = enum_items;
+        }
+
+        self.extensions.extend(extensions);
+
         if let Some(items) = items {
             if let Some(self_items) = self.items {
                 let items = *items;
@@ -321,6 +373,11 @@ impl MetaSchemaRef {
         }
     }
 
+    /// Merges `other` into this schema, see [`MetaSchema::merge`].
+    ///
+    /// A reference can not be modified, so if `other` is not empty, the
+    /// result is an inline schema combining the reference and `other` with
+    /// `allOf`.
     #[must_use]
     pub fn merge(self, other: MetaSchema) -> Self {
         match self {
@@ -366,6 +423,8 @@ pub struct MetaOperationParam {
     pub description: Option<&'static str>,
     pub required: bool,
     pub deprecated: bool,
+    #[serde(skip_serializing_if = "is_true")]
+    pub explode: bool,
 }
 
 #[derive(Debug, PartialEq, Serialize)]
@@ -373,6 +432,31 @@ pub struct MetaMediaType {
     #[serde(skip)]
     pub content_type: &'static str,
     pub schema: MetaSchemaRef,
+    #[serde(
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_examples"
+    )]
+    pub examples: Vec<MetaExample>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetaExample {
+    #[serde(skip)]
+    pub name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<&'static str>,
+    pub value: Value,
+}
+
+fn serialize_examples<S: Serializer>(
+    examples: &[MetaExample],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut s = serializer.serialize_map(None)?;
+    for example in examples {
+        s.serialize_entry(example.name, example)?;
+    }
+    s.end()
 }
 
 #[derive(Debug, PartialEq, Serialize)]
@@ -475,6 +559,10 @@ pub struct MetaOperation {
     pub security: Vec<HashMap<&'static str, Vec<&'static str>>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub operation_id: Option<&'static str>,
+    #[serde(rename = "x-maxBodySize", skip_serializing_if = "Option::is_none")]
+    pub max_body_size: Option<usize>,
+    #[serde(flatten, serialize_with = "serialize_extensions")]
+    pub extensions: Vec<(&'static str, Value)>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -695,4 +783,51 @@ impl Registry {
             self.security_schemes.insert(name, security_scheme);
         }
     }
+
+    /// Returns `true` if the schema, or any schema nested in it, has a
+    /// validator such as `maximum` or `pattern`.
+    pub fn has_validators(&self, schema: &MetaSchemaRef) -> bool {
+        self.has_validators_inner(schema, &mut BTreeSet::new())
+    }
+
+    fn has_validators_inner(
+        &self,
+        schema: &MetaSchemaRef,
+        visited: &mut BTreeSet<&'static str>,
+    ) -> bool {
+        let schema = match schema {
+            MetaSchemaRef::Inline(schema) => &**schema,
+            MetaSchemaRef::Reference(name) => {
+                if !visited.insert(name) {
+                    return false;
+                }
+                match self.schemas.get(name) {
+                    Some(schema) => schema,
+                    None => return false,
+                }
+            }
+        };
+
+        schema.multiple_of.is_some()
+            || schema.maximum.is_some()
+            || schema.minimum.is_some()
+            || schema.max_length.is_some()
+            || schema.min_length.is_some()
+            || schema.pattern.is_some()
+            || schema.max_items.is_some()
+            || schema.min_items.is_some()
+            || schema.unique_items.is_some()
+            || schema.max_properties.is_some()
+            || schema.min_properties.is_some()
+            || schema
+                .properties
+                .iter()
+                .map(|(_, schema)| schema)
+                .chain(schema.items.as_deref())
+                .chain(schema.additional_properties.as_deref())
+                .chain(&schema.any_of)
+                .chain(&schema.one_of)
+                .chain(&schema.all_of)
+                .any(|schema| self.has_validators_inner(schema, visited))
+    }
 }
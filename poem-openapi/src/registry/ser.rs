@@ -1,10 +1,11 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashSet};
 
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
 use crate::registry::{
-    MetaApi, MetaExternalDocument, MetaInfo, MetaPath, MetaResponses, MetaSchema, MetaSchemaRef,
-    MetaSecurityScheme, MetaServer, MetaWebhook, Registry,
+    MetaApi, MetaExample, MetaExternalDocument, MetaHeader, MetaInfo, MetaMediaType, MetaPath,
+    MetaResponse, MetaResponses, MetaSchema, MetaSchemaRef, MetaSecurityScheme, MetaServer,
+    MetaWebhook, Registry,
 };
 
 const OPENAPI_VERSION: &str = "3.0.0";
@@ -50,12 +51,149 @@ impl Serialize for MetaPath {
 
 impl Serialize for MetaResponses {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        let mut s = serializer.serialize_map(None)?;
+        // Responses with the same status are merged into one, see
+        // `MergedResponse`.
+        let mut groups: Vec<(Option<u16>, Vec<&MetaResponse>)> = Vec::new();
         for resp in &self.responses {
-            match resp.status {
-                Some(status) => s.serialize_entry(&format!("{}", status), resp)?,
-                None => s.serialize_entry("default", resp)?,
+            match groups.iter_mut().find(|(status, _)| *status == resp.status) {
+                Some((_, group)) => group.push(resp),
+                None => groups.push((resp.status, vec![resp])),
+            }
+        }
+
+        let mut s = serializer.serialize_map(None)?;
+        for (status, group) in groups {
+            let resp = MergedResponse(group);
+            match status {
+                Some(status) => s.serialize_entry(&format!("{}", status), &resp)?,
+                None => s.serialize_entry("default", &resp)?,
+            }
+        }
+        s.end()
+    }
+}
+
+/// Multiple responses declared for the same status.
+///
+/// The schemas of each content type are combined with `oneOf`, the distinct
+/// descriptions are joined with blank lines and the headers are merged.
+struct MergedResponse<'a>(Vec<&'a MetaResponse>);
+
+impl<'a> Serialize for MergedResponse<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let [resp] = self.0.as_slice() {
+            return resp.serialize(serializer);
+        }
+
+        let mut content: Vec<(&str, Vec<&MetaMediaType>)> = Vec::new();
+        let mut headers: Vec<&MetaHeader> = Vec::new();
+        for resp in &self.0 {
+            for media in &resp.content {
+                match content
+                    .iter_mut()
+                    .find(|(content_type, _)| *content_type == media.content_type)
+                {
+                    Some((_, items)) => items.push(media),
+                    None => content.push((media.content_type, vec![media])),
+                }
+            }
+            for header in &resp.headers {
+                if headers.iter().all(|h| h.name != header.name) {
+                    headers.push(header);
+                }
+            }
+        }
+
+        let mut descriptions: Vec<&str> = Vec::new();
+        for resp in &self.0 {
+            if !resp.description.is_empty() && !descriptions.contains(&resp.description) {
+                descriptions.push(resp.description);
+            }
+        }
+
+        let mut s = serializer.serialize_map(None)?;
+        s.serialize_entry("description", &descriptions.join("\n\n"))?;
+        if !content.is_empty() {
+            s.serialize_entry("content", &MergedContent(content))?;
+        }
+        if !headers.is_empty() {
+            s.serialize_entry("headers", &HeaderMap(headers))?;
+        }
+        s.end()
+    }
+}
+
+struct MergedContent<'a>(Vec<(&'a str, Vec<&'a MetaMediaType>)>);
+
+impl<'a> Serialize for MergedContent<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_map(Some(self.0.len()))?;
+        for (content_type, items) in &self.0 {
+            s.serialize_entry(content_type, &MergedMediaType(items))?;
+        }
+        s.end()
+    }
+}
+
+struct MergedMediaType<'a>(&'a [&'a MetaMediaType]);
+
+impl<'a> Serialize for MergedMediaType<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if let [media] = self.0 {
+            return media.serialize(serializer);
+        }
+
+        let schema = MetaSchema {
+            one_of: self.0.iter().map(|media| media.schema.clone()).collect(),
+            ..MetaSchema::ANY
+        };
+        let examples = self
+            .0
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, media)| media.examples.iter().map(move |example| (idx, example)))
+            .collect::<Vec<_>>();
+
+        let mut s = serializer.serialize_map(None)?;
+        s.serialize_entry("schema", &schema)?;
+        if !examples.is_empty() {
+            s.serialize_entry("examples", &ExampleMap(examples))?;
+        }
+        s.end()
+    }
+}
+
+struct HeaderMap<'a>(Vec<&'a MetaHeader>);
+
+impl<'a> Serialize for HeaderMap<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_map(Some(self.0.len()))?;
+        for header in &self.0 {
+            s.serialize_entry(header.name, header)?;
+        }
+        s.end()
+    }
+}
+
+/// The examples of the merged media types with the index of the media type
+/// they come from.
+///
+/// If several examples have the same name, the name of the examples after the
+/// first one is suffixed with the position of their media type, starting at 1.
+struct ExampleMap<'a>(Vec<(usize, &'a MetaExample)>);
+
+impl<'a> Serialize for ExampleMap<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_map(None)?;
+        let mut names = HashSet::new();
+        for (idx, example) in &self.0 {
+            let mut name = example.name.to_string();
+            let mut n = idx + 1;
+            while !names.insert(name.clone()) {
+                name = format!("{}_{}", example.name, n);
+                n += 1;
             }
+            s.serialize_entry(&name, example)?;
         }
         s.end()
     }
@@ -1,9 +1,22 @@
 use std::{fmt::Display, marker::PhantomData};
 
+use serde::Serialize;
 use serde_json::Value;
 
 use super::Type;
 
+/// A machine-readable description of the field that failed to parse.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct FieldError {
+    /// The JSON pointer to the field, for example `/a/b`.
+    ///
+    /// It is an empty string if the error occurred at the root value.
+    pub field: String,
+
+    /// The reason code, for example `maximum` or `expected_type`.
+    pub code: &'static str,
+}
+
 /// An error parsing an schema.
 ///
 /// This type is generic over T as it uses T's type name when converting to a
@@ -11,6 +24,8 @@ use super::Type;
 #[derive(Debug)]
 pub struct ParseError<T> {
     message: String,
+    pointer: String,
+    code: &'static str,
     phantom: PhantomData<T>,
 }
 
@@ -21,9 +36,11 @@ impl<T: Type, E: Display> From<E> for ParseError<T> {
 }
 
 impl<T: Type> ParseError<T> {
-    fn new(message: String) -> Self {
+    fn new(message: String, code: &'static str) -> Self {
         Self {
             message,
+            pointer: String::new(),
+            code,
             phantom: PhantomData,
         }
     }
@@ -31,17 +48,19 @@ impl<T: Type> ParseError<T> {
     /// The expected input type did not match the actual input type.
     #[must_use]
     pub fn expected_type(actual: Value) -> Self {
-        Self::new(format!(
-            r#"Expected input type "{}", found {}."#,
-            T::name(),
-            actual
-        ))
+        Self::new(
+            format!(r#"Expected input type "{}", found {}."#, T::name(), actual),
+            "expected_type",
+        )
     }
 
     /// Type A expects an input value.
     #[must_use]
     pub fn expected_input() -> Self {
-        Self::new(format!(r#"Type "{}" expects an input value."#, T::name()))
+        Self::new(
+            format!(r#"Type "{}" expects an input value."#, T::name()),
+            "expected_input",
+        )
     }
 
     /// A custom error message.
@@ -50,19 +69,82 @@ impl<T: Type> ParseError<T> {
     /// you use the `?` operator.
     #[must_use]
     pub fn custom(msg: impl Display) -> Self {
-        Self::new(format!(r#"failed to parse "{}": {}"#, T::name(), msg))
+        Self::new(
+            format!(r#"failed to parse "{}": {}"#, T::name(), msg),
+            "custom",
+        )
+    }
+
+    /// Attach the field that caused this error and a reason code.
+    ///
+    /// The field is prepended to the JSON pointer of this error.
+    #[must_use]
+    pub fn with_field(mut self, field: &str, code: &'static str) -> Self {
+        self.pointer = format!("/{}{}", escape_pointer(field), self.pointer);
+        self.code = code;
+        self
     }
 
     /// Propagate the error message to a different type.
     pub fn propagate<U: Type>(self) -> ParseError<U> {
-        if T::name() != U::name() {
-            ParseError::new(format!(
+        let message = if T::name() != U::name() {
+            format!(
                 r#"{} (occurred while parsing "{}")"#,
                 self.message,
                 U::name()
-            ))
+            )
         } else {
-            ParseError::new(self.message)
+            self.message
+        };
+        ParseError {
+            message,
+            pointer: self.pointer,
+            code: self.code,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Propagate the error message to a different type, and prepend the
+    /// field name to the JSON pointer of this error.
+    pub fn propagate_field<U: Type>(self, field: &str) -> ParseError<U> {
+        let mut err = self.propagate::<U>();
+        err.pointer = format!("/{}{}", escape_pointer(field), err.pointer);
+        err
+    }
+
+    /// Propagate the error from a flattened field to a different type.
+    ///
+    /// The JSON pointer is unchanged, because the properties of a flattened
+    /// field are at the same level, but the message mentions the field.
+    pub fn propagate_flatten<U: Type>(self, field: &str) -> ParseError<U> {
+        ParseError {
+            message: format!(
+                r#"{} (occurred while parsing the flattened field "{}" of "{}")"#,
+                self.message,
+                field,
+                U::name()
+            ),
+            pointer: self.pointer,
+            code: self.code,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns the JSON pointer to the field that caused this error.
+    pub fn pointer(&self) -> &str {
+        &self.pointer
+    }
+
+    /// Returns the reason code of this error.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the structured error for the field that caused this error.
+    pub fn field_error(&self) -> FieldError {
+        FieldError {
+            field: self.pointer.clone(),
+            code: self.code,
         }
     }
 
@@ -72,5 +154,9 @@ impl<T: Type> ParseError<T> {
     }
 }
 
+fn escape_pointer(field: &str) -> String {
+    field.replace('~', "~0").replace('/', "~1")
+}
+
 /// An error parsing a value of type `T`.
 pub type ParseResult<T> = Result<T, ParseError<T>>;
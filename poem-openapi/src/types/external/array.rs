@@ -55,8 +55,11 @@ impl<T: ParseFromJSON, const LEN: usize> ParseFromJSON for [T; LEN] {
                 }
 
                 let mut res = Vec::with_capacity(values.len());
-                for value in values {
-                    res.push(T::parse_from_json(Some(value)).map_err(ParseError::propagate)?);
+                for (idx, value) in values.into_iter().enumerate() {
+                    res.push(
+                        T::parse_from_json(Some(value))
+                            .map_err(|err| err.propagate_field(&idx.to_string()))?,
+                    );
                 }
 
                 Ok(res.try_into().ok().unwrap())
@@ -76,10 +79,10 @@ impl<T: ParseFromParameter, const LEN: usize> ParseFromParameter for [T; LEN] {
     ) -> ParseResult<Self> {
         let mut values = Vec::new();
 
-        for s in iter {
+        for (idx, s) in iter.into_iter().enumerate() {
             values.push(
                 T::parse_from_parameters(std::iter::once(s.as_ref()))
-                    .map_err(ParseError::propagate)?,
+                    .map_err(|err| err.propagate_field(&idx.to_string()))?,
             );
         }
 
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use chrono::{DateTime, FixedOffset, Local, Utc};
+use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use poem::web::Field;
 use serde_json::Value;
 
@@ -13,7 +13,7 @@ use crate::{
 };
 
 macro_rules! impl_datetime_types {
-    ($ty:ty, $type_name:literal, $format:literal) => {
+    ($ty:ty, $type_name:literal, $format:literal, $to_string:expr) => {
         impl Type for $ty {
             const IS_REQUIRED: bool = true;
 
@@ -69,12 +69,102 @@ macro_rules! impl_datetime_types {
 
         impl ToJSON for $ty {
             fn to_json(&self) -> Option<Value> {
-                Some(Value::String(self.to_rfc3339()))
+                let to_string: fn(&$ty) -> String = $to_string;
+                Some(Value::String(to_string(self)))
             }
         }
     };
 }
 
-impl_datetime_types!(DateTime<Utc>, "string", "date-time");
-impl_datetime_types!(DateTime<Local>, "string", "date-time");
-impl_datetime_types!(DateTime<FixedOffset>, "string", "date-time");
+impl_datetime_types!(DateTime<Utc>, "string", "date-time", DateTime::to_rfc3339);
+impl_datetime_types!(DateTime<Local>, "string", "date-time", DateTime::to_rfc3339);
+impl_datetime_types!(
+    DateTime<FixedOffset>,
+    "string",
+    "date-time",
+    DateTime::to_rfc3339
+);
+
+// The naive types do not have a timezone, so they can not use the `date-time`
+// format which requires the offset.
+impl_datetime_types!(NaiveDateTime, "string", "naive-date-time", |value| value
+    .format("%Y-%m-%dT%H:%M:%S%.f")
+    .to_string());
+impl_datetime_types!(NaiveDate, "string", "date", NaiveDate::to_string);
+impl_datetime_types!(NaiveTime, "string", "naive-time", NaiveTime::to_string);
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn test_type<T: Type + ParseFromJSON + ToJSON + PartialEq + std::fmt::Debug>(
+        value: T,
+        json: &str,
+        format: &str,
+    ) {
+        match T::schema_ref() {
+            MetaSchemaRef::Inline(schema) => {
+                assert_eq!(schema.ty, "string");
+                assert_eq!(schema.format, Some(format));
+            }
+            MetaSchemaRef::Reference(_) => unreachable!(),
+        }
+        assert_eq!(value.to_json(), Some(Value::String(json.to_string())));
+        assert_eq!(
+            T::parse_from_json(Some(Value::String(json.to_string()))).unwrap(),
+            value
+        );
+    }
+
+    fn naive_datetime() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2022, 1, 2)
+            .unwrap()
+            .and_hms_milli_opt(3, 4, 5, 600)
+            .unwrap()
+    }
+
+    #[test]
+    fn datetime() {
+        test_type(
+            Utc.from_utc_datetime(&naive_datetime()),
+            "2022-01-02T03:04:05.600+00:00",
+            "date-time",
+        );
+        test_type(
+            FixedOffset::east_opt(8 * 3600)
+                .unwrap()
+                .from_local_datetime(&naive_datetime())
+                .unwrap(),
+            "2022-01-02T03:04:05.600+08:00",
+            "date-time",
+        );
+        assert_eq!(
+            DateTime::<Utc>::parse_from_json(Some(Value::String(
+                "2022-01-02T11:04:05.600+08:00".to_string()
+            )))
+            .unwrap(),
+            Utc.from_utc_datetime(&naive_datetime())
+        );
+        assert!(DateTime::<Utc>::parse_from_json(Some(Value::String(
+            "2022-01-02T03:04:05".to_string()
+        )))
+        .is_err());
+    }
+
+    #[test]
+    fn naive() {
+        test_type(
+            naive_datetime(),
+            "2022-01-02T03:04:05.600",
+            "naive-date-time",
+        );
+        test_type(naive_datetime().date(), "2022-01-02", "date");
+        test_type(naive_datetime().time(), "03:04:05.600", "naive-time");
+        assert!(
+            NaiveDate::parse_from_json(Some(Value::String("2022-01-02T03:04:05".to_string())))
+                .is_err()
+        );
+    }
+}
@@ -55,10 +55,11 @@ where
         if let Value::Object(value) = value {
             let mut obj = HashMap::new();
             for (key, value) in value {
+                let value =
+                    V::parse_from_json(Some(value)).map_err(|err| err.propagate_field(&key))?;
                 let key = key
                     .parse()
                     .map_err(|err| ParseError::custom(format!("object key: {}", err)))?;
-                let value = V::parse_from_json(Some(value)).map_err(ParseError::propagate)?;
                 obj.insert(key, value);
             }
             Ok(obj)
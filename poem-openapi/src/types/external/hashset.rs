@@ -50,8 +50,11 @@ impl<T: ParseFromJSON + Hash + Eq> ParseFromJSON for HashSet<T> {
         match value {
             Value::Array(values) => {
                 let mut res = HashSet::new();
-                for value in values {
-                    res.insert(T::parse_from_json(Some(value)).map_err(ParseError::propagate)?);
+                for (idx, value) in values.into_iter().enumerate() {
+                    res.insert(
+                        T::parse_from_json(Some(value))
+                            .map_err(|err| err.propagate_field(&idx.to_string()))?,
+                    );
                 }
                 Ok(res)
             }
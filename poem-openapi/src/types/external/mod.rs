@@ -14,6 +14,8 @@ mod optional;
 mod regex;
 mod slice;
 mod string;
+#[cfg(feature = "time")]
+mod time;
 mod uri;
 #[cfg(feature = "url")]
 mod url;
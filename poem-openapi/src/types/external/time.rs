@@ -0,0 +1,247 @@
+use std::borrow::Cow;
+
+use poem::web::Field;
+use serde_json::Value;
+use time::{
+    format_description::well_known::Rfc3339, Date, OffsetDateTime, PrimitiveDateTime, Time,
+};
+
+use crate::{
+    registry::{MetaSchema, MetaSchemaRef},
+    types::{
+        ParseError, ParseFromJSON, ParseFromMultipartField, ParseFromParameter, ParseResult,
+        ToJSON, Type,
+    },
+};
+
+// RFC 3339 can not represent years outside `0..=9999` or offsets with seconds,
+// so these values are written in an extended form instead of failing.
+macro_rules! extended_offset_date_time_format {
+    () => {
+        time::macros::format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory]:[offset_minute]:[offset_second]"
+        )
+    };
+}
+
+fn format_offset_date_time(value: &OffsetDateTime) -> Result<String, time::error::Format> {
+    value
+        .format(&Rfc3339)
+        .or_else(|_| value.format(extended_offset_date_time_format!()))
+}
+
+fn parse_offset_date_time(value: &str) -> Result<OffsetDateTime, time::error::Parse> {
+    OffsetDateTime::parse(value, &Rfc3339)
+        .or_else(|_| OffsetDateTime::parse(value, extended_offset_date_time_format!()))
+}
+
+fn format_primitive_date_time(value: &PrimitiveDateTime) -> Result<String, time::error::Format> {
+    value.format(time::macros::format_description!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]"
+    ))
+}
+
+fn parse_primitive_date_time(value: &str) -> Result<PrimitiveDateTime, time::error::Parse> {
+    PrimitiveDateTime::parse(
+        value,
+        time::macros::format_description!(
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]"
+        ),
+    )
+    .or_else(|_| {
+        PrimitiveDateTime::parse(
+            value,
+            time::macros::format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+        )
+    })
+}
+
+fn format_date(value: &Date) -> Result<String, time::error::Format> {
+    value.format(time::macros::format_description!("[year]-[month]-[day]"))
+}
+
+fn parse_date(value: &str) -> Result<Date, time::error::Parse> {
+    Date::parse(
+        value,
+        time::macros::format_description!("[year]-[month]-[day]"),
+    )
+}
+
+fn format_time(value: &Time) -> Result<String, time::error::Format> {
+    value.format(time::macros::format_description!(
+        "[hour]:[minute]:[second].[subsecond]"
+    ))
+}
+
+fn parse_time(value: &str) -> Result<Time, time::error::Parse> {
+    Time::parse(
+        value,
+        time::macros::format_description!("[hour]:[minute]:[second].[subsecond]"),
+    )
+    .or_else(|_| {
+        Time::parse(
+            value,
+            time::macros::format_description!("[hour]:[minute]:[second]"),
+        )
+    })
+}
+
+macro_rules! impl_time_types {
+    ($ty:ty, $type_name:literal, $format:literal, $parse:expr, $to_string:expr) => {
+        impl Type for $ty {
+            const IS_REQUIRED: bool = true;
+
+            type RawValueType = Self;
+
+            type RawElementValueType = Self;
+
+            fn name() -> Cow<'static, str> {
+                concat!($type_name, "(", $format, ")").into()
+            }
+
+            fn schema_ref() -> MetaSchemaRef {
+                MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format($type_name, $format)))
+            }
+
+            fn as_raw_value(&self) -> Option<&Self::RawValueType> {
+                Some(self)
+            }
+
+            fn raw_element_iter<'a>(
+                &'a self,
+            ) -> Box<dyn Iterator<Item = &'a Self::RawElementValueType> + 'a> {
+                Box::new(self.as_raw_value().into_iter())
+            }
+        }
+
+        impl ParseFromJSON for $ty {
+            fn parse_from_json(value: Option<Value>) -> ParseResult<Self> {
+                let value = value.unwrap_or_default();
+                if let Value::String(value) = value {
+                    Ok($parse(&value)?)
+                } else {
+                    Err(ParseError::expected_type(value))
+                }
+            }
+        }
+
+        impl ParseFromParameter for $ty {
+            fn parse_from_parameter(value: &str) -> ParseResult<Self> {
+                Ok($parse(value)?)
+            }
+        }
+
+        #[poem::async_trait]
+        impl ParseFromMultipartField for $ty {
+            async fn parse_from_multipart(field: Option<Field>) -> ParseResult<Self> {
+                match field {
+                    Some(field) => Ok($parse(&field.text().await?)?),
+                    None => Err(ParseError::expected_input()),
+                }
+            }
+        }
+
+        impl ToJSON for $ty {
+            fn to_json(&self) -> Option<Value> {
+                $to_string(self).ok().map(Value::String)
+            }
+        }
+    };
+}
+
+impl_time_types!(
+    OffsetDateTime,
+    "string",
+    "date-time",
+    parse_offset_date_time,
+    format_offset_date_time
+);
+
+// The primitive types do not have an offset, so they can not use the
+// `date-time` format which requires it.
+impl_time_types!(
+    PrimitiveDateTime,
+    "string",
+    "naive-date-time",
+    parse_primitive_date_time,
+    format_primitive_date_time
+);
+impl_time_types!(Date, "string", "date", parse_date, format_date);
+impl_time_types!(Time, "string", "naive-time", parse_time, format_time);
+
+#[cfg(test)]
+mod tests {
+    use time::macros::{date, datetime, time};
+
+    use super::*;
+
+    fn test_type<T: Type + ParseFromJSON + ToJSON + PartialEq + std::fmt::Debug>(
+        value: T,
+        json: &str,
+        format: &str,
+    ) {
+        match T::schema_ref() {
+            MetaSchemaRef::Inline(schema) => {
+                assert_eq!(schema.ty, "string");
+                assert_eq!(schema.format, Some(format));
+            }
+            MetaSchemaRef::Reference(_) => unreachable!(),
+        }
+        assert_eq!(value.to_json(), Some(Value::String(json.to_string())));
+        assert_eq!(
+            T::parse_from_json(Some(Value::String(json.to_string()))).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn offset_date_time() {
+        test_type(
+            datetime!(2022-01-02 03:04:05.6 +08:00),
+            "2022-01-02T03:04:05.6+08:00",
+            "date-time",
+        );
+        test_type(
+            datetime!(2022-01-02 03:04:05 UTC),
+            "2022-01-02T03:04:05Z",
+            "date-time",
+        );
+        assert!(OffsetDateTime::parse_from_json(Some(Value::String(
+            "2022-01-02T03:04:05".to_string()
+        )))
+        .is_err());
+    }
+
+    #[test]
+    fn offset_date_time_outside_rfc3339() {
+        test_type(
+            datetime!(+10000-01-02 03:04:05 UTC),
+            "+10000-01-02T03:04:05.0+00:00:00",
+            "date-time",
+        );
+        test_type(
+            datetime!(2022-01-02 03:04:05 +08:00:30),
+            "2022-01-02T03:04:05.0+08:00:30",
+            "date-time",
+        );
+    }
+
+    #[test]
+    fn primitive() {
+        test_type(
+            datetime!(2022-01-02 03:04:05.6),
+            "2022-01-02T03:04:05.6",
+            "naive-date-time",
+        );
+        test_type(
+            datetime!(2022-01-02 03:04:05),
+            "2022-01-02T03:04:05.0",
+            "naive-date-time",
+        );
+        test_type(date!(2022 - 01 - 02), "2022-01-02", "date");
+        test_type(time!(03:04:05.6), "03:04:05.6", "naive-time");
+        assert!(
+            Date::parse_from_json(Some(Value::String("2022-01-02T03:04:05".to_string()))).is_err()
+        );
+    }
+}
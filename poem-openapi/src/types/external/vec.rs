@@ -50,8 +50,11 @@ impl<T: ParseFromJSON> ParseFromJSON for Vec<T> {
         match value {
             Value::Array(values) => {
                 let mut res = Vec::with_capacity(values.len());
-                for value in values {
-                    res.push(T::parse_from_json(Some(value)).map_err(ParseError::propagate)?);
+                for (idx, value) in values.into_iter().enumerate() {
+                    res.push(
+                        T::parse_from_json(Some(value))
+                            .map_err(|err| err.propagate_field(&idx.to_string()))?,
+                    );
                 }
                 Ok(res)
             }
@@ -69,10 +72,10 @@ impl<T: ParseFromParameter> ParseFromParameter for Vec<T> {
         iter: I,
     ) -> ParseResult<Self> {
         let mut values = Vec::new();
-        for s in iter {
+        for (idx, s) in iter.into_iter().enumerate() {
             values.push(
                 T::parse_from_parameters(std::iter::once(s.as_ref()))
-                    .map_err(ParseError::propagate)?,
+                    .map_err(|err| err.propagate_field(&idx.to_string()))?,
             );
         }
         Ok(values)
@@ -86,7 +89,7 @@ impl<T: ParseFromMultipartField> ParseFromMultipartField for Vec<T> {
             Some(field) => {
                 let item = T::parse_from_multipart(Some(field))
                     .await
-                    .map_err(ParseError::propagate)?;
+                    .map_err(|err| err.propagate_field("0"))?;
                 Ok(vec![item])
             }
             None => Ok(Vec::new()),
@@ -96,7 +99,7 @@ impl<T: ParseFromMultipartField> ParseFromMultipartField for Vec<T> {
     async fn parse_from_repeated_field(mut self, field: PoemField) -> ParseResult<Self> {
         let item = T::parse_from_multipart(Some(field))
             .await
-            .map_err(ParseError::propagate)?;
+            .map_err(|err| err.propagate_field(&self.len().to_string()))?;
         self.push(item);
         Ok(self)
     }
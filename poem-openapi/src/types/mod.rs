@@ -6,6 +6,7 @@ mod binary;
 mod error;
 mod external;
 mod maybe_undefined;
+mod select_fields;
 mod string_types;
 
 pub mod multipart;
@@ -15,7 +16,7 @@ use std::{borrow::Cow, sync::Arc};
 pub use any::Any;
 pub use base64_type::Base64;
 pub use binary::Binary;
-pub use error::{ParseError, ParseResult};
+pub use error::{FieldError, ParseError, ParseResult};
 pub use maybe_undefined::MaybeUndefined;
 use poem::{http::HeaderValue, web::Field as PoemField};
 use serde_json::Value;
@@ -25,7 +26,7 @@ pub use string_types::Email;
 pub use string_types::Hostname;
 pub use string_types::Password;
 
-use crate::registry::{MetaSchemaRef, Registry};
+use crate::registry::{MetaExample, MetaSchemaRef, Registry};
 
 /// Represents a OpenAPI type.
 pub trait Type: Send + Sync {
@@ -55,6 +56,12 @@ pub trait Type: Send + Sync {
     #[allow(unused_variables)]
     fn register(registry: &mut Registry) {}
 
+    /// Returns the named examples of this type, they are added to the media
+    /// types of the request and response bodies that contain it.
+    fn examples() -> Vec<MetaExample> {
+        Vec::new()
+    }
+
     /// Returns a reference to the raw value.
     fn as_raw_value(&self) -> Option<&Self::RawValueType>;
 
@@ -115,6 +122,56 @@ pub trait ToJSON: Type {
     fn to_json_string(&self) -> String {
         serde_json::to_string(&self.to_json()).unwrap_or_default()
     }
+
+    /// Convert this value to [`Value`], keeping only the specified fields of
+    /// the objects, which is useful to implement sparse fieldsets such as
+    /// `?fields=id,owner.name`.
+    ///
+    /// Nested fields are separated by `.`, and the fields of the objects in
+    /// an array are selected individually. Unknown fields are ignored, and
+    /// all fields are kept if no field is specified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poem_openapi::{types::ToJSON, Object};
+    /// use serde_json::json;
+    ///
+    /// #[derive(Object)]
+    /// struct Owner {
+    ///     name: String,
+    ///     email: String,
+    /// }
+    ///
+    /// #[derive(Object)]
+    /// struct Pet {
+    ///     id: u64,
+    ///     name: String,
+    ///     owner: Owner,
+    /// }
+    ///
+    /// let pet = Pet {
+    ///     id: 1,
+    ///     name: "dog".to_string(),
+    ///     owner: Owner {
+    ///         name: "sunli".to_string(),
+    ///         email: "sunli@example.com".to_string(),
+    ///     },
+    /// };
+    /// assert_eq!(
+    ///     pet.to_json_with_fields("id,owner.name".split(',')),
+    ///     Some(json!({ "id": 1, "owner": { "name": "sunli" } }))
+    /// );
+    /// ```
+    fn to_json_with_fields<'a, I>(&self, fields: I) -> Option<Value>
+    where
+        I: IntoIterator<Item = &'a str>,
+        Self: Sized,
+    {
+        let mut value = self.to_json()?;
+        select_fields::select_fields(&mut value, fields);
+        Some(value)
+    }
 }
 
 /// Represents a type that can converted to HTTP header.
@@ -4,6 +4,7 @@ use poem::web::Field as PoemField;
 use serde_json::Value;
 
 use crate::{
+    base::parse_json_multipart_field,
     registry::{MetaSchemaRef, Registry},
     types::{ParseError, ParseFromJSON, ParseFromMultipartField, ParseResult, ToJSON, Type},
 };
@@ -47,15 +48,10 @@ impl<T: Type> Type for JsonField<T> {
 #[poem::async_trait]
 impl<T: ParseFromJSON> ParseFromMultipartField for JsonField<T> {
     async fn parse_from_multipart(field: Option<PoemField>) -> ParseResult<Self> {
-        let value = match field {
-            Some(field) => {
-                let data = field.bytes().await.map_err(ParseError::custom)?;
-                serde_json::from_slice(&data).map_err(ParseError::custom)?
-            }
-            None => Value::Null,
-        };
         Ok(Self(
-            T::parse_from_json(Some(value)).map_err(ParseError::propagate)?,
+            parse_json_multipart_field(field)
+                .await
+                .map_err(ParseError::propagate)?,
         ))
     }
 }
@@ -0,0 +1,58 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+/// The requested fields, an empty node selects the whole value.
+#[derive(Default)]
+struct FieldTree(BTreeMap<String, FieldTree>);
+
+impl FieldTree {
+    fn insert(&mut self, path: &str) {
+        let mut segments = path
+            .split('.')
+            .filter(|segment| !segment.is_empty())
+            .peekable();
+        if segments.peek().is_none() {
+            return;
+        }
+
+        let mut node = self;
+        for segment in segments {
+            let is_new = !node.0.contains_key(segment);
+            node = node.0.entry(segment.to_string()).or_default();
+            if !is_new && node.0.is_empty() {
+                // the whole value is already selected
+                return;
+            }
+        }
+        node.0.clear();
+    }
+
+    fn apply(&self, value: &mut Value) {
+        match value {
+            Value::Object(object) => {
+                object.retain(|name, value| match self.0.get(name) {
+                    Some(node) => {
+                        if !node.0.is_empty() {
+                            node.apply(value);
+                        }
+                        true
+                    }
+                    None => false,
+                });
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.apply(value)),
+            _ => {}
+        }
+    }
+}
+
+pub(crate) fn select_fields<'a>(value: &mut Value, fields: impl IntoIterator<Item = &'a str>) {
+    let mut tree = FieldTree::default();
+    for path in fields {
+        tree.insert(path);
+    }
+    if !tree.0.is_empty() {
+        tree.apply(value);
+    }
+}
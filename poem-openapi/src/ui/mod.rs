@@ -4,3 +4,24 @@ pub(crate) mod rapidoc;
 pub(crate) mod redoc;
 #[cfg(feature = "swagger-ui")]
 pub(crate) mod swagger_ui;
+
+/// The specification loaded by a UI.
+#[cfg(any(feature = "swagger-ui", feature = "rapidoc", feature = "redoc"))]
+pub(crate) enum Spec<'a> {
+    /// The specification is embedded in the page.
+    Document(&'a str),
+    /// The specification is fetched from the URL.
+    Url(&'a str),
+}
+
+#[cfg(any(feature = "swagger-ui", feature = "rapidoc", feature = "redoc"))]
+impl Spec<'_> {
+    /// Returns the JavaScript expression of the specification, the UIs accept
+    /// either the document or its URL.
+    pub(crate) fn to_js(&self) -> String {
+        match self {
+            Spec::Document(document) => document.to_string(),
+            Spec::Url(url) => serde_json::to_string(url).unwrap(),
+        }
+    }
+}
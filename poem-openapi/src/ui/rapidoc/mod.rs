@@ -1,5 +1,7 @@
 use poem::{endpoint::make_sync, web::Html, Endpoint};
 
+use crate::ui::Spec;
+
 const RAPIDOC_JS: &str = include_str!("rapidoc-min.js");
 const OAUTH_RECEIVER_HTML: &str = include_str!("oauth-receiver.html");
 
@@ -39,10 +41,10 @@ const RAPIDOC_TEMPLATE: &str = r#"
 </body>
 "#;
 
-pub(crate) fn create_endpoint(document: &str) -> impl Endpoint {
+pub(crate) fn create_endpoint(spec: Spec<'_>) -> impl Endpoint {
     let ui_html = RAPIDOC_TEMPLATE
         .replace("{:script}", RAPIDOC_JS)
-        .replace("{:spec}", document);
+        .replace("{:spec}", &spec.to_js());
     let oauth_receiver_html = OAUTH_RECEIVER_HTML.replace("{:script}", RAPIDOC_JS);
 
     poem::Route::new()
@@ -1,5 +1,7 @@
 use poem::{endpoint::make_sync, web::Html, Endpoint};
 
+use crate::ui::Spec;
+
 const REDOC_JS: &str = include_str!("redoc.standalone.js");
 
 const REDOC_TEMPLATE: &str = r#"
@@ -36,10 +38,10 @@ const REDOC_TEMPLATE: &str = r#"
 </html>
 "#;
 
-pub(crate) fn create_endpoint(document: &str) -> impl Endpoint {
+pub(crate) fn create_endpoint(spec: Spec<'_>) -> impl Endpoint {
     let ui_html = REDOC_TEMPLATE
         .replace("{:script}", REDOC_JS)
-        .replace("{:spec}", document);
+        .replace("{:spec}", &spec.to_js());
 
     poem::Route::new().at("/", make_sync(move |_| Html(ui_html.clone())))
 }
@@ -1,5 +1,7 @@
 use poem::{endpoint::make_sync, web::Html, Endpoint};
 
+use crate::ui::Spec;
+
 const SWAGGER_UI_JS: &str = include_str!("swagger-ui-bundle.js");
 const SWAGGER_UI_CSS: &str = include_str!("swagger-ui.css");
 const OAUTH_RECEIVER_HTML: &str = include_str!("oauth-receiver.html");
@@ -34,7 +36,7 @@ const SWAGGER_UI_TEMPLATE: &str = r#"
 
     SwaggerUIBundle({
         dom_id: '#ui',
-        spec: spec,
+        {:spec_key}: spec,
         filter: false,
         oauth2RedirectUrl: oauth2RedirectUrl,
     })
@@ -43,11 +45,16 @@ const SWAGGER_UI_TEMPLATE: &str = r#"
 </body>
 "#;
 
-pub(crate) fn create_endpoint(document: &str) -> impl Endpoint {
+pub(crate) fn create_endpoint(spec: Spec<'_>) -> impl Endpoint {
+    let spec_key = match spec {
+        Spec::Document(_) => "spec",
+        Spec::Url(_) => "url",
+    };
     let ui_html = SWAGGER_UI_TEMPLATE
         .replace("{:style}", SWAGGER_UI_CSS)
         .replace("{:script}", SWAGGER_UI_JS)
-        .replace("{:spec}", document);
+        .replace("{:spec_key}", spec_key)
+        .replace("{:spec}", &spec.to_js());
 
     poem::Route::new()
         .at("/", make_sync(move |_| Html(ui_html.clone())))
@@ -0,0 +1,36 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::validation::Validator;
+
+/// A validator that calls a function returning the error message if the
+/// value is invalid.
+pub struct FnValidator<F>(F);
+
+impl<F> FnValidator<F> {
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F> Display for FnValidator<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("custom()")
+    }
+}
+
+impl<T, F: Fn(&T) -> Result<(), String>> Validator<T> for FnValidator<F> {
+    #[inline]
+    fn check(&self, value: &T) -> bool {
+        (self.0)(value).is_ok()
+    }
+
+    fn error_message(&self, value: &T) -> String {
+        (self.0)(value).err().unwrap_or_else(|| self.to_string())
+    }
+
+    #[inline]
+    fn validate(&self, value: &T) -> Result<(), String> {
+        (self.0)(value)
+    }
+}
@@ -0,0 +1,133 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use derive_more::Display;
+use regex::Regex;
+
+use crate::{
+    registry::MetaSchema,
+    validation::{Validator, ValidatorMeta},
+};
+
+lazy_static::lazy_static! {
+    static ref EMAIL: Regex = Regex::new(r"^[^@\s]+@[^@\s.]+(\.[^@\s.]+)+$").unwrap();
+    static ref URI: Regex = Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.-]*:[^\s]+$").unwrap();
+    static ref UUID: Regex = Regex::new(
+        r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    )
+    .unwrap();
+}
+
+#[derive(Copy, Clone)]
+enum FormatKind {
+    Email,
+    Uri,
+    Hostname,
+    Ipv4,
+    Ipv6,
+    Uuid,
+}
+
+impl FormatKind {
+    fn name(self) -> &'static str {
+        match self {
+            FormatKind::Email => "email",
+            FormatKind::Uri => "uri",
+            FormatKind::Hostname => "hostname",
+            FormatKind::Ipv4 => "ipv4",
+            FormatKind::Ipv6 => "ipv6",
+            FormatKind::Uuid => "uuid",
+        }
+    }
+}
+
+/// Validator that checks the value against a well-known string format, and
+/// sets the `format` of the schema.
+#[derive(Display)]
+#[display(fmt = "format(\"{}\")", "kind.name()")]
+pub struct Format {
+    kind: FormatKind,
+}
+
+impl Format {
+    /// Create a validator that checks the value is an email address.
+    #[inline]
+    pub fn email() -> Self {
+        Self {
+            kind: FormatKind::Email,
+        }
+    }
+
+    /// Create a validator that checks the value is an absolute URI.
+    #[inline]
+    pub fn uri() -> Self {
+        Self {
+            kind: FormatKind::Uri,
+        }
+    }
+
+    /// Create a validator that checks the value is a hostname, as defined by
+    /// RFC 1123.
+    #[inline]
+    pub fn hostname() -> Self {
+        Self {
+            kind: FormatKind::Hostname,
+        }
+    }
+
+    /// Create a validator that checks the value is an IPv4 address.
+    #[inline]
+    pub fn ipv4() -> Self {
+        Self {
+            kind: FormatKind::Ipv4,
+        }
+    }
+
+    /// Create a validator that checks the value is an IPv6 address.
+    #[inline]
+    pub fn ipv6() -> Self {
+        Self {
+            kind: FormatKind::Ipv6,
+        }
+    }
+
+    /// Create a validator that checks the value is a hyphenated UUID.
+    #[inline]
+    pub fn uuid() -> Self {
+        Self {
+            kind: FormatKind::Uuid,
+        }
+    }
+}
+
+fn is_hostname(value: &str) -> bool {
+    let value = value.strip_suffix('.').unwrap_or(value);
+    !value.is_empty()
+        && value.len() <= 253
+        && value.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
+
+impl<T: AsRef<str>> Validator<T> for Format {
+    fn check(&self, value: &T) -> bool {
+        let value = value.as_ref();
+        match self.kind {
+            FormatKind::Email => EMAIL.is_match(value),
+            FormatKind::Uri => URI.is_match(value),
+            FormatKind::Hostname => is_hostname(value),
+            FormatKind::Ipv4 => value.parse::<Ipv4Addr>().is_ok(),
+            FormatKind::Ipv6 => value.parse::<Ipv6Addr>().is_ok(),
+            FormatKind::Uuid => UUID.is_match(value),
+        }
+    }
+}
+
+impl ValidatorMeta for Format {
+    fn update_meta(&self, meta: &mut MetaSchema) {
+        meta.format = Some(self.kind.name());
+    }
+}
@@ -9,24 +9,43 @@ use crate::{
 #[display(fmt = "maxLength({})", len)]
 pub struct MaxLength {
     len: usize,
+    bytes: bool,
 }
 
 impl MaxLength {
+    /// Create a validator that measures the length in Unicode scalar values,
+    /// as JSON Schema does.
     #[inline]
     pub fn new(len: usize) -> Self {
-        Self { len }
+        Self { len, bytes: false }
+    }
+
+    /// Create a validator that measures the length in UTF-8 bytes.
+    #[inline]
+    pub fn bytes(len: usize) -> Self {
+        Self { len, bytes: true }
     }
 }
 
 impl<T: AsRef<str>> Validator<T> for MaxLength {
     #[inline]
     fn check(&self, value: &T) -> bool {
-        value.as_ref().len() <= self.len
+        let value = value.as_ref();
+        let len = if self.bytes {
+            value.len()
+        } else {
+            value.chars().count()
+        };
+        len <= self.len
     }
 }
 
 impl ValidatorMeta for MaxLength {
     fn update_meta(&self, meta: &mut MetaSchema) {
-        meta.max_length = Some(self.len);
+        // JSON Schema measures the length in characters, so a length in bytes
+        // can not be described.
+        if !self.bytes {
+            meta.max_length = Some(self.len);
+        }
     }
 }
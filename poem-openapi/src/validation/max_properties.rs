@@ -4,7 +4,7 @@ use derive_more::Display;
 
 use crate::{
     registry::MetaSchema,
-    validation::{Validator, ValidatorMeta},
+    validation::{properties_count, Validator, ValidatorMeta},
 };
 
 #[derive(Display)]
@@ -25,6 +25,10 @@ impl<K, V> Validator<HashMap<K, V>> for MaxProperties {
     fn check(&self, value: &HashMap<K, V>) -> bool {
         value.len() <= self.len
     }
+
+    fn error_message(&self, value: &HashMap<K, V>) -> String {
+        format!("{}, but got {}", self, properties_count(value.len()))
+    }
 }
 
 impl<K, V> Validator<BTreeMap<K, V>> for MaxProperties {
@@ -32,6 +36,10 @@ impl<K, V> Validator<BTreeMap<K, V>> for MaxProperties {
     fn check(&self, value: &BTreeMap<K, V>) -> bool {
         value.len() <= self.len
     }
+
+    fn error_message(&self, value: &BTreeMap<K, V>) -> String {
+        format!("{}, but got {}", self, properties_count(value.len()))
+    }
 }
 
 impl ValidatorMeta for MaxProperties {
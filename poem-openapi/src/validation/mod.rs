@@ -1,5 +1,7 @@
 use std::fmt::Display;
 
+mod fn_validator;
+mod format;
 mod max_items;
 mod max_length;
 mod max_properties;
@@ -12,6 +14,8 @@ mod multiple_of;
 mod pattern;
 mod unique_items;
 
+pub use fn_validator::FnValidator;
+pub use format::Format;
 pub use max_items::MaxItems;
 pub use max_length::MaxLength;
 pub use max_properties::MaxProperties;
@@ -30,8 +34,32 @@ use crate::registry::MetaSchema;
 pub trait Validator<T>: Display {
     /// Check the value is valid.
     fn check(&self, value: &T) -> bool;
+
+    /// Returns the message used when the value fails the check, the default
+    /// is the description of the validator.
+    fn error_message(&self, value: &T) -> String {
+        let _ = value;
+        self.to_string()
+    }
+
+    /// Checks the value and returns the error message if it is invalid.
+    fn validate(&self, value: &T) -> Result<(), String> {
+        if self.check(value) {
+            Ok(())
+        } else {
+            Err(self.error_message(value))
+        }
+    }
 }
 
 pub trait ValidatorMeta {
     fn update_meta(&self, meta: &mut MetaSchema);
 }
+
+/// Formats the number of properties in the error messages.
+fn properties_count(len: usize) -> String {
+    match len {
+        1 => "1 property".to_string(),
+        len => format!("{} properties", len),
+    }
+}
@@ -8,7 +8,7 @@ use poem_openapi::{
     payload::{Binary, Json, PlainText},
     registry::{MetaApi, MetaExternalDocument, MetaSchema},
     types::Type,
-    ApiRequest, ApiResponse, OpenApi, OpenApiService, Tags,
+    ApiRequest, ApiResponse, Object, OpenApi, OpenApiService, Tags,
 };
 
 #[tokio::test]
@@ -82,6 +82,255 @@ fn tag() {
     );
 }
 
+#[test]
+fn tags_in_document() {
+    #[derive(Tags)]
+    enum MyTags {
+        /// User operations
+        UserOperations,
+        /// Pet operations
+        PetOperations,
+    }
+
+    struct Api;
+
+    #[OpenApi(tag = "MyTags::UserOperations")]
+    impl Api {
+        #[oai(path = "/user", method = "get")]
+        async fn user(&self) {}
+
+        #[oai(path = "/pet", method = "get", tag = "MyTags::PetOperations")]
+        async fn pet(&self) {}
+    }
+
+    let spec: serde_json::Value =
+        serde_json::from_str(&OpenApiService::new(Api, "test", "1.0").spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/user"]["get"]["tags"],
+        serde_json::json!(["UserOperations"])
+    );
+    assert_eq!(
+        spec["paths"]["/pet"]["get"]["tags"],
+        serde_json::json!(["UserOperations", "PetOperations"])
+    );
+    assert_eq!(
+        spec["tags"],
+        serde_json::json!([
+            { "name": "PetOperations", "description": "Pet operations" },
+            { "name": "UserOperations", "description": "User operations" },
+        ])
+    );
+}
+
+#[tokio::test]
+async fn max_body_size() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/small", method = "post", max_body_size = 4)]
+        async fn small(&self, data: PlainText<String>) -> PlainText<String> {
+            data
+        }
+
+        #[oai(path = "/large", method = "post")]
+        async fn large(&self, data: PlainText<String>) -> PlainText<String> {
+            data
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0").max_body_size(8);
+    let spec: serde_json::Value = serde_json::from_str(&service.spec()).unwrap();
+    assert_eq!(spec["paths"]["/small"]["post"]["x-maxBodySize"], 4);
+    assert_eq!(spec["paths"]["/large"]["post"]["x-maxBodySize"], 8);
+
+    let ep = service.into_endpoint();
+    let call = |path: &'static str, body: &'static str, content_length: bool| {
+        let mut req = poem::Request::builder()
+            .method(Method::POST)
+            .uri(Uri::from_static(path))
+            .content_type("text/plain");
+        if content_length {
+            req = req.header("content-length", body.len());
+        }
+        ep.call(req.body(body))
+    };
+
+    let resp = call("/small", "abcd", true).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.into_body().into_string().await.unwrap(), "abcd");
+
+    let err = call("/small", "abcde", true).await.unwrap_err();
+    assert_eq!(err.as_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let err = call("/small", "abcde", false).await.unwrap_err();
+    assert_eq!(err.as_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    // the `Content-Length` header does not match the actual body
+    let err = ep
+        .call(
+            poem::Request::builder()
+                .method(Method::POST)
+                .uri(Uri::from_static("/small"))
+                .content_type("text/plain")
+                .header("content-length", 4)
+                .body("abcde"),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.as_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let resp = call("/large", "abcdefgh", false).await.unwrap();
+    assert_eq!(resp.into_body().into_string().await.unwrap(), "abcdefgh");
+
+    let err = call("/large", "abcdefghi", true).await.unwrap_err();
+    assert_eq!(err.as_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[test]
+fn validation_error_response() {
+    #[derive(Object)]
+    struct Inner {
+        #[oai(validator(maximum(value = "10")))]
+        value: i32,
+    }
+
+    #[derive(Object)]
+    struct Validated {
+        inner: Vec<Inner>,
+    }
+
+    #[derive(Object)]
+    struct Plain {
+        value: i32,
+    }
+
+    #[derive(ApiResponse)]
+    enum ConflictResponse {
+        #[oai(status = 200)]
+        Ok,
+        /// The value is locked
+        #[oai(status = 422)]
+        Locked(PlainText<String>),
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/validated", method = "post")]
+        async fn validated(&self, _data: Json<Validated>) {}
+
+        #[oai(path = "/declared", method = "post")]
+        async fn declared(&self, data: Json<Validated>) -> ConflictResponse {
+            if data.0.inner.is_empty() {
+                ConflictResponse::Locked(PlainText("empty".to_string()))
+            } else {
+                ConflictResponse::Ok
+            }
+        }
+
+        #[oai(path = "/plain", method = "post")]
+        async fn plain(&self, _data: Json<Plain>) {}
+    }
+
+    let spec: serde_json::Value =
+        serde_json::from_str(&OpenApiService::new(Api, "test", "1.0").spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/validated"]["post"]["responses"]["422"],
+        serde_json::json!({
+            "description": "Validation error",
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "type": "object",
+                        "required": ["errors"],
+                        "properties": {
+                            "errors": {
+                                "type": "array",
+                                "items": {
+                                    "type": "object",
+                                    "required": ["field", "code"],
+                                    "properties": {
+                                        "field": { "type": "string" },
+                                        "code": { "type": "string" },
+                                    },
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+        })
+    );
+    assert!(spec["paths"]["/plain"]["post"]["responses"]
+        .get("422")
+        .is_none());
+
+    let declared = &spec["paths"]["/declared"]["post"]["responses"]["422"]["content"];
+    assert!(declared.get("text/plain").is_some());
+    assert_eq!(
+        declared["application/json"],
+        spec["paths"]["/validated"]["post"]["responses"]["422"]["content"]["application/json"]
+    );
+}
+
+#[tokio::test]
+async fn deprecation_headers() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/old", method = "get", deprecated)]
+        async fn old(&self) {}
+
+        #[oai(
+            path = "/legacy",
+            method = "get",
+            sunset = "Sat, 31 Dec 2022 23:59:59 GMT"
+        )]
+        async fn legacy(&self) {}
+
+        #[oai(path = "/current", method = "get")]
+        async fn current(&self) {}
+
+        #[oai(path = "/failing", method = "get", deprecated)]
+        async fn failing(&self) -> poem::Result<()> {
+            Err(Error::from_status(StatusCode::CONFLICT))
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0");
+    let spec: serde_json::Value = serde_json::from_str(&service.spec()).unwrap();
+    assert_eq!(spec["paths"]["/old"]["get"]["deprecated"], true);
+    assert_eq!(spec["paths"]["/legacy"]["get"]["deprecated"], true);
+    assert_eq!(spec["paths"]["/current"]["get"].get("deprecated"), None);
+
+    let ep = service.into_endpoint();
+    let call = |path: &'static str| {
+        ep.call(
+            poem::Request::builder()
+                .uri(Uri::from_static(path))
+                .finish(),
+        )
+    };
+
+    let resp = call("/old").await.unwrap();
+    assert_eq!(resp.header("deprecation"), Some("true"));
+    assert_eq!(resp.header("sunset"), None);
+
+    let resp = call("/legacy").await.unwrap();
+    assert_eq!(resp.header("deprecation"), Some("true"));
+    assert_eq!(resp.header("sunset"), Some("Sat, 31 Dec 2022 23:59:59 GMT"));
+
+    let resp = call("/current").await.unwrap();
+    assert_eq!(resp.header("deprecation"), None);
+
+    let resp = call("/failing").await.unwrap();
+    assert_eq!(resp.status(), StatusCode::CONFLICT);
+    assert_eq!(resp.header("deprecation"), Some("true"));
+}
+
 #[tokio::test]
 async fn common_attributes() {
     #[derive(Tags)]
@@ -665,3 +914,163 @@ async fn external_docs() {
         })
     );
 }
+
+#[test]
+fn extensions() {
+    /// Internal
+    #[derive(Object)]
+    #[oai(extension(name = "x-internal", value = "true"))]
+    struct Obj {
+        a: i32,
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(
+            path = "/",
+            method = "post",
+            extension(name = "x-internal", value = "true"),
+            extension(name = "x-owner", value = r#"{"team": "payments"}"#)
+        )]
+        async fn test(&self, _obj: Json<Obj>) {}
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert_eq!(
+        meta.paths[0].operations[0].extensions,
+        vec![
+            ("x-internal", serde_json::json!(true)),
+            ("x-owner", serde_json::json!({ "team": "payments" }))
+        ]
+    );
+
+    let spec: serde_json::Value =
+        serde_json::from_str(&OpenApiService::new(Api, "test", "1.0").spec()).unwrap();
+    let operation = &spec["paths"]["/"]["post"];
+    assert_eq!(operation["x-internal"], serde_json::json!(true));
+    assert_eq!(operation["x-owner"]["team"], serde_json::json!("payments"));
+    assert_eq!(
+        spec["components"]["schemas"]["Obj"]["x-internal"],
+        serde_json::json!(true)
+    );
+}
+
+#[tokio::test]
+async fn vendor_content_type() {
+    #[derive(ApiResponse)]
+    enum MyResponse {
+        #[oai(status = 200, content_type = "application/vnd.myapi+json")]
+        Ok(Json<i32>),
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/value", method = "get")]
+        async fn value(&self) -> MyResponse {
+            MyResponse::Ok(Json(100))
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0");
+    let spec: serde_json::Value = serde_json::from_str(&service.spec()).unwrap();
+    let content = spec["paths"]["/value"]["get"]["responses"]["200"]["content"]
+        .as_object()
+        .unwrap();
+    assert_eq!(
+        content.keys().collect::<Vec<_>>(),
+        vec!["application/vnd.myapi+json"]
+    );
+
+    let resp = service
+        .into_endpoint()
+        .call(
+            poem::Request::builder()
+                .uri(Uri::from_static("/value"))
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.content_type(), Some("application/vnd.myapi+json"));
+    assert_eq!(resp.into_body().into_string().await.unwrap(), "100");
+}
+
+#[test]
+fn shared_request_body_schema() {
+    #[derive(poem_openapi::Object)]
+    struct User {
+        name: String,
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/users", method = "post")]
+        async fn create(&self, _user: Json<User>) {}
+
+        #[oai(path = "/users/:id", method = "put")]
+        async fn update(
+            &self,
+            id: poem_openapi::param::Path<i32>,
+            _user: Json<User>,
+        ) -> PlainText<String> {
+            PlainText(id.0.to_string())
+        }
+    }
+
+    let spec: serde_json::Value =
+        serde_json::from_str(&OpenApiService::new(Api, "test", "1.0").spec()).unwrap();
+    let schemas = spec["components"]["schemas"].as_object().unwrap();
+    assert_eq!(schemas.keys().collect::<Vec<_>>(), vec!["User"]);
+
+    for (path, method) in [("/users", "post"), ("/users/{id}", "put")] {
+        assert_eq!(
+            spec["paths"][path][method]["requestBody"]["content"]["application/json"]["schema"],
+            serde_json::json!({ "$ref": "#/components/schemas/User" })
+        );
+    }
+}
+
+#[test]
+fn operation_id() {
+    struct Api;
+
+    #[OpenApi(operation_id_style = "camelCase")]
+    impl Api {
+        #[oai(path = "/a", method = "get", operation_id = "getA")]
+        async fn a(&self) {}
+
+        #[oai(path = "/b", method = "get")]
+        async fn get_b(&self) {}
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert_eq!(meta.paths[0].operations[0].operation_id, Some("getA"));
+    assert_eq!(meta.paths[1].operations[0].operation_id, Some("getB"));
+}
+
+#[test]
+#[should_panic(expected = "duplicate operation id: getA")]
+fn duplicate_operation_id() {
+    struct Api1;
+
+    #[OpenApi]
+    impl Api1 {
+        #[oai(path = "/a", method = "get", operation_id = "getA")]
+        async fn a(&self) {}
+    }
+
+    struct Api2;
+
+    #[OpenApi(operation_id_style = "camelCase")]
+    impl Api2 {
+        #[oai(path = "/b", method = "get")]
+        async fn get_a(&self) {}
+    }
+
+    let _ = OpenApiService::new((Api1, Api2), "test", "1.0").into_endpoint();
+}
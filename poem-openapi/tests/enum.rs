@@ -1,6 +1,6 @@
 use poem_openapi::{
     registry::{MetaExternalDocument, MetaSchemaRef, Registry},
-    types::{ParseFromJSON, ToJSON, Type},
+    types::{ParseFromJSON, ParseFromParameter, ToJSON, Type},
     Enum,
 };
 use serde_json::{json, Value};
@@ -67,6 +67,44 @@ fn rename_all() {
     );
 }
 
+#[test]
+fn rename_all_kebab_and_train_case() {
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(rename_all = "kebab-case")]
+    enum Kebab {
+        CreateUser,
+    }
+
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(rename_all = "SCREAMING-KEBAB-CASE")]
+    enum ScreamingKebab {
+        CreateUser,
+    }
+
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(rename_all = "Train-Case")]
+    enum Train {
+        CreateUser,
+    }
+
+    assert_eq!(
+        Kebab::CreateUser.to_json(),
+        Some(Value::String("create-user".to_string()))
+    );
+    assert_eq!(
+        ScreamingKebab::CreateUser.to_json(),
+        Some(Value::String("CREATE-USER".to_string()))
+    );
+    assert_eq!(
+        Train::CreateUser.to_json(),
+        Some(Value::String("Create-User".to_string()))
+    );
+    assert_eq!(
+        Train::parse_from_json(Some(Value::String("Create-User".to_string()))).unwrap(),
+        Train::CreateUser
+    );
+}
+
 #[test]
 fn rename_item() {
     #[derive(Enum, Debug, Eq, PartialEq)]
@@ -172,6 +210,45 @@ fn description() {
     assert_eq!(meta.description, Some("A\n\nAB\nCDE"));
 }
 
+#[test]
+fn item_descriptions() {
+    /// Pet status
+    #[derive(Enum)]
+    enum PetStatus {
+        /// The pet can be adopted.
+        Available,
+        /// The pet is reserved.
+        ///
+        /// Reservations expire after a week.
+        Pending,
+        Sold,
+    }
+
+    let mut registry = Registry::new();
+    PetStatus::register(&mut registry);
+    let meta = registry.schemas.remove("PetStatus").unwrap();
+    assert_eq!(
+        meta.description,
+        Some(
+            "Pet status\n\n- `Available`: The pet can be adopted.\n- `Pending`: The pet is reserved.\n\n  Reservations expire after a week."
+        )
+    );
+
+    #[derive(Enum)]
+    #[oai(integer)]
+    #[repr(i32)]
+    enum Priority {
+        /// Handled last.
+        Low = 1,
+        High = 10,
+    }
+
+    let mut registry = Registry::new();
+    Priority::register(&mut registry);
+    let meta = registry.schemas.remove("Priority").unwrap();
+    assert_eq!(meta.description, Some("- `Low`: Handled last."));
+}
+
 #[test]
 fn deprecated() {
     #[derive(Enum)]
@@ -218,3 +295,73 @@ async fn external_docs() {
         })
     );
 }
+
+#[test]
+fn inline() {
+    /// Color
+    #[derive(Enum, Debug, Eq, PartialEq)]
+    #[oai(inline)]
+    enum Color {
+        Red,
+        Green,
+    }
+
+    let mut registry = Registry::new();
+    Color::register(&mut registry);
+    assert!(registry.schemas.is_empty());
+
+    let schema = Color::schema_ref();
+    let schema = schema.unwrap_inline();
+    assert_eq!(schema.ty, "string");
+    assert_eq!(schema.description, Some("Color"));
+    assert_eq!(schema.enum_items, vec![json!("Red"), json!("Green")]);
+}
+
+#[test]
+fn integer_enum() {
+    #[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+    #[oai(integer)]
+    #[repr(i64)]
+    enum Priority {
+        Low = 1,
+        Medium,
+        High = 10,
+    }
+
+    let mut registry = Registry::new();
+    Priority::register(&mut registry);
+    let meta = registry.schemas.remove("Priority").unwrap();
+    assert_eq!(meta.ty, "integer");
+    assert_eq!(meta.format, Some("int64"));
+    assert_eq!(meta.enum_items, vec![json!(1), json!(2), json!(10)]);
+
+    for item in [Priority::Low, Priority::Medium, Priority::High] {
+        assert_eq!(Priority::parse_from_json(item.to_json()).unwrap(), item);
+    }
+    assert_eq!(Priority::High.to_json(), Some(json!(10)));
+    assert_eq!(
+        Priority::parse_from_parameter("2").unwrap(),
+        Priority::Medium
+    );
+
+    assert!(Priority::parse_from_json(Some(json!(3))).is_err());
+    assert!(Priority::parse_from_json(Some(json!("Low"))).is_err());
+    assert!(Priority::parse_from_parameter("Low").is_err());
+}
+
+#[test]
+fn repr_without_integer() {
+    #[derive(Enum, Debug, Copy, Clone, Eq, PartialEq)]
+    #[repr(u8)]
+    enum Priority {
+        Low = 1,
+        High = 10,
+    }
+
+    let mut registry = Registry::new();
+    Priority::register(&mut registry);
+    let meta = registry.schemas.remove("Priority").unwrap();
+    assert_eq!(meta.ty, "string");
+    assert_eq!(meta.enum_items, vec![json!("Low"), json!("High")]);
+    assert_eq!(Priority::High.to_json(), Some(json!("High")));
+}
@@ -519,3 +519,71 @@ async fn deny_unknown_fields() {
     .unwrap_err();
     assert_eq!(err.to_string(), "parse multipart error: unknown field `c`");
 }
+
+#[tokio::test]
+async fn object_field() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Owner {
+        name: String,
+        age: i32,
+    }
+
+    #[derive(Multipart, Debug, Eq, PartialEq)]
+    struct A {
+        name: String,
+        owner: Owner,
+        owners: Vec<Owner>,
+    }
+
+    let data = create_multipart_payload(&[
+        ("name", None, b"abc"),
+        ("owner", None, br#"{"name": "sunli", "age": 18}"#),
+        ("owners", None, br#"{"name": "a", "age": 1}"#),
+        ("owners", None, br#"{"name": "b", "age": 2}"#),
+    ]);
+    let a = A::from_request(
+        &Request::builder()
+            .header("content-type", "multipart/form-data; boundary=X-BOUNDARY")
+            .finish(),
+        &mut RequestBody::new(data.into()),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        a,
+        A {
+            name: "abc".to_string(),
+            owner: Owner {
+                name: "sunli".to_string(),
+                age: 18
+            },
+            owners: vec![
+                Owner {
+                    name: "a".to_string(),
+                    age: 1
+                },
+                Owner {
+                    name: "b".to_string(),
+                    age: 2
+                }
+            ],
+        }
+    );
+
+    let data = create_multipart_payload(&[
+        ("name", None, b"abc"),
+        ("owner", None, br#"{"name": "sunli"}"#),
+    ]);
+    let err = A::from_request(
+        &Request::builder()
+            .header("content-type", "multipart/form-data; boundary=X-BOUNDARY")
+            .finish(),
+        &mut RequestBody::new(data.into()),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        r#"parse multipart error: failed to parse field `owner`: Expected input type "integer(int32)", found null. (occurred while parsing "Owner")"#
+    );
+}
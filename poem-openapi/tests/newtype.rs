@@ -1,4 +1,11 @@
-use poem_openapi::{types::Type, NewType};
+use poem::{
+    http::{StatusCode, Uri},
+    Endpoint, IntoEndpoint, Request,
+};
+use poem_openapi::{
+    param::Path, payload::PlainText, registry::Registry, types::Type, NewType, OpenApi,
+    OpenApiService,
+};
 
 #[tokio::test]
 async fn new_type() {
@@ -22,3 +29,64 @@ async fn new_type_summary_and_description() {
     let schema = schema.unwrap_inline();
     assert_eq!(schema.description, Some("MyString\n\nA\nB\nC"));
 }
+
+#[test]
+fn inline() {
+    /// User id
+    #[derive(NewType)]
+    struct UserId(i64);
+
+    let mut registry = Registry::new();
+    UserId::register(&mut registry);
+    assert!(registry.schemas.is_empty());
+
+    let schema = UserId::schema_ref();
+    let schema = schema.unwrap_inline();
+    assert_eq!(schema.ty, "integer");
+    assert_eq!(schema.format, Some("int64"));
+    assert_eq!(schema.description, Some("User id"));
+}
+
+#[tokio::test]
+async fn path_parameter() {
+    #[derive(NewType)]
+    struct UserId(u64);
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/users/:id", method = "get")]
+        async fn get_user(&self, id: Path<UserId>) -> PlainText<String> {
+            PlainText(format!("user {}", id.0 .0))
+        }
+    }
+
+    let meta = Api::meta().remove(0);
+    let param = &meta.paths[0].operations[0].params[0];
+    assert_eq!(param.name, "id");
+    let schema = param.schema.unwrap_inline();
+    assert_eq!(schema.ty, "integer");
+    assert_eq!(schema.format, Some("uint64"));
+
+    let ep = OpenApiService::new(Api, "test", "1.0").into_endpoint();
+    let resp = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/users/42"))
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.into_body().into_string().await.unwrap(), "user 42");
+
+    let err = ep
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/users/abc"))
+                .finish(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.as_response().status(), StatusCode::BAD_REQUEST);
+}
@@ -1,7 +1,9 @@
+use std::collections::HashMap;
+
 use poem_openapi::{
-    registry::{MetaExternalDocument, MetaSchema, MetaSchemaRef, Registry},
+    registry::{MetaExample, MetaExternalDocument, MetaSchema, MetaSchemaRef, Registry},
     types::{ParseFromJSON, ToJSON, Type},
-    Enum, NewType, Object, OpenApi,
+    Enum, NewType, Object, OpenApi, Union,
 };
 use serde_json::json;
 
@@ -35,6 +37,53 @@ fn rename_all() {
     assert_eq!(meta.properties[1].0, "deleteUser");
 }
 
+#[test]
+fn rename_all_kebab_and_train_case() {
+    #[derive(Object)]
+    #[oai(rename_all = "kebab-case")]
+    struct Kebab {
+        create_user: i32,
+    }
+
+    #[derive(Object)]
+    struct Inner {
+        delete_user: i32,
+    }
+
+    #[derive(Object)]
+    #[oai(rename_all = "SCREAMING-KEBAB-CASE", flatten_rename_all)]
+    struct ScreamingKebab {
+        create_user: i32,
+        #[oai(flatten)]
+        inner: Inner,
+    }
+
+    #[derive(Object, Debug)]
+    #[oai(rename_all = "Train-Case")]
+    struct Train {
+        create_user: i32,
+    }
+
+    assert_eq!(get_meta::<Kebab>().properties[0].0, "create-user");
+    assert_eq!(get_meta::<ScreamingKebab>().properties[0].0, "CREATE-USER");
+    assert_eq!(get_meta::<Train>().properties[0].0, "Create-User");
+
+    assert_eq!(
+        ScreamingKebab {
+            create_user: 1,
+            inner: Inner { delete_user: 2 }
+        }
+        .to_json(),
+        Some(json!({ "CREATE-USER": 1, "DELETE-USER": 2 }))
+    );
+    assert_eq!(
+        Train::parse_from_json(Some(json!({ "Create-User": 1 })))
+            .unwrap()
+            .create_user,
+        1
+    );
+}
+
 #[test]
 fn concretes() {
     #[derive(Object)]
@@ -84,6 +133,37 @@ fn deprecated() {
     assert!(meta.deprecated);
 }
 
+#[test]
+fn field_deprecated() {
+    #[derive(Object)]
+    struct Obj {
+        a: i32,
+        #[oai(deprecated)]
+        b: i32,
+        #[oai(deprecated)]
+        c: Inner,
+    }
+
+    #[derive(Object)]
+    struct Inner {
+        v: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert!(!meta.deprecated);
+    assert!(!meta.properties[0].1.unwrap_inline().deprecated);
+    assert!(meta.properties[1].1.unwrap_inline().deprecated);
+    assert_eq!(meta.properties[1].1.unwrap_inline().ty, "integer");
+
+    let field_c = meta.properties[2].1.unwrap_inline();
+    assert_eq!(field_c.all_of[0], MetaSchemaRef::Reference("Inner"));
+    assert!(field_c.all_of[1].unwrap_inline().deprecated);
+
+    let mut registry = Registry::new();
+    Obj::register(&mut registry);
+    assert!(!registry.schemas["Inner"].deprecated);
+}
+
 #[test]
 fn read_only_all() {
     #[derive(Debug, Object, PartialEq)]
@@ -388,6 +468,28 @@ fn read_only() {
     );
 }
 
+#[test]
+fn read_only_lenient() {
+    #[derive(Debug, Object, PartialEq)]
+    #[oai(deny_unknown_fields)]
+    struct Obj {
+        #[oai(read_only_lenient)]
+        id: i32,
+        value: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.properties[0].0, "id");
+    assert!(meta.properties[0].1.unwrap_inline().read_only);
+
+    let json = Obj { id: 99, value: 100 }.to_json();
+    assert_eq!(json, Some(json!({ "id": 99, "value": 100 })));
+    assert_eq!(
+        Obj::parse_from_json(json).unwrap(),
+        Obj { id: 0, value: 100 }
+    );
+}
+
 #[test]
 fn write_only() {
     #[derive(Debug, Object, PartialEq)]
@@ -418,6 +520,44 @@ fn write_only() {
     );
 }
 
+#[test]
+fn write_only_password_format() {
+    #[derive(Object)]
+    #[allow(dead_code)]
+    struct Obj {
+        name: String,
+        #[oai(write_only)]
+        password: String,
+        #[oai(write_only)]
+        old_password: Option<String>,
+        #[oai(write_only, format = "byte")]
+        key: String,
+        #[oai(write_only)]
+        pin: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    let format = |idx: usize| meta.properties[idx].1.unwrap_inline().format;
+    assert_eq!(format(0), None);
+    assert_eq!(format(1), Some("password"));
+    assert_eq!(format(2), Some("password"));
+    assert_eq!(format(3), Some("byte"));
+    assert_eq!(format(4), Some("int32"));
+
+    #[derive(Object)]
+    #[oai(write_only_all)]
+    #[allow(dead_code)]
+    struct WriteOnlyAll {
+        name: String,
+        #[oai(skip_serializing)]
+        token: String,
+    }
+
+    let meta = get_meta::<WriteOnlyAll>();
+    assert_eq!(meta.properties[0].1.unwrap_inline().format, None);
+    assert_eq!(meta.properties[1].1.unwrap_inline().format, None);
+}
+
 #[test]
 fn inline_fields() {
     #[derive(Object)]
@@ -560,6 +700,130 @@ fn example() {
     );
 }
 
+#[test]
+fn example_from_default() {
+    #[derive(Object)]
+    #[oai(example_from_default)]
+    struct Obj {
+        a: i32,
+        b: String,
+    }
+
+    impl Default for Obj {
+        fn default() -> Self {
+            Obj {
+                a: 10,
+                b: "abc".to_string(),
+            }
+        }
+    }
+
+    #[derive(Object, Default)]
+    struct NoExample {
+        a: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.example, Some(json!({ "a": 10, "b": "abc" })));
+    assert_eq!(get_meta::<NoExample>().example, None);
+}
+
+#[test]
+fn named_examples() {
+    #[derive(Object)]
+    #[oai(
+        example(name = "minimal", value = "minimal_example"),
+        example(name = "full", value = "full_example", summary = "Full")
+    )]
+    struct Obj {
+        a: i32,
+        b: Option<String>,
+    }
+
+    fn minimal_example() -> Obj {
+        Obj { a: 1, b: None }
+    }
+
+    fn full_example() -> Obj {
+        Obj {
+            a: 2,
+            b: Some("abc".to_string()),
+        }
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.example, Some(json!({ "a": 1, "b": null })));
+    assert!(serde_json::to_value(&meta)
+        .unwrap()
+        .get("examples")
+        .is_none());
+
+    assert_eq!(
+        Obj::examples(),
+        vec![
+            MetaExample {
+                name: "minimal",
+                summary: None,
+                value: json!({ "a": 1, "b": null }),
+            },
+            MetaExample {
+                name: "full",
+                summary: Some("Full"),
+                value: json!({ "a": 2, "b": "abc" }),
+            },
+        ]
+    );
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "post")]
+        async fn create(
+            &self,
+            obj: poem_openapi::payload::Json<Obj>,
+        ) -> poem_openapi::payload::Json<Obj> {
+            obj
+        }
+    }
+
+    let spec: serde_json::Value =
+        serde_json::from_str(&poem_openapi::OpenApiService::new(Api, "test", "1.0").spec())
+            .unwrap();
+    let operation = &spec["paths"]["/"]["post"];
+    let examples = json!({
+        "minimal": { "value": { "a": 1, "b": null } },
+        "full": { "summary": "Full", "value": { "a": 2, "b": "abc" } },
+    });
+    assert_eq!(
+        operation["requestBody"]["content"]["application/json"]["examples"],
+        examples
+    );
+    assert_eq!(
+        operation["responses"]["200"]["content"]["application/json"]["examples"],
+        examples
+    );
+}
+
+#[test]
+fn example_value() {
+    #[derive(Object)]
+    #[oai(example_value = r#"{ "a": 100, "b": "abc" }"#)]
+    struct Obj {
+        a: i32,
+        b: String,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(
+        meta.example,
+        Some(json!({
+            "a": 100,
+            "b": "abc",
+        }))
+    );
+}
+
 #[test]
 fn concretes_example() {
     #[derive(Object)]
@@ -651,6 +915,81 @@ fn required_fields() {
     assert_eq!(meta.required, vec!["a"]);
 }
 
+#[test]
+fn nullable_field() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Obj {
+        a: i32,
+        #[oai(nullable)]
+        b: Option<i32>,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.required, vec!["a", "b"]);
+    assert!(!meta.properties[0].1.unwrap_inline().nullable);
+    assert!(meta.properties[1].1.unwrap_inline().nullable);
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({"a": 1, "b": null}))).unwrap(),
+        Obj { a: 1, b: None }
+    );
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({"a": 1, "b": 2}))).unwrap(),
+        Obj { a: 1, b: Some(2) }
+    );
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({"a": 1})))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"Obj\": properties `b` is required."
+    );
+
+    assert_eq!(
+        Obj { a: 1, b: None }.to_json(),
+        Some(json!({"a": 1, "b": null}))
+    );
+}
+
+#[test]
+fn const_value() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Obj {
+        #[oai(const_value = "user")]
+        ty: String,
+        a: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.required, vec!["ty", "a"]);
+    let field_meta = meta.properties[0].1.unwrap_inline();
+    assert_eq!(field_meta.ty, "string");
+    assert_eq!(field_meta.enum_items, vec![json!("user")]);
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({ "ty": "user", "a": 1 }))).unwrap(),
+        Obj {
+            ty: "user".to_string(),
+            a: 1
+        }
+    );
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({ "ty": "admin", "a": 1 })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"Obj\": properties `ty` must be `\"user\"`."
+    );
+    assert!(Obj::parse_from_json(Some(json!({ "a": 1 }))).is_err());
+
+    assert_eq!(
+        Obj {
+            ty: String::new(),
+            a: 1
+        }
+        .to_json(),
+        Some(json!({ "ty": "user", "a": 1 }))
+    );
+}
+
 #[tokio::test]
 async fn external_docs() {
     #[derive(Object)]
@@ -695,6 +1034,43 @@ fn issue_171() {
     let _ = poem_openapi::OpenApiService::new(Api, "a", "1.0").spec();
 }
 
+#[test]
+fn field_schema_ref() {
+    #[derive(Object)]
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Object)]
+    struct Obj {
+        #[oai(schema_ref = "SharedAddress")]
+        address: Address,
+        /// The previous address
+        #[oai(schema_ref = "SharedAddress")]
+        previous: Option<Address>,
+    }
+
+    let mut registry = Registry::new();
+    Obj::register(&mut registry);
+    assert!(!registry.schemas.contains_key("Address"));
+
+    let meta = registry.schemas.remove("Obj").unwrap();
+    assert_eq!(meta.required, vec!["address"]);
+    assert_eq!(
+        meta.properties[0].1,
+        MetaSchemaRef::Reference("SharedAddress")
+    );
+    let previous = meta.properties[1].1.unwrap_inline();
+    assert_eq!(
+        previous.all_of[0],
+        MetaSchemaRef::Reference("SharedAddress")
+    );
+    assert_eq!(
+        previous.all_of[1].unwrap_inline().description,
+        Some("The previous address")
+    );
+}
+
 #[test]
 fn flatten_field() {
     #[derive(Object, Debug, Eq, PartialEq)]
@@ -728,3 +1104,1065 @@ fn flatten_field() {
         obj
     );
 }
+
+#[test]
+fn flatten_read_only_and_write_only() {
+    #[derive(Object)]
+    #[allow(dead_code)]
+    struct Audit {
+        #[oai(read_only)]
+        created_by: String,
+        #[oai(write_only)]
+        secret: String,
+        note: String,
+    }
+
+    #[derive(Object, Default)]
+    struct Timestamps {
+        created_at: i64,
+        updated_at: i64,
+    }
+
+    #[derive(Object)]
+    struct Obj {
+        #[oai(flatten)]
+        audit: Audit,
+        #[oai(flatten, read_only)]
+        timestamps: Timestamps,
+        name: String,
+    }
+
+    let meta = get_meta::<Obj>();
+    let flags = meta
+        .properties
+        .iter()
+        .map(|(name, schema)| {
+            let schema = schema.unwrap_inline();
+            (*name, schema.read_only, schema.write_only)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        flags,
+        vec![
+            ("created_by", true, false),
+            ("secret", false, true),
+            ("note", false, false),
+            ("created_at", true, false),
+            ("updated_at", true, false),
+            ("name", false, false),
+        ]
+    );
+}
+
+#[test]
+fn flatten_field_error() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Range1 {
+        #[oai(validator(maximum(value = "10")))]
+        value: i32,
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Range2 {
+        #[oai(validator(maximum(value = "100")))]
+        value: i32,
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Obj {
+        #[oai(flatten)]
+        first: Range1,
+        #[oai(flatten)]
+        second: Range2,
+    }
+
+    let err = Obj::parse_from_json(Some(json!({ "value": 50 }))).unwrap_err();
+    assert_eq!(err.pointer(), "/value");
+    assert_eq!(err.code(), "maximum");
+    assert_eq!(
+        err.into_message(),
+        r#"failed to parse "Range1": field `value` verification failed. maximum(10, exclusive: false) (occurred while parsing the flattened field "first" of "Obj")"#
+    );
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct RawObj {
+        #[oai(flatten)]
+        r#type: Range1,
+    }
+
+    assert_eq!(
+        RawObj::parse_from_json(Some(json!({ "value": 50 })))
+            .unwrap_err()
+            .into_message(),
+        r#"failed to parse "Range1": field `value` verification failed. maximum(10, exclusive: false) (occurred while parsing the flattened field "type" of "RawObj")"#
+    );
+}
+
+#[test]
+fn default_with() {
+    fn default_c(obj: &ObjPartial) -> i32 {
+        obj.a * 10 + obj.b.unwrap_or_default()
+    }
+
+    fn default_d(obj: &ObjPartial) -> String {
+        format!("{}-{}", obj.a, obj.e)
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Obj {
+        a: i32,
+        b: Option<i32>,
+        #[oai(default_with = "default_c")]
+        c: i32,
+        #[oai(default_with = "default_d")]
+        d: String,
+        #[oai(default)]
+        e: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.required, vec!["a"]);
+    assert_eq!(meta.properties[2].1.unwrap_inline().default, None);
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({ "a": 1, "b": 2 }))).unwrap(),
+        Obj {
+            a: 1,
+            b: Some(2),
+            c: 12,
+            d: "1-0".to_string(),
+            e: 0,
+        }
+    );
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({ "a": 3, "e": 4 }))).unwrap(),
+        Obj {
+            a: 3,
+            b: None,
+            c: 30,
+            d: "3-4".to_string(),
+            e: 4,
+        }
+    );
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({ "a": 3, "c": 5, "d": "x" }))).unwrap(),
+        Obj {
+            a: 3,
+            b: None,
+            c: 5,
+            d: "x".to_string(),
+            e: 0,
+        }
+    );
+}
+
+#[test]
+fn serde_compat() {
+    fn default_b() -> i32 {
+        7
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[oai(serde_compat)]
+    struct Obj {
+        #[serde(rename = "A")]
+        a: i32,
+        #[serde(default = "default_b")]
+        b: i32,
+        #[serde(skip)]
+        c: i32,
+        #[serde(rename = "ignored")]
+        #[oai(rename = "D")]
+        d: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.required, vec!["A", "D"]);
+    assert_eq!(meta.properties[0].0, "A");
+    assert_eq!(meta.properties[1].0, "b");
+    assert_eq!(meta.properties[1].1.unwrap_inline().default, Some(json!(7)));
+    assert_eq!(meta.properties[2].0, "D");
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({"A": 1, "D": 4, "c": 3}))).unwrap(),
+        Obj {
+            a: 1,
+            b: 7,
+            c: 0,
+            d: 4
+        }
+    );
+}
+
+#[test]
+fn title() {
+    #[derive(Object)]
+    #[oai(title = "A friendly object")]
+    struct Obj {
+        #[oai(title = "The first field")]
+        a: i32,
+        b: i32,
+    }
+
+    assert_eq!(Obj::name(), "Obj");
+    let meta = get_meta::<Obj>();
+    assert_eq!(meta.title.as_deref(), Some("A friendly object"));
+    assert_eq!(
+        meta.properties[0].1.unwrap_inline().title.as_deref(),
+        Some("The first field")
+    );
+    assert_eq!(meta.properties[1].1.unwrap_inline().title, None);
+}
+
+#[test]
+fn concretes_inferred_name() {
+    #[derive(Object)]
+    struct User {
+        id: i32,
+    }
+
+    #[derive(Object)]
+    #[oai(
+        concrete(params(User, i64)),
+        concrete(name = "CustomPage", params(i32, u64)),
+        concrete(params("Vec<User>", "Option<i64>"))
+    )]
+    struct Page<T1: ParseFromJSON + ToJSON, T2: ParseFromJSON + ToJSON> {
+        items: T1,
+        total: T2,
+    }
+
+    assert_eq!(<Page<User, i64>>::name(), "PageUserI64");
+    assert_eq!(<Page<i32, u64>>::name(), "CustomPage");
+    assert_eq!(
+        <Page<Vec<User>, Option<i64>>>::name(),
+        "PageVecUserOptionI64"
+    );
+}
+
+#[test]
+fn field_format() {
+    #[derive(Object)]
+    struct Obj {
+        #[oai(format = "uuid")]
+        id: String,
+        #[oai(format = "int53")]
+        count: i64,
+        name: String,
+    }
+
+    let meta = get_meta::<Obj>();
+    let id = meta.properties[0].1.unwrap_inline();
+    assert_eq!(id.ty, "string");
+    assert_eq!(id.format, Some("uuid"));
+    let count = meta.properties[1].1.unwrap_inline();
+    assert_eq!(count.ty, "integer");
+    assert_eq!(count.format, Some("int53"));
+    assert_eq!(meta.properties[2].1.unwrap_inline().format, None);
+}
+
+#[test]
+fn flatten_union() {
+    #[derive(Object, Debug, PartialEq)]
+    struct Cat {
+        lives: i32,
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    struct Dog {
+        barks: bool,
+    }
+
+    #[derive(Union, Debug, PartialEq)]
+    #[oai(discriminator_name = "kind")]
+    enum Animal {
+        Cat(Cat),
+        Dog(Dog),
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    struct Pet {
+        name: String,
+        #[oai(flatten)]
+        animal: Animal,
+    }
+
+    let mut registry = Registry::new();
+    Pet::register(&mut registry);
+    let meta = registry.schemas.remove("Pet").unwrap();
+    assert_eq!(meta.required, vec!["name"]);
+    assert_eq!(meta.properties.len(), 1);
+    assert_eq!(meta.properties[0].0, "name");
+    assert_eq!(meta.all_of, vec![MetaSchemaRef::Reference("Animal")]);
+    assert!(registry.schemas.contains_key("Animal"));
+    assert!(registry.schemas.contains_key("Cat"));
+    assert!(registry.schemas.contains_key("Dog"));
+
+    let pet = Pet {
+        name: "Tom".to_string(),
+        animal: Animal::Cat(Cat { lives: 9 }),
+    };
+    let value = json!({"name": "Tom", "kind": "Cat", "lives": 9});
+    assert_eq!(pet.to_json(), Some(value.clone()));
+    assert_eq!(Pet::parse_from_json(Some(value)).unwrap(), pet);
+
+    assert_eq!(
+        Pet::parse_from_json(Some(json!({"name": "Spike", "kind": "Dog", "barks": true}))).unwrap(),
+        Pet {
+            name: "Spike".to_string(),
+            animal: Animal::Dog(Dog { barks: true }),
+        }
+    );
+}
+
+#[test]
+fn flatten_all_of() {
+    #[derive(Object, Debug, PartialEq)]
+    struct Timestamps {
+        created_at: i64,
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    struct Owner {
+        owner: String,
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    #[oai(inline)]
+    struct Tags {
+        tags: Vec<String>,
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    #[oai(flatten_all_of)]
+    struct Document {
+        title: String,
+        #[oai(flatten)]
+        timestamps: Timestamps,
+        #[oai(flatten)]
+        owner: Owner,
+        #[oai(flatten)]
+        tags: Tags,
+    }
+
+    let mut registry = Registry::new();
+    Document::register(&mut registry);
+    let meta = registry.schemas.remove("Document").unwrap();
+    assert_eq!(meta.required, vec!["title", "tags"]);
+    assert_eq!(
+        meta.properties
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>(),
+        vec!["title", "tags"]
+    );
+    assert_eq!(
+        meta.all_of,
+        vec![
+            MetaSchemaRef::Reference("Timestamps"),
+            MetaSchemaRef::Reference("Owner")
+        ]
+    );
+    assert!(registry.schemas.contains_key("Timestamps"));
+    assert!(registry.schemas.contains_key("Owner"));
+
+    let doc = Document {
+        title: "a".to_string(),
+        timestamps: Timestamps { created_at: 1 },
+        owner: Owner {
+            owner: "sunli".to_string(),
+        },
+        tags: Tags {
+            tags: vec!["b".to_string()],
+        },
+    };
+    let value = json!({"title": "a", "created_at": 1, "owner": "sunli", "tags": ["b"]});
+    assert_eq!(doc.to_json(), Some(value.clone()));
+    assert_eq!(Document::parse_from_json(Some(value)).unwrap(), doc);
+}
+
+#[test]
+fn flatten_rename_all() {
+    #[derive(Object, Debug, PartialEq)]
+    struct Audit {
+        created_at: i64,
+        updated_by: Option<String>,
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    #[oai(rename_all = "camelCase", flatten_rename_all)]
+    struct Document {
+        document_title: String,
+        #[oai(flatten)]
+        audit: Audit,
+    }
+
+    let mut registry = Registry::new();
+    Document::register(&mut registry);
+    let meta = registry.schemas.remove("Document").unwrap();
+    assert_eq!(meta.required, vec!["documentTitle", "createdAt"]);
+    assert_eq!(
+        meta.properties
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>(),
+        vec!["documentTitle", "createdAt", "updatedBy"]
+    );
+
+    let doc = Document {
+        document_title: "a".to_string(),
+        audit: Audit {
+            created_at: 1,
+            updated_by: Some("sunli".to_string()),
+        },
+    };
+    let value = json!({"documentTitle": "a", "createdAt": 1, "updatedBy": "sunli"});
+    assert_eq!(doc.to_json(), Some(value.clone()));
+    assert_eq!(Document::parse_from_json(Some(value)).unwrap(), doc);
+
+    assert!(
+        Document::parse_from_json(Some(json!({"documentTitle": "a", "created_at": 1}))).is_err()
+    );
+}
+
+#[test]
+fn flatten_map() {
+    #[derive(Object, Debug, PartialEq)]
+    struct Audit {
+        created_by: String,
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    #[oai(deny_unknown_fields)]
+    struct Event {
+        name: String,
+        #[oai(flatten)]
+        extra: HashMap<String, serde_json::Value>,
+        #[oai(flatten)]
+        audit: Audit,
+        count: i32,
+    }
+
+    let mut registry = Registry::new();
+    Event::register(&mut registry);
+    let meta = registry.schemas.remove("Event").unwrap();
+    assert_eq!(meta.required, vec!["name", "created_by", "count"]);
+    assert_eq!(
+        meta.properties
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>(),
+        vec!["name", "created_by", "count"]
+    );
+    assert!(meta.additional_properties.is_some());
+
+    let event = Event::parse_from_json(Some(json!({
+        "name": "click",
+        "count": 3,
+        "created_by": "sunli",
+        "button": "left",
+        "position": {"x": 1, "y": 2},
+    })))
+    .unwrap();
+    assert_eq!(
+        event,
+        Event {
+            name: "click".to_string(),
+            extra: [
+                ("button".to_string(), json!("left")),
+                ("position".to_string(), json!({"x": 1, "y": 2})),
+            ]
+            .into_iter()
+            .collect(),
+            audit: Audit {
+                created_by: "sunli".to_string()
+            },
+            count: 3,
+        }
+    );
+    assert_eq!(
+        event.to_json(),
+        Some(json!({
+            "name": "click",
+            "count": 3,
+            "created_by": "sunli",
+            "button": "left",
+            "position": {"x": 1, "y": 2},
+        }))
+    );
+}
+
+#[test]
+fn namespace() {
+    mod a {
+        use poem_openapi::Object;
+
+        #[derive(Object)]
+        #[oai(namespace = "a")]
+        pub struct User {
+            pub id: i32,
+        }
+    }
+
+    mod b {
+        use poem_openapi::Object;
+
+        #[derive(Object)]
+        #[oai(namespace = "b")]
+        pub struct User {
+            pub name: String,
+        }
+    }
+
+    #[derive(Object)]
+    struct Obj {
+        a: a::User,
+        b: b::User,
+    }
+
+    assert_eq!(a::User::name(), "a.User");
+    assert_eq!(b::User::name(), "b.User");
+
+    let mut registry = Registry::new();
+    Obj::register(&mut registry);
+    let meta = registry.schemas.remove("Obj").unwrap();
+    assert_eq!(meta.properties[0].1, MetaSchemaRef::Reference("a.User"));
+    assert_eq!(meta.properties[1].1, MetaSchemaRef::Reference("b.User"));
+    assert_eq!(registry.schemas["a.User"].properties[0].0, "id");
+    assert_eq!(registry.schemas["b.User"].properties[0].0, "name");
+}
+
+#[test]
+fn patch() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    #[oai(patch = "UserPatch", rename_all = "camelCase")]
+    struct User {
+        #[oai(read_only)]
+        id: i32,
+        #[oai(validator(max_length = 8))]
+        user_name: String,
+        nick_name: Option<String>,
+        #[oai(validator(maximum(value = "200")))]
+        age: Option<i32>,
+    }
+
+    let meta = get_meta::<UserPatch>();
+    assert!(meta.required.is_empty());
+    assert_eq!(
+        meta.properties
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>(),
+        vec!["userName", "nickName", "age"]
+    );
+
+    let mut user = User {
+        id: 1,
+        user_name: "sunli".to_string(),
+        nick_name: Some("sl".to_string()),
+        age: Some(18),
+    };
+
+    let patch = UserPatch::parse_from_json(Some(json!({ "userName": "lisun", "nickName": null })))
+        .map_err(|err| err.into_message())
+        .unwrap();
+    patch.apply(&mut user);
+    assert_eq!(
+        user,
+        User {
+            id: 1,
+            user_name: "lisun".to_string(),
+            nick_name: None,
+            age: Some(18),
+        }
+    );
+
+    UserPatch::parse_from_json(Some(json!({ "age": 20 })))
+        .map_err(|err| err.into_message())
+        .unwrap()
+        .apply(&mut user);
+    assert_eq!(user.age, Some(20));
+    assert_eq!(user.user_name, "lisun");
+
+    UserPatch::default().apply(&mut user);
+    assert_eq!(user.age, Some(20));
+
+    assert_eq!(
+        UserPatch::parse_from_json(Some(json!({ "userName": null })))
+            .err()
+            .unwrap()
+            .into_message(),
+        "failed to parse \"UserPatch\": properties `userName` can not be null."
+    );
+    assert_eq!(
+        UserPatch::parse_from_json(Some(json!({ "userName": "sunli1234" })))
+            .err()
+            .unwrap()
+            .into_message(),
+        "failed to parse \"UserPatch\": field `userName` verification failed. maxLength(8)"
+    );
+    assert!(UserPatch::parse_from_json(Some(json!({ "age": 300 }))).is_err());
+}
+
+#[test]
+fn skip_serializing_and_deserializing() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    #[oai(deny_unknown_fields)]
+    struct Obj {
+        a: i32,
+        #[oai(skip_serializing)]
+        password: String,
+        #[oai(skip_deserializing)]
+        created_at: i64,
+        #[oai(skip_serializing, skip_deserializing)]
+        cache: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(
+        meta.properties
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>(),
+        vec!["a", "password", "created_at"]
+    );
+    let password = meta.properties[1].1.unwrap_inline();
+    assert!(password.write_only);
+    assert!(!password.read_only);
+    let created_at = meta.properties[2].1.unwrap_inline();
+    assert!(created_at.read_only);
+    assert!(!created_at.write_only);
+    assert_eq!(meta.required, vec!["a", "password", "created_at"]);
+
+    let obj = Obj::parse_from_json(Some(json!({
+        "a": 1,
+        "password": "123456",
+        "created_at": 100,
+    })))
+    .unwrap();
+    assert_eq!(
+        obj,
+        Obj {
+            a: 1,
+            password: "123456".to_string(),
+            created_at: 0,
+            cache: 0,
+        }
+    );
+
+    let obj = Obj {
+        a: 1,
+        password: "123456".to_string(),
+        created_at: 100,
+        cache: 5,
+    };
+    assert_eq!(obj.to_json(), Some(json!({ "a": 1, "created_at": 100 })));
+}
+
+#[test]
+fn serde_compat_skip_directions() {
+    #[derive(Object, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+    #[oai(serde_compat)]
+    struct Obj {
+        a: i32,
+        #[serde(skip_serializing)]
+        b: i32,
+        #[serde(skip_deserializing)]
+        c: i32,
+    }
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({ "a": 1, "b": 2, "c": 3 }))).unwrap(),
+        Obj { a: 1, b: 2, c: 0 }
+    );
+    assert_eq!(
+        Obj { a: 1, b: 2, c: 3 }.to_json(),
+        Some(json!({ "a": 1, "c": 3 }))
+    );
+}
+
+#[test]
+fn tuple_struct() {
+    /// A coordinate
+    #[derive(Object, Debug, PartialEq)]
+    struct Coord(f64, f64);
+
+    let meta = get_meta::<Coord>();
+    assert_eq!(meta.ty, "array");
+    assert_eq!(meta.description, Some("A coordinate"));
+    assert_eq!(meta.items, Some(Box::new(f64::schema_ref())));
+    assert_eq!(meta.min_items, Some(2));
+    assert_eq!(meta.max_items, Some(2));
+
+    assert_eq!(
+        Coord::parse_from_json(Some(json!([1.0, 2.0]))).unwrap(),
+        Coord(1.0, 2.0)
+    );
+    assert_eq!(Coord(1.0, 2.0).to_json(), Some(json!([1.0, 2.0])));
+
+    assert!(Coord::parse_from_json(Some(json!([1.0]))).is_err());
+    assert_eq!(
+        Coord::parse_from_json(Some(json!([1.0, "a"])))
+            .unwrap_err()
+            .into_message(),
+        r#"Expected input type "number(double)", found "a". (occurred while parsing "Coord")"#
+    );
+
+    #[derive(Object, Debug, PartialEq)]
+    struct Pair(i32, String);
+
+    let meta = get_meta::<Pair>();
+    assert_eq!(meta.min_items, Some(2));
+    assert_eq!(meta.max_items, Some(2));
+    match meta.items.as_deref() {
+        Some(MetaSchemaRef::Inline(schema)) => {
+            assert_eq!(schema.one_of, vec![i32::schema_ref(), String::schema_ref()]);
+        }
+        _ => panic!("expected inline items"),
+    }
+}
+
+#[test]
+fn coerce() {
+    #[derive(Object, Debug, PartialEq)]
+    struct Obj {
+        #[oai(coerce)]
+        a: i32,
+        #[oai(coerce)]
+        b: f64,
+        #[oai(coerce)]
+        c: bool,
+        #[oai(coerce)]
+        d: String,
+        #[oai(coerce, default)]
+        e: Option<u8>,
+        f: i32,
+    }
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({
+            "a": "123",
+            "b": "1.5",
+            "c": "true",
+            "d": "456",
+            "e": "7",
+            "f": 8,
+        })))
+        .unwrap(),
+        Obj {
+            a: 123,
+            b: 1.5,
+            c: true,
+            d: "456".to_string(),
+            e: Some(7),
+            f: 8,
+        }
+    );
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({
+            "a": 1,
+            "b": 2.0,
+            "c": false,
+            "d": "x",
+            "f": 3,
+        })))
+        .unwrap(),
+        Obj {
+            a: 1,
+            b: 2.0,
+            c: false,
+            d: "x".to_string(),
+            e: None,
+            f: 3,
+        }
+    );
+
+    assert!(Obj::parse_from_json(Some(json!({
+        "a": "abc",
+        "b": 1.0,
+        "c": true,
+        "d": "x",
+        "f": 1,
+    })))
+    .is_err());
+
+    assert!(Obj::parse_from_json(Some(json!({
+        "a": 1,
+        "b": 1.0,
+        "c": true,
+        "d": "x",
+        "f": "1",
+    })))
+    .is_err());
+}
+
+#[test]
+fn extensions() {
+    #[derive(Object)]
+    #[oai(
+        extension(name = "x-internal", value = "true"),
+        extension(name = "x-tags", value = r#"["a", "b"]"#)
+    )]
+    struct Obj {
+        a: i32,
+    }
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(
+        meta.extensions,
+        vec![("x-internal", json!(true)), ("x-tags", json!(["a", "b"]))]
+    );
+
+    let value = serde_json::to_value(&meta).unwrap();
+    assert_eq!(value["x-internal"], json!(true));
+    assert_eq!(value["x-tags"], json!(["a", "b"]));
+}
+
+#[cfg(feature = "preserve-order")]
+#[test]
+fn preserve_order() {
+    #[derive(Object)]
+    struct Inner {
+        y: i32,
+        x: i32,
+    }
+
+    #[derive(Object)]
+    struct Obj {
+        c: i32,
+        a: i32,
+        #[oai(flatten)]
+        inner: Inner,
+        b: i32,
+    }
+
+    let value = Obj {
+        c: 1,
+        a: 2,
+        inner: Inner { y: 3, x: 4 },
+        b: 5,
+    }
+    .to_json()
+    .unwrap();
+    assert_eq!(
+        value.as_object().unwrap().keys().collect::<Vec<_>>(),
+        vec!["c", "a", "y", "x", "b"]
+    );
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"c":1,"a":2,"y":3,"x":4,"b":5}"#
+    );
+}
+
+#[test]
+fn to_json_with_fields() {
+    #[derive(Object)]
+    struct Tag {
+        id: i32,
+        name: String,
+    }
+
+    #[derive(Object)]
+    struct Owner {
+        name: String,
+        email: String,
+        tags: Vec<Tag>,
+    }
+
+    #[derive(Object)]
+    struct Pet {
+        id: i32,
+        name: String,
+        owner: Owner,
+    }
+
+    let pet = Pet {
+        id: 1,
+        name: "dog".to_string(),
+        owner: Owner {
+            name: "sunli".to_string(),
+            email: "sunli@example.com".to_string(),
+            tags: vec![
+                Tag {
+                    id: 1,
+                    name: "a".to_string(),
+                },
+                Tag {
+                    id: 2,
+                    name: "b".to_string(),
+                },
+            ],
+        },
+    };
+
+    assert_eq!(
+        pet.to_json_with_fields([
+            "id",
+            "owner.name",
+            "owner.tags.name",
+            "unknown",
+            "owner.x.y"
+        ]),
+        Some(json!({
+            "id": 1,
+            "owner": {
+                "name": "sunli",
+                "tags": [{ "name": "a" }, { "name": "b" }],
+            },
+        }))
+    );
+
+    // selecting a field selects all of its nested fields
+    assert_eq!(
+        pet.to_json_with_fields(["owner.email", "owner", "name"]),
+        Some(json!({
+            "name": "dog",
+            "owner": {
+                "name": "sunli",
+                "email": "sunli@example.com",
+                "tags": [{ "id": 1, "name": "a" }, { "id": 2, "name": "b" }],
+            },
+        }))
+    );
+
+    assert_eq!(pet.to_json_with_fields([]), pet.to_json());
+}
+
+#[test]
+fn required_if() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Payment {
+        #[oai(rename = "type")]
+        ty: String,
+        #[oai(required_if(field = "type", equals = "card"))]
+        card_number: Option<String>,
+        amount: i32,
+        #[oai(required_if(field = "amount", equals = "0"))]
+        reason: Option<String>,
+    }
+
+    assert_eq!(
+        Payment::parse_from_json(Some(json!({
+            "type": "card",
+            "card_number": "1234",
+            "amount": 100,
+        })))
+        .unwrap(),
+        Payment {
+            ty: "card".to_string(),
+            card_number: Some("1234".to_string()),
+            amount: 100,
+            reason: None,
+        }
+    );
+
+    assert_eq!(
+        Payment::parse_from_json(Some(json!({
+            "type": "cash",
+            "amount": 100,
+        })))
+        .unwrap(),
+        Payment {
+            ty: "cash".to_string(),
+            card_number: None,
+            amount: 100,
+            reason: None,
+        }
+    );
+
+    assert_eq!(
+        Payment::parse_from_json(Some(json!({
+            "type": "card",
+            "amount": 100,
+        })))
+        .unwrap_err()
+        .into_message(),
+        "failed to parse \"Payment\": properties `card_number` is required when `type` is `card`."
+    );
+
+    assert_eq!(
+        Payment::parse_from_json(Some(json!({
+            "type": "cash",
+            "amount": 0,
+        })))
+        .unwrap_err()
+        .into_message(),
+        "failed to parse \"Payment\": properties `reason` is required when `amount` is `0`."
+    );
+
+    let meta = get_meta::<Payment>();
+    assert_eq!(meta.required, vec!["type", "amount"]);
+    assert_eq!(
+        meta.properties[1].1.unwrap_inline().extensions,
+        vec![(
+            "x-required-if",
+            json!({ "field": "type", "equals": "card" })
+        )]
+    );
+}
+
+#[test]
+fn option_field_default() {
+    fn default_limit() -> i32 {
+        10
+    }
+
+    fn default_offset() -> Option<i32> {
+        Some(0)
+    }
+
+    fn default_cursor() -> Option<String> {
+        None
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Obj {
+        #[oai(default = "default_limit")]
+        limit: Option<i32>,
+        #[oai(default = "default_offset")]
+        offset: Option<i32>,
+        #[oai(default = "default_cursor")]
+        cursor: Option<String>,
+        #[oai(default)]
+        filter: Option<String>,
+        sort: Option<String>,
+    }
+
+    // an absent key uses the default function, or is `None`
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({}))).unwrap(),
+        Obj {
+            limit: Some(10),
+            offset: Some(0),
+            cursor: None,
+            filter: None,
+            sort: None,
+        }
+    );
+
+    assert_eq!(
+        Obj::parse_from_json(Some(json!({
+            "limit": 5,
+            "offset": 20,
+            "cursor": "abc",
+            "filter": "x",
+            "sort": "y",
+        })))
+        .unwrap(),
+        Obj {
+            limit: Some(5),
+            offset: Some(20),
+            cursor: Some("abc".to_string()),
+            filter: Some("x".to_string()),
+            sort: Some("y".to_string()),
+        }
+    );
+
+    let meta = get_meta::<Obj>();
+    assert_eq!(
+        meta.properties[0].1.unwrap_inline().default,
+        Some(json!(10))
+    );
+    assert_eq!(meta.properties[1].1.unwrap_inline().default, Some(json!(0)));
+}
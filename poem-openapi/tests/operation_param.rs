@@ -108,6 +108,49 @@ async fn query_multiple_values() {
     assert_eq!(resp.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn query_explode() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/a", method = "get")]
+        async fn a(&self, v: Query<Vec<u32>>) {
+            assert_eq!(v.0, vec![10, 20, 30]);
+        }
+
+        #[oai(path = "/b", method = "get")]
+        async fn b(&self, #[oai(explode = false)] v: Query<Vec<u32>>) {
+            assert_eq!(v.0, vec![10, 20, 30]);
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    assert!(meta.paths[0].operations[0].params[0].explode);
+    assert!(!meta.paths[1].operations[0].params[0].explode);
+
+    let api = OpenApiService::new(Api, "test", "1.0").into_endpoint();
+    let resp = api
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/a?v=10&v=20&v=30"))
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = api
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/b?v=10,20,30"))
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
 #[tokio::test]
 async fn query_default() {
     struct Api;
@@ -223,6 +266,61 @@ async fn path() {
     assert_eq!(resp.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn path_and_query_with_same_name() {
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/item/:id", method = "get")]
+        async fn test(
+            &self,
+            #[oai(name = "id")] path_id: Path<i32>,
+            #[oai(name = "id")] query_id: Query<i32>,
+        ) {
+            assert_eq!(path_id.0, 10);
+            assert_eq!(query_id.0, 20);
+        }
+    }
+
+    let meta: MetaApi = Api::meta().remove(0);
+    let params = &meta.paths[0].operations[0].params;
+    assert_eq!(
+        params
+            .iter()
+            .map(|param| (param.name, param.in_type))
+            .collect::<Vec<_>>(),
+        vec![("id", MetaParamIn::Path), ("id", MetaParamIn::Query)]
+    );
+
+    let api = OpenApiService::new(Api, "test", "1.0").into_endpoint();
+    let resp = api
+        .call(
+            Request::builder()
+                .uri(Uri::from_static("/item/10?id=20"))
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[test]
+#[should_panic(expected = "duplicate parameter `id` in Query")]
+fn duplicate_param() {
+    type Id = Query<i32>;
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/item", method = "get")]
+        async fn test(&self, #[oai(name = "id")] _a: Query<i32>, #[oai(name = "id")] _b: Id) {}
+    }
+
+    Api::meta();
+}
+
 #[tokio::test]
 async fn cookie() {
     struct Api;
@@ -396,3 +494,66 @@ async fn required_params() {
     assert_eq!(meta.paths[0].operations[0].params[1].name, "b");
     assert_eq!(meta.paths[0].operations[0].params[1].required, true);
 }
+
+#[tokio::test]
+async fn query_object() {
+    use poem::handler;
+    use poem_openapi::{param::QueryObject, Object};
+
+    #[derive(Object)]
+    struct Search {
+        #[oai(validator(max_length = 5))]
+        q: String,
+        #[oai(default)]
+        tags: Vec<String>,
+        page: Option<u32>,
+    }
+
+    #[handler]
+    fn search(QueryObject(search): QueryObject<Search>) -> String {
+        format!("{} {} {:?}", search.q, search.tags.join(","), search.page)
+    }
+
+    let call = |uri: &'static str| {
+        search.get_response(
+            Request::builder()
+                .method(Method::GET)
+                .uri(Uri::from_static(uri))
+                .finish(),
+        )
+    };
+
+    let resp = call("/?q=poem&tags=a&page=2&tags=b").await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.into_body().into_string().await.unwrap(),
+        "poem a,b Some(2)"
+    );
+
+    let resp = call("/?q=a&q=b").await;
+    assert_eq!(resp.into_body().into_string().await.unwrap(), "b  None");
+
+    let resp = call("/?tags=a").await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&resp.into_body().into_string().await.unwrap())
+            .unwrap(),
+        json!({ "errors": [{ "field": "/q", "code": "expected_type" }] })
+    );
+
+    let resp = call("/?q=poem&page=abc").await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&resp.into_body().into_string().await.unwrap())
+            .unwrap(),
+        json!({ "errors": [{ "field": "/page", "code": "expected_type" }] })
+    );
+
+    let resp = call("/?q=poem-openapi").await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&resp.into_body().into_string().await.unwrap())
+            .unwrap(),
+        json!({ "errors": [{ "field": "/q", "code": "max_length" }] })
+    );
+}
@@ -1,12 +1,15 @@
+use futures_util::{stream, Stream};
 use poem::{
-    http::{StatusCode, Uri},
-    Endpoint, Error, IntoEndpoint, Request,
+    http::{Method, StatusCode, Uri},
+    Endpoint, Error, IntoEndpoint, IntoResponse, Request,
 };
 use poem_openapi::{
+    error::{JsonErrorPosition, ParseJsonError},
     param::Query,
-    payload::{Json, Response},
-    ApiResponse, OpenApi, OpenApiService,
+    payload::{Binary, Json, JsonStream, Response},
+    ApiResponse, Object, OpenApi, OpenApiService,
 };
+use serde_json::json;
 
 #[tokio::test]
 async fn response_wrapper() {
@@ -66,3 +69,162 @@ async fn response_wrapper() {
     assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
     assert_eq!(resp.header("MY-HEADER1"), Some("def"));
 }
+
+#[tokio::test]
+async fn json_stream() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct Item {
+        id: i32,
+    }
+
+    fn items(n: i32) -> JsonStream<impl Stream<Item = Result<Item, std::io::Error>> + Send> {
+        JsonStream::new(stream::iter((0..n).map(|id| Ok(Item { id }))))
+    }
+
+    let resp = items(3).into_response();
+    assert_eq!(resp.header("content-type"), Some("application/json"));
+    let data = resp.into_body().into_vec().await.unwrap();
+    assert_eq!(
+        serde_json::from_slice::<Vec<serde_json::Value>>(&data).unwrap(),
+        vec![json!({ "id": 0 }), json!({ "id": 1 }), json!({ "id": 2 })]
+    );
+
+    let data = items(0)
+        .into_response()
+        .into_body()
+        .into_vec()
+        .await
+        .unwrap();
+    assert_eq!(data, b"[]");
+
+    let failing = JsonStream::new(stream::iter(vec![
+        Ok(Item { id: 1 }),
+        Err(std::io::Error::new(std::io::ErrorKind::Other, "broken")),
+        Ok(Item { id: 2 }),
+    ]));
+    assert!(failing
+        .into_response()
+        .into_body()
+        .into_vec()
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn binary_echo() {
+    #[derive(ApiResponse)]
+    enum ImageResponse {
+        #[oai(status = 200, content_type = "image/png")]
+        Ok(Binary<Vec<u8>>),
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/echo", method = "post")]
+        async fn echo(&self, data: Binary<Vec<u8>>) -> Binary<Vec<u8>> {
+            data
+        }
+
+        #[oai(path = "/image", method = "post")]
+        async fn image(&self, data: Binary<Vec<u8>>) -> ImageResponse {
+            ImageResponse::Ok(data)
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0");
+    let spec: serde_json::Value = serde_json::from_str(&service.spec()).unwrap();
+    let binary_schema = json!({ "type": "string", "format": "binary" });
+    assert_eq!(
+        spec["paths"]["/echo"]["post"]["requestBody"]["content"]["application/octet-stream"]
+            ["schema"],
+        binary_schema
+    );
+    assert_eq!(
+        spec["paths"]["/echo"]["post"]["responses"]["200"]["content"]["application/octet-stream"]
+            ["schema"],
+        binary_schema
+    );
+    assert_eq!(
+        spec["paths"]["/image"]["post"]["responses"]["200"]["content"]["image/png"]["schema"],
+        binary_schema
+    );
+
+    let ep = service.into_endpoint();
+    let data = vec![0x89, b'P', b'N', b'G', 0x00, 0xff];
+
+    let resp = ep
+        .call(
+            Request::builder()
+                .method(Method::POST)
+                .uri(Uri::from_static("/echo"))
+                .content_type("application/octet-stream")
+                .body(data.clone()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.content_type(), Some("application/octet-stream"));
+    assert_eq!(resp.into_body().into_vec().await.unwrap(), data);
+
+    let resp = ep
+        .call(
+            Request::builder()
+                .method(Method::POST)
+                .uri(Uri::from_static("/image"))
+                .content_type("application/octet-stream")
+                .body(data.clone()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.content_type(), Some("image/png"));
+    assert_eq!(resp.into_body().into_vec().await.unwrap(), data);
+}
+
+#[tokio::test]
+async fn json_syntax_error_position() {
+    #[derive(Object)]
+    struct CreateUser {
+        name: String,
+        age: i32,
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/user", method = "post")]
+        async fn create(&self, user: Json<CreateUser>) -> Json<String> {
+            Json(format!("{} {}", user.0.name, user.0.age))
+        }
+    }
+
+    let ep = OpenApiService::new(Api, "test", "1.0").into_endpoint();
+    let err = ep
+        .call(
+            Request::builder()
+                .method(Method::POST)
+                .uri(Uri::from_static("/user"))
+                .content_type("application/json")
+                .body("{\n  \"name\": \"sunli\",\n  \"age\" 18\n}"),
+        )
+        .await
+        .unwrap_err();
+
+    let parse_err = err.downcast_ref::<ParseJsonError>().unwrap();
+    assert_eq!(
+        parse_err.position,
+        Some(JsonErrorPosition {
+            line: 3,
+            column: 9,
+            offset: 29,
+        })
+    );
+
+    let resp = err.as_response();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        resp.into_body().into_string().await.unwrap(),
+        "parse JSON error: expected `:` at line 3 column 9 (byte offset 29)"
+    );
+}
@@ -0,0 +1,92 @@
+use poem_openapi::registry::{MetaSchema, MetaSchemaRef};
+use serde_json::json;
+
+#[test]
+fn merge_overrides() {
+    let schema = MetaSchema {
+        description: Some("original"),
+        default: Some(json!(1)),
+        maximum: Some(100.0),
+        min_length: Some(1),
+        ..MetaSchema::new("integer")
+    };
+
+    let merged = schema.clone().merge(MetaSchema {
+        description: Some("patched"),
+        default: Some(json!(2)),
+        maximum: Some(10.0),
+        ..MetaSchema::ANY
+    });
+    assert_eq!(merged.ty, "integer");
+    assert_eq!(merged.description, Some("patched"));
+    assert_eq!(merged.default, Some(json!(2)));
+    assert_eq!(merged.maximum, Some(10.0));
+    assert_eq!(merged.min_length, Some(1));
+
+    // unset fields in the patch keep the original values
+    assert_eq!(schema.clone().merge(MetaSchema::ANY), schema);
+}
+
+#[test]
+fn merge_enum_items() {
+    let schema = MetaSchema {
+        enum_items: vec![json!("a"), json!("b")],
+        ..MetaSchema::new("string")
+    };
+
+    let merged = schema.clone().merge(MetaSchema::ANY);
+    assert_eq!(merged.enum_items, vec![json!("a"), json!("b")]);
+
+    let merged = schema.clone().merge(MetaSchema {
+        enum_items: vec![json!("b")],
+        ..MetaSchema::ANY
+    });
+    assert_eq!(merged.enum_items, vec![json!("b")]);
+
+    // the values are replaced, not intersected
+    let merged = schema.merge(MetaSchema {
+        enum_items: vec![json!("c")],
+        ..MetaSchema::ANY
+    });
+    assert_eq!(merged.enum_items, vec![json!("c")]);
+}
+
+#[test]
+fn merge_flags() {
+    let schema = MetaSchema {
+        nullable: true,
+        ..MetaSchema::new("string")
+    };
+
+    let merged = schema.merge(MetaSchema {
+        deprecated: true,
+        ..MetaSchema::ANY
+    });
+    assert!(merged.nullable);
+    assert!(merged.deprecated);
+    assert!(!merged.read_only);
+}
+
+#[test]
+fn merge_reference() {
+    let schema_ref = MetaSchemaRef::Reference("User");
+    assert_eq!(
+        schema_ref.clone().merge(MetaSchema::ANY),
+        MetaSchemaRef::Reference("User")
+    );
+
+    let patch = MetaSchema {
+        description: Some("the owner"),
+        ..MetaSchema::ANY
+    };
+    assert_eq!(
+        schema_ref.merge(patch.clone()),
+        MetaSchemaRef::Inline(Box::new(MetaSchema {
+            all_of: vec![
+                MetaSchemaRef::Reference("User"),
+                MetaSchemaRef::Inline(Box::new(patch)),
+            ],
+            ..MetaSchema::ANY
+        }))
+    );
+}
@@ -30,10 +30,12 @@ fn meta() {
                 MetaMediaType {
                     content_type: "application/json",
                     schema: MetaSchemaRef::Reference("CreateUser"),
+                    examples: vec![],
                 },
                 MetaMediaType {
                     content_type: "text/plain",
                     schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+                    examples: vec![],
                 }
             ],
             required: true
@@ -93,6 +95,7 @@ async fn generic() {
             content: vec![MetaMediaType {
                 content_type: "application/json",
                 schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+                examples: vec![],
             },],
             required: true
         }
@@ -124,6 +127,7 @@ async fn item_content_type() {
                 schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format(
                     "integer", "int32"
                 ))),
+                examples: vec![],
             },],
             required: true
         }
@@ -140,3 +144,107 @@ async fn item_content_type() {
         Req::Create(Json(100))
     );
 }
+
+#[tokio::test]
+async fn json_or_form() {
+    use poem::{http::StatusCode, Endpoint, IntoEndpoint};
+    use poem_openapi::{payload::Form, OpenApi, OpenApiService};
+
+    #[derive(Debug, Object, Eq, PartialEq)]
+    struct Pet {
+        name: String,
+        #[oai(validator(maximum(value = "30")))]
+        age: u8,
+        tags: Vec<String>,
+    }
+
+    #[derive(ApiRequest)]
+    enum CreatePet {
+        Json(Json<Pet>),
+        Form(Form<Pet>),
+    }
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/pet", method = "post")]
+        async fn create(&self, req: CreatePet) -> PlainText<String> {
+            let (kind, pet) = match req {
+                CreatePet::Json(Json(pet)) => ("json", pet),
+                CreatePet::Form(Form(pet)) => ("form", pet),
+            };
+            PlainText(format!(
+                "{}: {} {} {}",
+                kind,
+                pet.name,
+                pet.age,
+                pet.tags.join(",")
+            ))
+        }
+    }
+
+    let service = OpenApiService::new(Api, "test", "1.0");
+    let spec: serde_json::Value = serde_json::from_str(&service.spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/pet"]["post"]["requestBody"]["content"],
+        serde_json::json!({
+            "application/json": { "schema": { "$ref": "#/components/schemas/Pet" } },
+            "application/x-www-form-urlencoded": { "schema": { "$ref": "#/components/schemas/Pet" } },
+        })
+    );
+
+    let ep = service.into_endpoint();
+    let call = |content_type: &'static str, body: &'static str| {
+        ep.call(
+            poem::Request::builder()
+                .method(poem::http::Method::POST)
+                .uri_str("/pet")
+                .content_type(content_type)
+                .body(body),
+        )
+    };
+
+    let resp = call(
+        "application/json",
+        r#"{ "name": "tom", "age": 3, "tags": ["a", "b"] }"#,
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        resp.into_body().into_string().await.unwrap(),
+        "json: tom 3 a,b"
+    );
+
+    let resp = call(
+        "application/x-www-form-urlencoded",
+        "name=tom&age=3&tags=a&tags=b",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        resp.into_body().into_string().await.unwrap(),
+        "form: tom 3 a,b"
+    );
+
+    let resp = call(
+        "application/x-www-form-urlencoded",
+        "name=tom&age=3&tags=a&name=jerry&age=4",
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        resp.into_body().into_string().await.unwrap(),
+        "form: jerry 4 a"
+    );
+
+    let err = call("application/x-www-form-urlencoded", "name=tom&age=31")
+        .await
+        .unwrap_err();
+    assert_eq!(err.as_response().status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let err = call("application/x-www-form-urlencoded", "name=tom&age=abc")
+        .await
+        .unwrap_err();
+    assert_eq!(err.as_response().status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
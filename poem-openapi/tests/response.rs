@@ -6,8 +6,10 @@ use poem::{
 };
 use poem_openapi::{
     payload::{Json, PlainText},
-    registry::{MetaMediaType, MetaResponse, MetaResponses, MetaSchema, MetaSchemaRef},
-    types::ToJSON,
+    registry::{
+        MetaExample, MetaMediaType, MetaResponse, MetaResponses, MetaSchema, MetaSchemaRef,
+    },
+    types::{MaybeUndefined, ToJSON},
     ApiResponse, Object,
 };
 use serde_json::Value;
@@ -49,7 +51,8 @@ fn meta() {
                     status: Some(400),
                     content: vec![MetaMediaType {
                         content_type: "application/json",
-                        schema: MetaSchemaRef::Reference("BadRequestResult")
+                        schema: MetaSchemaRef::Reference("BadRequestResult"),
+                        examples: vec![],
                     }],
                     headers: vec![]
                 },
@@ -59,6 +62,7 @@ fn meta() {
                     content: vec![MetaMediaType {
                         content_type: "text/plain",
                         schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+                        examples: vec![],
                     }],
                     headers: vec![]
                 }
@@ -208,6 +212,51 @@ async fn headers() {
     );
 }
 
+#[test]
+fn declared_headers() {
+    #[derive(ApiResponse)]
+    enum MyResponse {
+        #[oai(
+            status = 201,
+            header(name = "Location", ty = "String", description = "The created resource"),
+            header(name = "X-RateLimit-Remaining", ty = "Option<i32>", deprecated)
+        )]
+        Created(#[oai(header = "X-Request-Id")] String),
+    }
+
+    let meta: MetaResponses = MyResponse::meta();
+    let headers = &meta.responses[0].headers;
+    assert_eq!(
+        headers.iter().map(|header| header.name).collect::<Vec<_>>(),
+        vec!["X-REQUEST-ID", "LOCATION", "X-RATELIMIT-REMAINING"]
+    );
+
+    assert_eq!(headers[1].description, Some("The created resource"));
+    assert!(headers[1].required);
+    assert!(!headers[1].deprecated);
+    assert_eq!(
+        headers[1].schema,
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new("string")))
+    );
+
+    assert_eq!(headers[2].description, None);
+    assert!(!headers[2].required);
+    assert!(headers[2].deprecated);
+    assert_eq!(
+        headers[2].schema,
+        MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format("integer", "int32")))
+    );
+
+    // The declared headers are not set by the response itself.
+    let resp = MyResponse::Created("abc".to_string()).into_response();
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    assert_eq!(
+        resp.headers().get("X-Request-Id"),
+        Some(&HeaderValue::from_static("abc"))
+    );
+    assert!(!resp.headers().contains_key("Location"));
+}
+
 #[tokio::test]
 async fn bad_request_handler() {
     #[derive(ApiResponse, Debug, Eq, PartialEq)]
@@ -246,7 +295,8 @@ async fn generic() {
                 status: Some(200),
                 content: vec![MetaMediaType {
                     content_type: "application/json",
-                    schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string")))
+                    schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new("string"))),
+                    examples: vec![],
                 }],
                 headers: vec![]
             },],
@@ -282,7 +332,8 @@ async fn item_content_type() {
                         content_type: "application/json2",
                         schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format(
                             "integer", "int32"
-                        )))
+                        ))),
+                        examples: vec![],
                     }],
                     headers: vec![]
                 },
@@ -293,7 +344,8 @@ async fn item_content_type() {
                         content_type: "application/json3",
                         schema: MetaSchemaRef::Inline(Box::new(MetaSchema::new_with_format(
                             "integer", "int32"
-                        )))
+                        ))),
+                        examples: vec![],
                     }],
                     headers: vec![]
                 }
@@ -330,3 +382,182 @@ async fn header_deprecated() {
     let meta: MetaResponses = Resp::meta();
     assert_eq!(meta.responses[0].headers[0].deprecated, true);
 }
+
+#[test]
+fn examples() {
+    #[derive(ApiResponse)]
+    #[allow(dead_code)]
+    pub enum Resp {
+        #[oai(status = 200, example = "example_ok")]
+        Ok(Json<BadRequestResult>),
+        #[oai(
+            status = 400,
+            example(name = "minimal", value = "example_minimal"),
+            example(name = "full", value = "example_full", summary = "Full")
+        )]
+        BadRequest(Json<BadRequestResult>),
+        #[oai(status = 404, example = "example_undefined")]
+        NotFound(Json<MaybeUndefined<i32>>),
+    }
+
+    fn example_undefined() -> MaybeUndefined<i32> {
+        MaybeUndefined::Undefined
+    }
+
+    fn example_ok() -> BadRequestResult {
+        BadRequestResult {
+            error_code: 0,
+            message: "ok".to_string(),
+        }
+    }
+
+    fn example_minimal() -> BadRequestResult {
+        BadRequestResult {
+            error_code: 1,
+            message: String::new(),
+        }
+    }
+
+    fn example_full() -> BadRequestResult {
+        BadRequestResult {
+            error_code: 2,
+            message: "bad request".to_string(),
+        }
+    }
+
+    let meta: MetaResponses = Resp::meta();
+    assert_eq!(
+        meta.responses[0].content[0].examples,
+        vec![MetaExample {
+            name: "default",
+            summary: None,
+            value: serde_json::json!({ "error_code": 0, "message": "ok" }),
+        }]
+    );
+    assert_eq!(
+        meta.responses[1].content[0].examples,
+        vec![
+            MetaExample {
+                name: "minimal",
+                summary: None,
+                value: serde_json::json!({ "error_code": 1, "message": "" }),
+            },
+            MetaExample {
+                name: "full",
+                summary: Some("Full"),
+                value: serde_json::json!({ "error_code": 2, "message": "bad request" }),
+            }
+        ]
+    );
+
+    // the examples that are not serialized are skipped
+    assert!(meta.responses[2].content[0].examples.is_empty());
+
+    assert_eq!(
+        serde_json::to_value(&meta.responses[1].content[0]).unwrap()["examples"],
+        serde_json::json!({
+            "minimal": { "value": { "error_code": 1, "message": "" } },
+            "full": {
+                "summary": "Full",
+                "value": { "error_code": 2, "message": "bad request" },
+            },
+        })
+    );
+}
+
+#[tokio::test]
+async fn same_status_one_of() {
+    #[derive(Object)]
+    struct Cat {
+        lives: i32,
+    }
+
+    #[derive(Object)]
+    struct Dog {
+        name: String,
+    }
+
+    #[derive(ApiResponse)]
+    #[allow(dead_code)]
+    enum Resp {
+        /// A cat
+        #[oai(status = 200)]
+        Cat(Json<Cat>),
+        /// A dog
+        #[oai(status = 200)]
+        Dog(Json<Dog>),
+        #[oai(status = 404)]
+        NotFound,
+    }
+
+    assert_eq!(
+        serde_json::to_value(Resp::meta()).unwrap(),
+        serde_json::json!({
+            "200": {
+                "description": "A cat\n\nA dog",
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "oneOf": [
+                                { "$ref": "#/components/schemas/Cat" },
+                                { "$ref": "#/components/schemas/Dog" },
+                            ]
+                        }
+                    }
+                }
+            },
+            "404": { "description": "" },
+        })
+    );
+
+    let mut resp = Resp::Dog(Json(Dog {
+        name: "rex".to_string(),
+    }))
+    .into_response();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.take_body().into_string().await.unwrap(),
+        r#"{"name":"rex"}"#
+    );
+}
+
+#[test]
+fn same_status_duplicate_examples() {
+    #[derive(Object)]
+    struct Cat {
+        lives: i32,
+    }
+
+    #[derive(Object)]
+    struct Dog {
+        name: String,
+    }
+
+    #[derive(ApiResponse)]
+    #[allow(dead_code)]
+    enum Resp {
+        #[oai(status = 200, example = "cat_example")]
+        Cat(Json<Cat>),
+        #[oai(status = 200, example = "dog_example")]
+        Dog(Json<Dog>),
+    }
+
+    fn cat_example() -> Cat {
+        Cat { lives: 9 }
+    }
+
+    fn dog_example() -> Dog {
+        Dog {
+            name: "rex".to_string(),
+        }
+    }
+
+    assert_eq!(
+        serde_json::to_value(Resp::meta()).unwrap()["200"]["content"]["application/json"]
+            ["examples"],
+        serde_json::json!({
+            "default": { "value": { "lives": 9 } },
+            "default_2": { "value": { "name": "rex" } },
+        })
+    );
+}
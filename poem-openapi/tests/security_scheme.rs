@@ -173,6 +173,80 @@ async fn bearer_auth() {
     assert_eq!(resp.take_body().into_string().await.unwrap(), "abcdef");
 }
 
+#[tokio::test]
+async fn bearer_auth_checker() {
+    struct User {
+        name: String,
+    }
+
+    async fn bearer_checker(_req: &poem::Request, bearer: Bearer) -> Option<User> {
+        (bearer.token == "abcdef").then(|| User {
+            name: "sunli".to_string(),
+        })
+    }
+
+    #[derive(SecurityScheme)]
+    #[oai(type = "bearer", checker = "bearer_checker")]
+    struct MySecurityScheme(User);
+
+    struct MyApi;
+
+    #[OpenApi]
+    impl MyApi {
+        #[oai(path = "/test", method = "get")]
+        async fn test(&self, auth: MySecurityScheme) -> PlainText<String> {
+            PlainText(auth.0.name)
+        }
+    }
+
+    let service = OpenApiService::new(MyApi, "test", "1.0");
+    let spec = serde_json::from_str::<serde_json::Value>(&service.spec()).unwrap();
+    assert_eq!(
+        spec["paths"]["/test"]["get"]["security"],
+        serde_json::json!([{ "MySecurityScheme": [] }])
+    );
+    assert_eq!(
+        spec["components"]["securitySchemes"]["MySecurityScheme"],
+        serde_json::json!({ "type": "http", "scheme": "bearer" })
+    );
+
+    let ep = service.into_endpoint();
+    let bearer =
+        |token: &str| typed_headers::Credentials::bearer(Token68::new(token).unwrap()).to_string();
+
+    let mut resp = ep
+        .call(
+            poem::Request::builder()
+                .uri(Uri::from_static("/test"))
+                .header(header::AUTHORIZATION, bearer("abcdef"))
+                .finish(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(resp.take_body().into_string().await.unwrap(), "sunli");
+
+    for req in [
+        poem::Request::builder()
+            .uri(Uri::from_static("/test"))
+            .finish(),
+        poem::Request::builder()
+            .uri(Uri::from_static("/test"))
+            .header(header::AUTHORIZATION, bearer("123456"))
+            .finish(),
+    ] {
+        let err = ep.call(req).await.unwrap_err();
+        let resp = err.as_response();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            resp.headers()
+                .get(header::WWW_AUTHENTICATE)
+                .and_then(|value| value.to_str().ok()),
+            Some("Bearer")
+        );
+    }
+}
+
 #[tokio::test]
 async fn api_key_auth() {
     #[derive(SecurityScheme)]
@@ -268,7 +268,6 @@ fn without_discriminator() {
     assert_eq!(
         MyObj::schema_ref(),
         MetaSchemaRef::Inline(Box::new(MetaSchema {
-            ty: "object",
             discriminator: None,
             any_of: vec![MetaSchemaRef::Reference("A"), bool::schema_ref()],
             ..MetaSchema::ANY
@@ -329,7 +328,6 @@ fn anyof() {
     assert_eq!(
         MyObj::schema_ref(),
         MetaSchemaRef::Inline(Box::new(MetaSchema {
-            ty: "object",
             discriminator: None,
             any_of: vec![MetaSchemaRef::Reference("A"), MetaSchemaRef::Reference("B")],
             ..MetaSchema::ANY
@@ -380,7 +378,6 @@ fn oneof() {
     assert_eq!(
         MyObj::schema_ref(),
         MetaSchemaRef::Inline(Box::new(MetaSchema {
-            ty: "object",
             discriminator: None,
             one_of: vec![MetaSchemaRef::Reference("A"), MetaSchemaRef::Reference("B")],
             ..MetaSchema::ANY
@@ -463,3 +460,154 @@ async fn no_inline() {
     MyObj::register(&mut registry);
     assert!(registry.schemas.contains_key("MyObj"));
 }
+
+#[test]
+fn discriminator_unknown_value() {
+    #[derive(Object, Debug, PartialEq)]
+    struct A {
+        v1: i32,
+    }
+
+    #[derive(Union, Debug, PartialEq)]
+    #[oai(discriminator_name = "type", one_of)]
+    enum MyObj {
+        A(A),
+    }
+
+    assert_eq!(
+        MyObj::parse_from_json(Some(json!({ "type": "C", "v1": 1 })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"object\": unknown value `C` of the discriminator `type`."
+    );
+    assert!(MyObj::parse_from_json(Some(json!({ "v1": 1 }))).is_err());
+}
+
+#[test]
+fn adjacently_tagged() {
+    #[derive(Object, Debug, PartialEq)]
+    struct A {
+        v1: i32,
+    }
+
+    #[derive(Union, Debug, PartialEq)]
+    #[oai(inline, discriminator_name = "type", content_name = "data", one_of)]
+    enum MyObj {
+        A(A),
+        #[oai(mapping = "flag")]
+        B(bool),
+        C(Option<String>),
+    }
+
+    let schema = MyObj::schema_ref();
+    let schema = schema.unwrap_inline();
+    assert_eq!(
+        schema.discriminator,
+        Some(MetaDiscriminatorObject {
+            property_name: "type",
+            mapping: vec![
+                ("A", "#/components/schemas/MyObjA".to_string()),
+                ("flag", "#/components/schemas/MyObjB".to_string()),
+                ("string", "#/components/schemas/MyObjC".to_string()),
+            ],
+        })
+    );
+    assert_eq!(
+        schema.one_of,
+        vec![
+            MetaSchemaRef::Reference("MyObjA"),
+            MetaSchemaRef::Reference("MyObjB"),
+            MetaSchemaRef::Reference("MyObjC"),
+        ]
+    );
+
+    let mut registry = Registry::new();
+    MyObj::register(&mut registry);
+    let mut variant = registry.schemas.remove("MyObjA").unwrap();
+    variant.rust_typename = None;
+    assert_eq!(
+        variant,
+        MetaSchema {
+            title: Some("A".to_string()),
+            required: vec!["type", "data"],
+            properties: vec![
+                (
+                    "type",
+                    String::schema_ref().merge(MetaSchema {
+                        example: Some("A".into()),
+                        ..MetaSchema::ANY
+                    })
+                ),
+                ("data", MetaSchemaRef::Reference("A")),
+            ],
+            ..MetaSchema::new("object")
+        }
+    );
+    assert_eq!(registry.schemas["MyObjB"].title.as_deref(), Some("flag"));
+    assert_eq!(registry.schemas["MyObjC"].required, vec!["type"]);
+
+    for (obj, value) in [
+        (
+            MyObj::A(A { v1: 1 }),
+            json!({ "type": "A", "data": { "v1": 1 } }),
+        ),
+        (MyObj::B(true), json!({ "type": "flag", "data": true })),
+        (
+            MyObj::C(Some("abc".to_string())),
+            json!({ "type": "string", "data": "abc" }),
+        ),
+        (MyObj::C(None), json!({ "type": "string", "data": null })),
+    ] {
+        assert_eq!(obj.to_json(), Some(value.clone()));
+        assert_eq!(MyObj::parse_from_json(Some(value)).unwrap(), obj);
+    }
+    assert_eq!(
+        MyObj::parse_from_json(Some(json!({ "type": "string" }))).unwrap(),
+        MyObj::C(None)
+    );
+
+    let err = MyObj::parse_from_json(Some(json!({ "type": "flag", "data": 1 }))).unwrap_err();
+    assert_eq!(err.field_error().field, "/data");
+    assert_eq!(
+        MyObj::parse_from_json(Some(json!({ "type": "D", "data": 1 })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"object\": unknown value `D` of the discriminator `type`."
+    );
+}
+
+#[test]
+fn field_with_primitive_variants() {
+    #[derive(Union, Debug, PartialEq)]
+    #[oai(one_of)]
+    enum StringOrInteger {
+        String(String),
+        Integer(i64),
+    }
+
+    #[derive(Object, Debug, PartialEq)]
+    struct Obj {
+        value: StringOrInteger,
+    }
+
+    let mut registry = Registry::new();
+    Obj::register(&mut registry);
+    assert_eq!(
+        registry.schemas["Obj"].properties[0].1,
+        MetaSchemaRef::Reference("StringOrInteger")
+    );
+    let schema = &registry.schemas["StringOrInteger"];
+    assert_eq!(schema.ty, "");
+    assert_eq!(schema.one_of, vec![String::schema_ref(), i64::schema_ref()]);
+
+    for (value, expected) in [
+        (json!("abc"), StringOrInteger::String("abc".to_string())),
+        (json!(100), StringOrInteger::Integer(100)),
+    ] {
+        let obj = Obj::parse_from_json(Some(json!({ "value": value }))).unwrap();
+        assert_eq!(obj, Obj { value: expected });
+        assert_eq!(obj.to_json(), Some(json!({ "value": value })));
+    }
+
+    assert!(Obj::parse_from_json(Some(json!({ "value": 1.5 }))).is_err());
+}
@@ -2,6 +2,7 @@ use std::{
     collections::HashMap,
     fmt::{self, Display, Formatter},
     ops::Range,
+    sync::atomic::{AtomicUsize, Ordering},
 };
 
 use poem::{
@@ -168,6 +169,62 @@ fn test_min_length() {
     assert_eq!(schema.min_length, Some(10));
 }
 
+#[test]
+fn test_length_counts_unicode_scalar_values() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct A {
+        #[oai(validator(min_length = "2", max_length = "3"))]
+        value: String,
+    }
+
+    // each emoji is 4 bytes but a single scalar value
+    assert!(A::parse_from_json(Some(json!({ "value": "😀😀😀" }))).is_ok());
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "value": "😀😀😀😀" })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `value` verification failed. maxLength(3)"
+    );
+    // `e` followed by a combining acute accent is two scalar values
+    assert!(A::parse_from_json(Some(json!({ "value": "e\u{301}" }))).is_ok());
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "value": "é" })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `value` verification failed. minLength(2)"
+    );
+}
+
+#[test]
+fn test_length_in_bytes() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct A {
+        #[oai(validator(min_length = "2", max_length = "4", length_in_bytes))]
+        value: String,
+    }
+
+    assert!(A::parse_from_json(Some(json!({ "value": "😀" }))).is_ok());
+    assert!(A::parse_from_json(Some(json!({ "value": "é" }))).is_ok());
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "value": "a😀" })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `value` verification failed. maxLength(4)"
+    );
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "value": "a" })))
+            .unwrap_err()
+            .into_message(),
+        "failed to parse \"A\": field `value` verification failed. minLength(2)"
+    );
+
+    let mut registry = Registry::new();
+    A::register(&mut registry);
+    let schema = registry.schemas["A"].properties[0].1.unwrap_inline();
+    assert_eq!(schema.max_length, None);
+    assert_eq!(schema.min_length, None);
+}
+
 #[test]
 fn test_pattern() {
     #[derive(Object, Debug, Eq, PartialEq)]
@@ -194,6 +251,105 @@ fn test_pattern() {
     assert_eq!(schema.pattern.as_deref(), Some(r#"\[.*\]"#));
 }
 
+#[test]
+fn test_email() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct A {
+        #[oai(validator(email))]
+        value: String,
+    }
+
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "value": "sunli@example.com" }))).unwrap(),
+        A {
+            value: "sunli@example.com".to_string()
+        }
+    );
+    for value in [
+        "sunli",
+        "sunli@example",
+        "sun li@example.com",
+        "@example.com",
+    ] {
+        assert_eq!(
+            A::parse_from_json(Some(json!({ "value": value })))
+                .unwrap_err()
+                .into_message(),
+            r#"failed to parse "A": field `value` verification failed. format("email")"#
+        );
+    }
+
+    let mut registry = Registry::new();
+    A::register(&mut registry);
+    let meta = registry.schemas.remove("A").unwrap();
+    assert_eq!(meta.properties[0].1.unwrap_inline().format, Some("email"));
+}
+
+#[test]
+fn test_uuid() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct A {
+        #[oai(validator(uuid))]
+        value: String,
+    }
+
+    assert!(A::parse_from_json(Some(
+        json!({ "value": "67e55044-10b1-426f-9247-bb680e5fe0c8" })
+    ))
+    .is_ok());
+    for value in [
+        "67e55044-10b1-426f-9247",
+        "67e5504410b1426f9247bb680e5fe0c8",
+        "67e55044-10b1-426f-9247-bb680e5fe0cz",
+    ] {
+        assert_eq!(
+            A::parse_from_json(Some(json!({ "value": value })))
+                .unwrap_err()
+                .into_message(),
+            r#"failed to parse "A": field `value` verification failed. format("uuid")"#
+        );
+    }
+
+    let mut schema = MetaSchema::new("string");
+    validation::Format::uuid().update_meta(&mut schema);
+    assert_eq!(schema.format, Some("uuid"));
+}
+
+#[test]
+fn test_formats() {
+    let cases: [(validation::Format, &[&str], &[&str]); 4] = [
+        (
+            validation::Format::uri(),
+            &["https://example.com/a?b=1", "urn:isbn:0451450523"],
+            &["example.com", "https://exa mple.com", "1http://a"],
+        ),
+        (
+            validation::Format::hostname(),
+            &["example.com", "a-b.example.com", "localhost"],
+            &["-a.com", "a..com", "exa_mple.com", ""],
+        ),
+        (
+            validation::Format::ipv4(),
+            &["127.0.0.1", "192.168.1.255"],
+            &["256.0.0.1", "::1", "1.2.3"],
+        ),
+        (
+            validation::Format::ipv6(),
+            &["::1", "2001:db8::8a2e:370:7334"],
+            &["127.0.0.1", "2001:db8:::1"],
+        ),
+    ];
+
+    for (validator, valid, invalid) in cases {
+        for value in valid {
+            assert!(validator.check(value), "{} {}", validator, value);
+        }
+        for value in invalid {
+            assert!(!validator.check(value), "{} {}", validator, value);
+        }
+    }
+}
+
 #[test]
 fn test_max_items() {
     #[derive(Object, Debug, Eq, PartialEq)]
@@ -588,7 +744,7 @@ fn test_max_properties() {
         })))
         .unwrap_err()
         .into_message(),
-        "failed to parse \"A\": field `values` verification failed. maxProperties(3)"
+        "failed to parse \"A\": field `values` verification failed. maxProperties(3), but got 4 properties"
     );
 
     let mut schema = MetaSchema::new("string");
@@ -632,7 +788,7 @@ fn test_min_properties() {
         })))
         .unwrap_err()
         .into_message(),
-        "failed to parse \"A\": field `values` verification failed. minProperties(2)"
+        "failed to parse \"A\": field `values` verification failed. minProperties(2), but got 1 property"
     );
 
     let mut schema = MetaSchema::new("string");
@@ -731,3 +887,121 @@ fn test_custom_validator() {
         "failed to parse \"A\": field `value` verification failed. MyIntValidator"
     );
 }
+
+#[test]
+fn test_custom_fn_validator() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    fn weekday(value: &String) -> Result<(), String> {
+        CALLS.fetch_add(1, Ordering::SeqCst);
+        match value.as_str() {
+            "saturday" | "sunday" => Err(format!("`{}` is not a weekday", value)),
+            _ => Ok(()),
+        }
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct A {
+        #[oai(validator(custom_fn = "weekday", max_length = 9))]
+        day: String,
+    }
+
+    assert_eq!(
+        A::parse_from_json(Some(json!({ "day": "monday" }))).unwrap(),
+        A {
+            day: "monday".to_string()
+        }
+    );
+
+    let err = A::parse_from_json(Some(json!({ "day": "sunday" }))).unwrap_err();
+    assert_eq!(err.pointer(), "/day");
+    assert_eq!(err.code(), "custom");
+    assert_eq!(
+        err.into_message(),
+        "failed to parse \"A\": field `day` verification failed. `sunday` is not a weekday"
+    );
+    // the function is called once for every value
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    let mut registry = Registry::new();
+    A::register(&mut registry);
+    let schema = registry.schemas["A"].properties[0].1.unwrap_inline();
+    assert_eq!(schema.max_length, Some(9));
+    assert_eq!(schema.description, None);
+}
+
+#[tokio::test]
+async fn structured_errors() {
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct C {
+        #[oai(validator(maximum(value = "10")))]
+        value: i32,
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct B {
+        c: C,
+    }
+
+    #[derive(Object, Debug, Eq, PartialEq)]
+    struct A {
+        b: B,
+    }
+
+    let err = A::parse_from_json(Some(json!({ "b": { "c": { "value": 11 } } }))).unwrap_err();
+    assert_eq!(err.pointer(), "/b/c/value");
+    assert_eq!(err.code(), "maximum");
+
+    let err = A::parse_from_json(Some(json!({ "b": { "c": { "value": "abc" } } }))).unwrap_err();
+    assert_eq!(err.pointer(), "/b/c/value");
+    assert_eq!(err.code(), "expected_type");
+
+    let err =
+        Vec::<C>::parse_from_json(Some(json!([{ "value": 1 }, { "value": 11 }]))).unwrap_err();
+    assert_eq!(err.pointer(), "/1/value");
+
+    let err =
+        HashMap::<String, C>::parse_from_json(Some(json!({ "a/b": { "value": 11 } }))).unwrap_err();
+    assert_eq!(err.pointer(), "/a~1b/value");
+
+    struct Api;
+
+    #[OpenApi]
+    impl Api {
+        #[oai(path = "/", method = "post")]
+        async fn test(&self, _a: poem_openapi::payload::Json<A>) {}
+    }
+
+    let api = OpenApiService::new(Api, "test", "1.0").into_endpoint();
+    let resp = api
+        .call(
+            Request::builder()
+                .method(poem::http::Method::POST)
+                .content_type("application/json")
+                .body(r#"{ "b": { "c": { "value": 11 } } }"#),
+        )
+        .await
+        .unwrap_err()
+        .as_response();
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(resp.content_type(), Some("application/json"));
+    assert_eq!(
+        resp.into_body()
+            .into_json::<serde_json::Value>()
+            .await
+            .unwrap(),
+        json!({ "errors": [{ "field": "/b/c/value", "code": "maximum" }] })
+    );
+
+    let resp = api
+        .call(
+            Request::builder()
+                .method(poem::http::Method::POST)
+                .content_type("application/json")
+                .body(r#"{ "b": "#),
+        )
+        .await
+        .unwrap_err()
+        .as_response();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
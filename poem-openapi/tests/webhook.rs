@@ -118,7 +118,8 @@ async fn parameters() {
                 in_type: MetaParamIn::Query,
                 description: None,
                 required: true,
-                deprecated: false
+                deprecated: false,
+                explode: true,
             },
             MetaOperationParam {
                 name: "b",
@@ -126,7 +127,8 @@ async fn parameters() {
                 in_type: MetaParamIn::Path,
                 description: None,
                 required: true,
-                deprecated: false
+                deprecated: false,
+                explode: true,
             }
         ]
     );
@@ -147,6 +149,7 @@ async fn request_body() {
             content: vec![MetaMediaType {
                 content_type: "application/json",
                 schema: i32::schema_ref(),
+                examples: vec![],
             }],
             required: true
         })
@@ -170,6 +173,7 @@ async fn response() {
                 content: vec![MetaMediaType {
                     content_type: "application/json",
                     schema: i32::schema_ref(),
+                    examples: vec![],
                 }],
                 headers: vec![]
             }]
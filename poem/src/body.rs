@@ -209,6 +209,31 @@ impl Body {
     }
 
     /// Consumes this body object to return a bytes stream.
+    ///
+    /// The chunks are yielded as they are received, without buffering the
+    /// whole body in memory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use futures_util::TryStreamExt;
+    /// use poem::{error::ReadBodyError, handler, Body, Endpoint, Request, Result};
+    ///
+    /// #[handler]
+    /// async fn index(body: Body) -> Result<String> {
+    ///     let size = body
+    ///         .into_bytes_stream()
+    ///         .try_fold(0, |size, chunk| async move { Ok(size + chunk.len()) })
+    ///         .await
+    ///         .map_err(ReadBodyError::Io)?;
+    ///     Ok(size.to_string())
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let resp = index.call(Request::builder().body("abcdef")).await.unwrap();
+    /// assert_eq!(resp.into_body().into_string().await.unwrap(), "6");
+    /// # });
+    /// ```
     pub fn into_bytes_stream(self) -> impl Stream<Item = Result<Bytes, IoError>> + Send + 'static {
         TryStreamExt::map_err(self.0, |err| IoError::new(ErrorKind::Other, err))
     }
@@ -292,4 +317,46 @@ mod tests {
         let body = Body::from_json("abc").unwrap();
         assert_eq!(body.into_json::<String>().await.unwrap(), "abc");
     }
+
+    #[tokio::test]
+    async fn stream_large_body() {
+        use crate::{handler, Endpoint, Request};
+
+        #[handler(internal)]
+        async fn index(body: Body, other: Result<Body>) -> Result<String> {
+            assert!(matches!(
+                other.unwrap_err().downcast_ref::<ReadBodyError>(),
+                Some(ReadBodyError::BodyHasBeenTaken)
+            ));
+
+            let mut chunks = 0;
+            let size = body
+                .into_bytes_stream()
+                .try_fold(0, |size, chunk| {
+                    chunks += 1;
+                    async move { Ok(size + chunk.len()) }
+                })
+                .await
+                .map_err(ReadBodyError::Io)?;
+            assert_eq!(chunks, 64);
+            Ok(size.to_string())
+        }
+
+        let body = Body::from_bytes_stream(futures_util::stream::iter(
+            (0..64).map(|_| Ok::<_, std::io::Error>(Bytes::from(vec![0u8; 64 * 1024]))),
+        ));
+        let resp = index.call(Request::builder().body(body)).await.unwrap();
+        assert_eq!(
+            resp.into_body().into_string().await.unwrap(),
+            (64 * 64 * 1024).to_string()
+        );
+
+        let body = Body::from_bytes_stream(futures_util::stream::iter(
+            (0..64).map(|_| Ok::<_, std::io::Error>(Bytes::from(vec![0u8; 64 * 1024]))),
+        ));
+        assert!(matches!(
+            body.into_bytes_limit(1024 * 1024).await,
+            Err(ReadBodyError::PayloadTooLarge)
+        ));
+    }
 }
@@ -2,7 +2,7 @@ use std::{future::Future, marker::PhantomData, sync::Arc};
 
 use super::{
     After, AndThen, Around, Before, CatchAllError, CatchError, InspectAllError, InspectError, Map,
-    MapToResponse, ToResponse,
+    MapResponse, MapToResponse, ToResponse,
 };
 use crate::{
     error::IntoResult,
@@ -86,9 +86,11 @@ where
     }
 }
 
-/// Combines two different endpoints for [`Endpoint::with_if`].
+/// Combines two different endpoints for [`EndpointExt::with_if`].
 pub enum EitherEndpoint<A, B> {
+    /// The endpoint without the middleware.
     A(A),
+    /// The endpoint transformed by the middleware.
     B(B),
 }
 
@@ -244,6 +246,9 @@ pub trait EndpointExt: IntoEndpoint {
 
     /// if `enable` is `true` then use middleware to transform this endpoint.
     ///
+    /// Both branches are unified with [`EitherEndpoint`], so the type of the
+    /// result does not depend on `enable` and no boxing is needed.
+    ///
     /// # Example
     ///
     /// ```
@@ -283,13 +288,13 @@ pub trait EndpointExt: IntoEndpoint {
     /// assert_eq!(resp.into_body().into_string().await.unwrap(), "none");
     /// # });
     /// ```
-    fn with_if<T>(self, enable: bool, middleware: T) -> EitherEndpoint<Self, T::Output>
+    fn with_if<T>(self, enable: bool, middleware: T) -> EitherEndpoint<Self::Endpoint, T::Output>
     where
         T: Middleware<Self::Endpoint>,
         Self: Sized,
     {
         if !enable {
-            EitherEndpoint::A(self)
+            EitherEndpoint::A(self.into_endpoint())
         } else {
             EitherEndpoint::B(middleware.transform(self.into_endpoint()))
         }
@@ -297,6 +302,10 @@ pub trait EndpointExt: IntoEndpoint {
 
     /// A helper function, similar to `with(AddData(T))`.
     ///
+    /// It can be applied to a subtree of routes to give it its own state. When
+    /// the same type is added more than once, [`Data`](crate::web::Data)
+    /// extracts the value added closest to the handler.
+    ///
     /// # Example
     ///
     /// ```
@@ -323,6 +332,10 @@ pub trait EndpointExt: IntoEndpoint {
 
     /// Maps the request of this endpoint.
     ///
+    /// The closure can inspect or modify the request before it is passed to
+    /// the inner endpoint. Returning an error skips the inner endpoint, and the
+    /// error is converted to the response.
+    ///
     /// # Example
     ///
     /// ```
@@ -525,6 +538,39 @@ pub trait EndpointExt: IntoEndpoint {
         Map::new(self.into_endpoint(), f)
     }
 
+    /// Maps the [`Response`] of this endpoint.
+    ///
+    /// The output is converted into a response before calling `f`. Errors are
+    /// returned unchanged, use [`EndpointExt::to_response`] first to map them
+    /// too.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poem::{
+    ///     endpoint::make, http::HeaderValue, Endpoint, EndpointExt, Request, Response,
+    /// };
+    ///
+    /// let ep = make(|_| async { "hello" }).map_response(|mut resp| async move {
+    ///     resp.headers_mut()
+    ///         .insert("server", HeaderValue::from_static("poem"));
+    ///     resp
+    /// });
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let resp: Response = ep.call(Request::default()).await.unwrap();
+    /// assert_eq!(resp.header("server"), Some("poem"));
+    /// # });
+    /// ```
+    fn map_response<F, Fut>(self, f: F) -> MapResponse<Self::Endpoint, F>
+    where
+        F: Fn(Response) -> Fut + Send + Sync,
+        Fut: Future<Output = Response> + Send,
+        Self: Sized,
+    {
+        MapResponse::new(self.into_endpoint(), f)
+    }
+
     /// Calls `f` if the result is `Ok`, otherwise returns the `Err` value of
     /// self.
     ///
@@ -762,6 +808,42 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_before_rewrite_header() {
+        let ep = make_sync(|req| req.header("x-lang").unwrap_or_default().to_string()).before(
+            |mut req| async move {
+                if req.header("x-token").is_none() {
+                    return Err(Error::from_status(StatusCode::UNAUTHORIZED));
+                }
+                if !req.headers().contains_key("x-lang") {
+                    req.headers_mut()
+                        .insert("x-lang", HeaderValue::from_static("en"));
+                }
+                Ok(req)
+            },
+        );
+
+        let resp = ep
+            .call(Request::builder().header("x-token", "abc").finish())
+            .await
+            .unwrap();
+        assert_eq!(resp, "en");
+
+        let resp = ep
+            .call(
+                Request::builder()
+                    .header("x-token", "abc")
+                    .header("x-lang", "fr")
+                    .finish(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp, "fr");
+
+        let err = ep.call(Request::default()).await.unwrap_err();
+        assert_eq!(err.as_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test]
     async fn test_after() {
         assert_eq!(
@@ -790,6 +872,39 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_map_response() {
+        let stamp = |mut resp: Response| async move {
+            resp.headers_mut()
+                .insert("server", HeaderValue::from_static("poem"));
+            resp
+        };
+
+        let resp = make_sync(|_| "abc")
+            .map_response(stamp)
+            .call(Request::default())
+            .await
+            .unwrap();
+        assert_eq!(resp.header("server"), Some("poem"));
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "abc");
+
+        let err = make_sync(|_| Err::<String, _>(Error::from_status(StatusCode::BAD_REQUEST)))
+            .map_response(stamp)
+            .call(Request::default())
+            .await
+            .unwrap_err();
+        assert_eq!(err.as_response().status(), StatusCode::BAD_REQUEST);
+
+        let resp = make_sync(|_| Err::<String, _>(Error::from_status(StatusCode::BAD_REQUEST)))
+            .to_response()
+            .map_response(stamp)
+            .call(Request::default())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.header("server"), Some("poem"));
+    }
+
     #[tokio::test]
     async fn test_and_then() {
         assert_eq!(
@@ -842,6 +957,64 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_around_set_response_header() {
+        let ep = make_sync(|_| "hello").around(|ep, req| async move {
+            let path = req.uri().path().to_string();
+            let mut resp = ep.call(req).await?.into_response();
+            resp.headers_mut()
+                .insert("x-path", HeaderValue::from_str(&path).unwrap());
+            Ok(resp)
+        });
+
+        let mut resp = ep
+            .call(Request::builder().uri(Uri::from_static("/abc")).finish())
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.headers().get("x-path"),
+            Some(&HeaderValue::from_static("/abc"))
+        );
+        assert_eq!(resp.take_body().into_string().await.unwrap(), "hello");
+    }
+
+    #[tokio::test]
+    async fn test_catch_error() {
+        #[derive(Debug, thiserror::Error)]
+        #[error("invalid input: {0}")]
+        struct InvalidInput(String);
+
+        let ep = make(|req| async move {
+            match req.uri().path() {
+                "/invalid" => Err::<(), _>(Error::new(
+                    InvalidInput("name".to_string()),
+                    StatusCode::BAD_REQUEST,
+                )),
+                _ => Err(Error::from_status(StatusCode::NOT_FOUND)),
+            }
+        })
+        .catch_error(|err: InvalidInput| async move {
+            err.to_string()
+                .with_status(StatusCode::UNPROCESSABLE_ENTITY)
+        });
+
+        let resp = ep
+            .call(Request::builder().uri_str("/invalid").finish())
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            resp.into_body().into_string().await.unwrap(),
+            "invalid input: name"
+        );
+
+        let err = ep
+            .call(Request::builder().uri_str("/other").finish())
+            .await
+            .unwrap_err();
+        assert_eq!(err.as_response().status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn test_with_if() {
         let resp = make_sync(|_| ())
@@ -862,6 +1035,51 @@ mod test {
         assert_eq!(resp.headers().get("a"), None);
     }
 
+    #[tokio::test]
+    async fn test_with_if_into_endpoint() {
+        struct MyEndpointFactory;
+
+        impl IntoEndpoint for MyEndpointFactory {
+            type Endpoint = Route;
+
+            fn into_endpoint(self) -> Self::Endpoint {
+                Route::new().at("/", get(make_sync(|_| "a")))
+            }
+        }
+
+        for enable in [true, false] {
+            let resp = MyEndpointFactory
+                .with_if(enable, SetHeader::new().appending("a", 1))
+                .call(Request::default())
+                .await
+                .unwrap();
+            assert_eq!(resp.headers().contains_key("a"), enable);
+            assert_eq!(resp.into_body().into_string().await.unwrap(), "a");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_data_nearest_scope() {
+        #[handler(internal)]
+        fn index(data: web::Data<&String>) -> String {
+            data.0.clone()
+        }
+
+        let app = Route::new()
+            .nest("/a", Route::new().at("/", get(index)).data("a".to_string()))
+            .nest("/b", Route::new().at("/", get(index)).data("b".to_string()))
+            .at("/c", get(index))
+            .data("root".to_string());
+
+        for (path, value) in [("/a", "a"), ("/b", "b"), ("/c", "root")] {
+            let resp = app
+                .call(Request::builder().uri(path.parse().unwrap()).finish())
+                .await
+                .unwrap();
+            assert_eq!(resp.into_body().into_string().await.unwrap(), value);
+        }
+    }
+
     #[tokio::test]
     async fn test_into_endpoint() {
         struct MyEndpointFactory;
@@ -0,0 +1,203 @@
+use std::{future::Future, time::Duration};
+
+use futures_util::future::{join_all, BoxFuture};
+use serde_json::{json, Map, Value};
+
+use crate::{http::StatusCode, web::Json, Endpoint, IntoResponse, Request, Response, Result};
+
+/// An endpoint for the liveness probe, it always returns `200 OK` with the
+/// body `{"status": "ok"}`.
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     endpoint::{Liveness, Readiness},
+///     Route,
+/// };
+///
+/// let app = Route::new()
+///     .at("/healthz", Liveness)
+///     .at("/readyz", Readiness::new().check("db", || async { Ok(()) }));
+/// ```
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Liveness;
+
+#[async_trait::async_trait]
+impl Endpoint for Liveness {
+    type Output = Response;
+
+    async fn call(&self, _req: Request) -> Result<Self::Output> {
+        Ok(Json(json!({ "status": "ok" })).into_response())
+    }
+}
+
+type CheckFn = Box<dyn Fn() -> BoxFuture<'static, Result<()>> + Send + Sync>;
+
+/// An endpoint for the readiness probe.
+///
+/// All registered checks are run concurrently, each of them is limited by the
+/// timeout. It returns `200 OK` if all checks succeed, otherwise
+/// `503 Service Unavailable`. The body lists the status of each check:
+///
+/// ```json
+/// {
+///     "status": "error",
+///     "checks": {
+///         "db": { "status": "ok" },
+///         "cache": { "status": "error", "error": "timeout" }
+///     }
+/// }
+/// ```
+pub struct Readiness {
+    checks: Vec<(String, CheckFn)>,
+    timeout: Duration,
+}
+
+impl Default for Readiness {
+    fn default() -> Self {
+        Self {
+            checks: Vec::new(),
+            timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+impl Readiness {
+    /// Create a `Readiness` endpoint.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Register a check with the specified name.
+    #[must_use]
+    pub fn check<F, Fut>(mut self, name: impl Into<String>, f: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.checks
+            .push((name.into(), Box::new(move || Box::pin(f()))));
+        self
+    }
+
+    /// Sets the timeout for each check, default is 5 seconds.
+    #[must_use]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+}
+
+#[async_trait::async_trait]
+impl Endpoint for Readiness {
+    type Output = Response;
+
+    async fn call(&self, _req: Request) -> Result<Self::Output> {
+        let results = join_all(self.checks.iter().map(|(_, check)| async move {
+            match tokio::time::timeout(self.timeout, check()).await {
+                Ok(Ok(())) => Ok(()),
+                Ok(Err(err)) => Err(err.to_string()),
+                Err(_) => Err("timeout".to_string()),
+            }
+        }))
+        .await;
+
+        let mut ready = true;
+        let mut checks = Map::new();
+        for ((name, _), res) in self.checks.iter().zip(results) {
+            let value = match res {
+                Ok(()) => json!({ "status": "ok" }),
+                Err(err) => {
+                    ready = false;
+                    json!({ "status": "error", "error": err })
+                }
+            };
+            checks.insert(name.clone(), value);
+        }
+
+        let (status, status_text) = if ready {
+            (StatusCode::OK, "ok")
+        } else {
+            (StatusCode::SERVICE_UNAVAILABLE, "error")
+        };
+        Ok(Json(json!({
+            "status": status_text,
+            "checks": Value::Object(checks),
+        }))
+        .with_status(status)
+        .into_response())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    async fn call(ep: &impl Endpoint<Output = Response>) -> (StatusCode, Value) {
+        let resp = ep.call(Request::default()).await.unwrap();
+        let status = resp.status();
+        (status, resp.into_body().into_json().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn liveness() {
+        assert_eq!(
+            call(&Liveness).await,
+            (StatusCode::OK, json!({ "status": "ok" }))
+        );
+    }
+
+    #[tokio::test]
+    async fn readiness_all_healthy() {
+        let ep = Readiness::new()
+            .check("db", || async { Ok(()) })
+            .check("cache", || async { Ok(()) });
+
+        assert_eq!(
+            call(&ep).await,
+            (
+                StatusCode::OK,
+                json!({
+                    "status": "ok",
+                    "checks": {
+                        "db": { "status": "ok" },
+                        "cache": { "status": "ok" },
+                    }
+                })
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn readiness_failing() {
+        let ep = Readiness::new()
+            .timeout(Duration::from_millis(50))
+            .check("db", || async { Ok(()) })
+            .check("cache", || async {
+                Err(Error::from_string(
+                    "connection refused",
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                ))
+            })
+            .check("queue", || async {
+                tokio::time::sleep(Duration::from_secs(10)).await;
+                Ok(())
+            });
+
+        assert_eq!(
+            call(&ep).await,
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                json!({
+                    "status": "error",
+                    "checks": {
+                        "db": { "status": "ok" },
+                        "cache": { "status": "error", "error": "connection refused" },
+                        "queue": { "status": "error", "error": "timeout" },
+                    }
+                })
+            )
+        );
+    }
+}
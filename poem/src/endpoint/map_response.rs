@@ -0,0 +1,31 @@
+use std::future::Future;
+
+use crate::{Endpoint, IntoResponse, Request, Response, Result};
+
+/// Endpoint for the [`map_response`](super::EndpointExt::map_response) method.
+pub struct MapResponse<E, F> {
+    inner: E,
+    f: F,
+}
+
+impl<E, F> MapResponse<E, F> {
+    #[inline]
+    pub(crate) fn new(inner: E, f: F) -> MapResponse<E, F> {
+        Self { inner, f }
+    }
+}
+
+#[async_trait::async_trait]
+impl<E, F, Fut> Endpoint for MapResponse<E, F>
+where
+    E: Endpoint,
+    F: Fn(Response) -> Fut + Send + Sync,
+    Fut: Future<Output = Response> + Send,
+{
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> Result<Self::Output> {
+        let resp = self.inner.call(req).await?.into_response();
+        Ok((self.f)(resp).await)
+    }
+}
@@ -8,9 +8,11 @@ mod catch_all_error;
 mod catch_error;
 #[allow(clippy::module_inception)]
 mod endpoint;
+mod health;
 mod inspect_all_err;
 mod inspect_err;
 mod map;
+mod map_response;
 mod map_to_response;
 #[cfg(feature = "prometheus")]
 mod prometheus_exporter;
@@ -27,9 +29,11 @@ pub use before::Before;
 pub use catch_all_error::CatchAllError;
 pub use catch_error::CatchError;
 pub use endpoint::{make, make_sync, BoxEndpoint, Endpoint, EndpointExt, IntoEndpoint};
+pub use health::{Liveness, Readiness};
 pub use inspect_all_err::InspectAllError;
 pub use inspect_err::InspectError;
 pub use map::Map;
+pub use map_response::MapResponse;
 pub use map_to_response::MapToResponse;
 #[cfg(feature = "prometheus")]
 pub use prometheus_exporter::PrometheusExporter;
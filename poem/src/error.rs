@@ -4,13 +4,15 @@ use std::{
     convert::Infallible,
     error::Error as StdError,
     fmt::{self, Debug, Display, Formatter},
+    io::ErrorKind,
     string::FromUtf8Error,
+    sync::Arc,
 };
 
-use headers::{ContentRange, HeaderMapExt};
+use headers::{ContentRange, ETag, HeaderMapExt};
 use http::Method;
 
-use crate::{http::StatusCode, IntoResponse, Response};
+use crate::{http::StatusCode, IntoResponse, Request, Response};
 
 macro_rules! define_http_error {
     ($($(#[$docs:meta])* ($name:ident, $status:ident);)*) => {
@@ -176,6 +178,44 @@ impl AsResponse {
 /// # });
 /// ```
 ///
+/// # Return the error type from handlers directly
+///
+/// Any type that implements [`ResponseError`] can be used as the error type of
+/// a handler, the `Ok` value is converted with its own
+/// [`IntoResponse`](crate::IntoResponse) implementation.
+///
+/// ```
+/// use poem::{error::ResponseError, handler, http::StatusCode, Endpoint, Request};
+///
+/// #[derive(Debug, thiserror::Error)]
+/// enum UserError {
+///     #[error("invalid user id")]
+///     InvalidId,
+///     #[error("user not found")]
+///     NotFound,
+/// }
+///
+/// impl ResponseError for UserError {
+///     fn status(&self) -> StatusCode {
+///         match self {
+///             UserError::InvalidId => StatusCode::BAD_REQUEST,
+///             UserError::NotFound => StatusCode::NOT_FOUND,
+///         }
+///     }
+/// }
+///
+/// #[handler]
+/// async fn index() -> Result<String, UserError> {
+///     Err(UserError::NotFound)
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = index.get_response(Request::default()).await;
+/// assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+/// assert_eq!(resp.into_body().into_string().await.unwrap(), "user not found");
+/// # });
+/// ```
+///
 /// # Downcast the error to concrete error type
 /// ```
 /// use poem::{error::NotFoundError, Error};
@@ -506,6 +546,76 @@ impl<T: IntoResponse> IntoResult<T> for T {
     }
 }
 
+type BoxRejectionFn = Arc<dyn Fn(Error, &Request) -> Response + Send + Sync + 'static>;
+
+/// A handler that formats the errors returned by the extractors of a
+/// [`handler`](crate::handler), such as a body that is not valid JSON or a
+/// missing path parameter.
+///
+/// It is usually configured once with
+/// [`Server::rejection_handler`](crate::Server::rejection_handler), or added to
+/// a route with [`EndpointExt::data`](crate::EndpointExt::data). Without it,
+/// the response of the error itself is used.
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     error::RejectionHandler,
+///     handler,
+///     http::StatusCode,
+///     web::{Json, Path},
+///     Endpoint, EndpointExt, IntoResponse, Request, Route,
+/// };
+/// use serde_json::json;
+///
+/// #[handler]
+/// fn index(Path(id): Path<u32>) -> String {
+///     id.to_string()
+/// }
+///
+/// let app = Route::new()
+///     .at("/:id", index)
+///     .data(RejectionHandler::new(|err, req| {
+///         let status = err.as_response().status();
+///         Json(json!({ "error": err.to_string(), "path": req.uri().path() }))
+///             .with_status(status)
+///             .into_response()
+///     }));
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = app
+///     .call(Request::builder().uri_str("/abc").finish())
+///     .await
+///     .unwrap();
+/// assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+/// # });
+/// ```
+#[derive(Clone)]
+pub struct RejectionHandler(BoxRejectionFn);
+
+impl RejectionHandler {
+    /// Create a rejection handler from a function that converts the error of
+    /// an extractor to the response.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(Error, &Request) -> Response + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    /// Converts the error of an extractor with the rejection handler in the
+    /// extensions of the request, the error is returned unchanged if there
+    /// is no rejection handler.
+    #[doc(hidden)]
+    pub fn reject(req: &Request, err: Error) -> Result<Response> {
+        match req.extensions().get::<RejectionHandler>() {
+            Some(handler) => Ok((handler.0)(err, req)),
+            None => Err(err),
+        }
+    }
+}
+
 macro_rules! define_simple_errors {
     ($($(#[$docs:meta])* ($name:ident, $status:ident, $err_msg:literal);)*) => {
         $(
@@ -535,8 +645,81 @@ define_simple_errors!(
 
     /// Error occurred in the `Cors` middleware.
     (CorsError, UNAUTHORIZED, "unauthorized");
+
+    /// None of the offered media types is acceptable to the client.
+    (NotAcceptableError, NOT_ACCEPTABLE, "not acceptable");
 );
 
+/// A possible error value when running a server.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum ServerError {
+    /// The address is already in use.
+    #[error("address in use: {0}")]
+    AddrInUse(std::io::Error),
+
+    /// The permission to bind the address is denied.
+    #[error("permission denied: {0}")]
+    PermissionDenied(std::io::Error),
+
+    /// Other errors when binding the listener.
+    #[error("bind: {0}")]
+    Bind(std::io::Error),
+
+    /// The TLS config of the listener is invalid.
+    #[error("tls: {0}")]
+    Tls(std::io::Error),
+
+    /// Other errors when serving the connections.
+    #[error("serve: {0}")]
+    Serve(std::io::Error),
+}
+
+impl ServerError {
+    pub(crate) fn bind(err: std::io::Error) -> Self {
+        #[cfg(any(feature = "rustls", feature = "native-tls"))]
+        if err
+            .get_ref()
+            .map(|err| err.is::<TlsConfigError>())
+            .unwrap_or_default()
+        {
+            return ServerError::Tls(err);
+        }
+
+        match err.kind() {
+            ErrorKind::AddrInUse => ServerError::AddrInUse(err),
+            ErrorKind::PermissionDenied => ServerError::PermissionDenied(err),
+            _ => ServerError::Bind(err),
+        }
+    }
+}
+
+impl From<ServerError> for std::io::Error {
+    fn from(err: ServerError) -> Self {
+        match err {
+            ServerError::AddrInUse(err)
+            | ServerError::PermissionDenied(err)
+            | ServerError::Bind(err)
+            | ServerError::Tls(err)
+            | ServerError::Serve(err) => err,
+        }
+    }
+}
+
+/// Marks the errors of loading the TLS config of a listener, so that they are
+/// reported as [`ServerError::Tls`].
+#[cfg(any(feature = "rustls", feature = "native-tls"))]
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub(crate) struct TlsConfigError(std::io::Error);
+
+#[cfg(any(feature = "rustls", feature = "native-tls"))]
+impl TlsConfigError {
+    pub(crate) fn wrap(err: std::io::Error) -> std::io::Error {
+        std::io::Error::new(err.kind(), TlsConfigError(err))
+    }
+}
+
 /// A possible error value when reading the body.
 #[derive(Debug, thiserror::Error)]
 pub enum ReadBodyError {
@@ -814,6 +997,79 @@ impl ResponseError for StaticFileError {
     }
 }
 
+/// A possible error value returned by
+/// [`Conditional::check`](crate::web::Conditional::check).
+#[derive(Debug, thiserror::Error, Clone, Eq, PartialEq)]
+pub enum ConditionalError {
+    /// The resource has not been modified, the response includes the current
+    /// `ETag`.
+    #[error("not modified")]
+    NotModified(ETag),
+
+    /// Precondition failed
+    #[error("precondition failed")]
+    PreconditionFailed,
+}
+
+impl ResponseError for ConditionalError {
+    fn status(&self) -> StatusCode {
+        match self {
+            ConditionalError::NotModified(_) => StatusCode::NOT_MODIFIED,
+            ConditionalError::PreconditionFailed => StatusCode::PRECONDITION_FAILED,
+        }
+    }
+
+    fn as_response(&self) -> Response {
+        match self {
+            ConditionalError::NotModified(etag) => {
+                let mut resp = self.status().into_response();
+                resp.headers_mut().typed_insert(etag.clone());
+                resp
+            }
+            ConditionalError::PreconditionFailed => Response::builder()
+                .status(self.status())
+                .body(self.to_string()),
+        }
+    }
+}
+
+/// A possible error value occurred in the `Decompression` middleware.
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "compression")))]
+#[derive(Debug, thiserror::Error)]
+pub enum DecompressionError {
+    /// The `Content-Encoding` of the request is not supported.
+    #[error("unsupported content encoding: {0}")]
+    UnsupportedEncoding(String),
+
+    /// The decompressed body is larger than the limit.
+    #[error("payload too large")]
+    PayloadTooLarge,
+
+    /// The body can not be decompressed.
+    #[error("io: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
+impl ResponseError for DecompressionError {
+    fn status(&self) -> StatusCode {
+        match self {
+            DecompressionError::UnsupportedEncoding(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            DecompressionError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            DecompressionError::Io(_) => StatusCode::BAD_REQUEST,
+        }
+    }
+}
+
 /// A possible error value occurred in the `SizeLimit` middleware.
 #[derive(Debug, thiserror::Error, Eq, PartialEq)]
 pub enum SizedLimitError {
@@ -832,6 +1088,49 @@ impl ResponseError for SizedLimitError {
     }
 }
 
+/// A possible error value occurred in the
+/// [`BodyLimit`](crate::web::BodyLimit) extractor.
+#[derive(Debug, thiserror::Error, Eq, PartialEq)]
+pub struct BodyLimitError {
+    /// The size of the body, `None` if the request has no `Content-Length`
+    /// header and reading stopped after exceeding the limit.
+    pub actual: Option<usize>,
+
+    /// The maximum size of the body.
+    pub limit: usize,
+}
+
+impl Display for BodyLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.actual {
+            Some(actual) => write!(
+                f,
+                "payload too large: the body is {} bytes, the limit is {} bytes",
+                actual, self.limit
+            ),
+            None => write!(
+                f,
+                "payload too large: the body is more than {} bytes",
+                self.limit
+            ),
+        }
+    }
+}
+
+impl ResponseError for BodyLimitError {
+    fn status(&self) -> StatusCode {
+        StatusCode::PAYLOAD_TOO_LARGE
+    }
+
+    fn as_response(&self) -> Response {
+        let body = self.to_string();
+        Response::builder()
+            .status(self.status())
+            .header(http::header::CONTENT_LENGTH, body.len())
+            .body(body)
+    }
+}
+
 /// A possible error value occurred when adding a route.
 #[derive(Debug, thiserror::Error, Eq, PartialEq)]
 pub enum RouteError {
@@ -972,6 +1271,52 @@ mod tests {
         assert_eq!(err.as_response().status(), StatusCode::BAD_GATEWAY);
     }
 
+    #[tokio::test]
+    async fn test_rejection_handler() {
+        use serde_json::json;
+
+        use crate::{handler, web::Json, Endpoint, EndpointExt};
+
+        #[handler(internal)]
+        fn index(Json(value): Json<i32>) -> String {
+            value.to_string()
+        }
+
+        let ep = index.data(RejectionHandler::new(|err, req| {
+            let status = err.as_response().status();
+            Json(json!({
+                "error": {
+                    "status": status.as_u16(),
+                    "path": req.uri().path(),
+                }
+            }))
+            .with_status(status)
+            .into_response()
+        }));
+
+        let resp = ep
+            .call(Request::builder().uri_str("/abc").body("{"))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.into_body()
+                .into_json::<serde_json::Value>()
+                .await
+                .unwrap(),
+            json!({ "error": { "status": 400, "path": "/abc" } })
+        );
+
+        let resp = ep
+            .call(Request::builder().uri_str("/abc").body("10"))
+            .await
+            .unwrap();
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "10");
+
+        let err = index.call(Request::builder().body("{")).await.unwrap_err();
+        assert!(err.is::<ParseJsonError>());
+    }
+
     #[tokio::test]
     async fn test_custom_as_response() {
         #[derive(Debug, thiserror::Error)]
@@ -999,4 +1344,63 @@ mod tests {
             "my error message"
         );
     }
+
+    #[tokio::test]
+    async fn test_handler_custom_error_enum() {
+        use crate::{handler, web::Query, Endpoint, Request};
+
+        #[derive(Debug, thiserror::Error)]
+        enum MyError {
+            #[error("bad request")]
+            BadRequest,
+            #[error("not found")]
+            NotFound,
+            #[error("internal")]
+            Internal,
+        }
+
+        impl ResponseError for MyError {
+            fn status(&self) -> StatusCode {
+                match self {
+                    MyError::BadRequest => StatusCode::BAD_REQUEST,
+                    MyError::NotFound => StatusCode::NOT_FOUND,
+                    MyError::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+                }
+            }
+        }
+
+        #[derive(serde::Deserialize)]
+        struct Params {
+            code: u16,
+        }
+
+        #[handler(internal)]
+        async fn index(
+            Query(params): Query<Params>,
+        ) -> Result<(StatusCode, &'static str), MyError> {
+            match params.code {
+                400 => Err(MyError::BadRequest),
+                404 => Err(MyError::NotFound),
+                500 => Err(MyError::Internal),
+                _ => Ok((StatusCode::CREATED, "ok")),
+            }
+        }
+
+        for (code, status, body) in [
+            (400, StatusCode::BAD_REQUEST, "bad request"),
+            (404, StatusCode::NOT_FOUND, "not found"),
+            (500, StatusCode::INTERNAL_SERVER_ERROR, "internal"),
+            (0, StatusCode::CREATED, "ok"),
+        ] {
+            let resp = index
+                .get_response(
+                    Request::builder()
+                        .uri(format!("/?code={}", code).parse().unwrap())
+                        .finish(),
+                )
+                .await;
+            assert_eq!(resp.status(), status);
+            assert_eq!(resp.into_body().into_string().await.unwrap(), body);
+        }
+    }
 }
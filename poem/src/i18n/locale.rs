@@ -7,7 +7,7 @@ use unic_langid::LanguageIdentifier;
 use crate::{
     error::I18NError,
     i18n::{I18NArgs, I18NBundle, I18NResources},
-    FromRequest, Request, RequestBody, Result,
+    FromRequestParts, Request, Result,
 };
 
 type LanguageArray = SmallVec<[LanguageIdentifier; 8]>;
@@ -82,8 +82,8 @@ impl Locale {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for Locale {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for Locale {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         let resources = req
             .extensions()
             .get::<I18NResources>()
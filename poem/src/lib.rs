@@ -214,7 +214,7 @@
 //! #[handler]
 //! fn index() {}
 //!
-//! let app = Route::new().at("/", index).with(Tracing);
+//! let app = Route::new().at("/", index).with(Tracing::new());
 //! ```
 //!
 //! You can create your own middleware, see also [`Middleware`].
@@ -226,7 +226,10 @@
 //!
 //! |Feature           |Description                     |
 //! |------------------|--------------------------------|
-//! |compression  | Support decompress request body and compress response body |
+//! |compression  | Support decompress request body and compress response body, enables all the encodings |
+//! |compression-gzip | Support the `gzip` encoding of `compression` |
+//! |compression-deflate | Support the `deflate` encoding of `compression` |
+//! |compression-br | Support the `br` encoding of `compression` |
 //! |cookie            | Support for Cookie             |
 //! |csrf | Support for Cross-Site Request Forgery (CSRF) protection |
 //! |multipart         | Support for Multipart          |
@@ -289,4 +292,4 @@ pub use route::{
     connect, delete, get, head, options, patch, post, put, trace, Route, RouteDomain, RouteMethod,
 };
 pub use server::Server;
-pub use web::{FromRequest, IntoResponse, RequestBody};
+pub use web::{FromRequest, FromRequestParts, IntoResponse, RequestBody};
@@ -7,6 +7,7 @@ use tokio::io::{Error as IoError, ErrorKind, Result as IoResult};
 use tokio_native_tls::{native_tls::Identity, TlsStream};
 
 use crate::{
+    error::TlsConfigError,
     listener::{Acceptor, HandshakeStream, IntoTlsConfigStream, Listener},
     web::{LocalAddr, RemoteAddr},
 };
@@ -111,7 +112,10 @@ impl<T: Listener, S: IntoTlsConfigStream<NativeTlsConfig>> Listener for NativeTl
     async fn into_acceptor(self) -> IoResult<Self::Acceptor> {
         Ok(NativeTlsAcceptor::new(
             self.inner.into_acceptor().await?,
-            self.config_stream.into_stream()?.boxed(),
+            self.config_stream
+                .into_stream()
+                .map_err(TlsConfigError::wrap)?
+                .boxed(),
         ))
     }
 }
@@ -17,6 +17,7 @@ use tokio_rustls::{
 };
 
 use crate::{
+    error::TlsConfigError,
     listener::{Acceptor, HandshakeStream, IntoTlsConfigStream, Listener},
     web::{LocalAddr, RemoteAddr},
 };
@@ -202,7 +203,10 @@ impl<T: Listener, S: IntoTlsConfigStream<RustlsConfig>> Listener for RustlsListe
     async fn into_acceptor(self) -> IoResult<Self::Acceptor> {
         Ok(RustlsAcceptor::new(
             self.inner.into_acceptor().await?,
-            self.config_stream.into_stream()?.boxed(),
+            self.config_stream
+                .into_stream()
+                .map_err(TlsConfigError::wrap)?
+                .boxed(),
         ))
     }
 }
@@ -317,4 +321,15 @@ mod tests {
         let (mut stream, _, _, _) = acceptor.accept().await.unwrap();
         assert_eq!(stream.read_i32().await.unwrap(), 10);
     }
+
+    #[tokio::test]
+    async fn invalid_tls_config() {
+        let err = crate::Server::new(
+            TcpListener::bind("127.0.0.1:0").rustls(RustlsConfig::new().cert(b"abc".as_ref())),
+        )
+        .try_run(crate::endpoint::make_sync(|_| ()))
+        .await
+        .unwrap_err();
+        assert!(matches!(err, crate::error::ServerError::Tls(_)));
+    }
 }
@@ -1,11 +1,11 @@
-use std::{cmp::Reverse, str::FromStr};
+use std::cmp::Reverse;
 
 use typed_headers::{AcceptEncoding, ContentCoding, HeaderMapExt};
 
 use crate::{
-    http::header,
+    middleware::decompression::{decompress_body, DEFAULT_MAX_SIZE},
     web::{Compress, CompressionAlgo},
-    Body, Endpoint, IntoResponse, Middleware, Request, Response, Result,
+    Endpoint, IntoResponse, Middleware, Request, Response, Result,
 };
 
 /// Middleware for decompress request body and compress response body.
@@ -13,9 +13,27 @@ use crate::{
 /// It selects the decompression algorithm according to the request
 /// `Content-Encoding` header, and selects the compression algorithm according
 /// to the request `Accept-Encoding` header.
+///
+/// The request body is decompressed in the same way as
+/// [`Decompression`](crate::middleware::Decompression), so it is limited by
+/// [`Compression::max_size`].
+///
+/// # Errors
+///
+/// - [`DecompressionError`](crate::error::DecompressionError)
 #[cfg_attr(docsrs, doc(cfg(feature = "compression")))]
-#[derive(Default)]
-pub struct Compression;
+#[derive(Debug, Copy, Clone)]
+pub struct Compression {
+    max_size: usize,
+}
+
+impl Default for Compression {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_SIZE,
+        }
+    }
+}
 
 impl Compression {
     /// Creates a new `Compression` middleware.
@@ -23,13 +41,23 @@ impl Compression {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Sets the maximum size of the decompressed request body, default is 16
+    /// MiB.
+    #[must_use]
+    pub fn max_size(self, max_size: usize) -> Self {
+        Self { max_size }
+    }
 }
 
 impl<E: Endpoint> Middleware<E> for Compression {
     type Output = CompressionEndpoint<E>;
 
     fn transform(&self, ep: E) -> Self::Output {
-        CompressionEndpoint { ep }
+        CompressionEndpoint {
+            ep,
+            max_size: self.max_size,
+        }
     }
 }
 
@@ -37,6 +65,7 @@ impl<E: Endpoint> Middleware<E> for Compression {
 #[cfg_attr(docsrs, doc(cfg(feature = "compression")))]
 pub struct CompressionEndpoint<E: Endpoint> {
     ep: E,
+    max_size: usize,
 }
 
 #[async_trait::async_trait]
@@ -45,15 +74,7 @@ impl<E: Endpoint> Endpoint for CompressionEndpoint<E> {
 
     async fn call(&self, mut req: Request) -> Result<Self::Output> {
         // decompress request body
-        if let Some(algo) = req
-            .headers()
-            .get(header::CONTENT_ENCODING)
-            .and_then(|value| value.to_str().ok())
-            .and_then(|value| CompressionAlgo::from_str(value).ok())
-        {
-            let new_body = algo.decompress(req.take_body().into_async_read());
-            req.set_body(Body::from_async_read(new_body));
-        }
+        decompress_body(&mut req, self.max_size).await?;
 
         // negotiate content-encoding
         let mut compress_algo = None;
@@ -62,8 +83,11 @@ impl<E: Endpoint> Endpoint for CompressionEndpoint<E> {
             encoding.0.sort_by_key(|item| Reverse(item.quality));
             if let Some(item) = encoding.0.get(0) {
                 compress_algo = match item.item {
+                    #[cfg(feature = "compression-br")]
                     ContentCoding::BROTLI => Some(CompressionAlgo::BR),
+                    #[cfg(feature = "compression-deflate")]
                     ContentCoding::DEFLATE => Some(CompressionAlgo::DEFLATE),
+                    #[cfg(feature = "compression-gzip")]
                     ContentCoding::STAR | ContentCoding::GZIP => Some(CompressionAlgo::GZIP),
                     _ => None,
                 }
@@ -82,7 +106,7 @@ mod tests {
     use tokio::io::AsyncReadExt;
 
     use super::*;
-    use crate::{handler, EndpointExt, Request};
+    use crate::{handler, Body, EndpointExt, Request};
 
     const DATA: &str = "abcdefghijklmnopqrstuvwxyz1234567890";
     const DATA_REV: &str = "0987654321zyxwvutsrqponmlkjihgfedcba";
@@ -93,7 +117,7 @@ mod tests {
     }
 
     async fn test_algo(algo: CompressionAlgo) {
-        let ep = index.with(Compression);
+        let ep = index.with(Compression::new());
         let mut resp = ep
             .call(
                 Request::builder()
@@ -119,14 +143,43 @@ mod tests {
 
     #[tokio::test]
     async fn test_compression() {
+        #[cfg(feature = "compression-br")]
         test_algo(CompressionAlgo::BR).await;
+        #[cfg(feature = "compression-deflate")]
         test_algo(CompressionAlgo::DEFLATE).await;
+        #[cfg(feature = "compression-gzip")]
         test_algo(CompressionAlgo::GZIP).await;
     }
 
+    #[cfg(feature = "compression-gzip")]
+    #[tokio::test]
+    async fn test_request_size_limit() {
+        let mut bomb = Vec::new();
+        CompressionAlgo::GZIP
+            .compress(&vec![b'0'; 1024 * 1024][..])
+            .read_to_end(&mut bomb)
+            .await
+            .unwrap();
+
+        let err = index
+            .with(Compression::new().max_size(64 * 1024))
+            .call(
+                Request::builder()
+                    .header("Content-Encoding", "gzip")
+                    .body(bomb),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.as_response().status(),
+            crate::http::StatusCode::PAYLOAD_TOO_LARGE
+        );
+    }
+
+    #[cfg(feature = "compression-gzip")]
     #[tokio::test]
     async fn test_negotiate() {
-        let ep = index.with(Compression);
+        let ep = index.with(Compression::new());
         let mut resp = ep
             .call(
                 Request::builder()
@@ -149,9 +202,10 @@ mod tests {
         assert_eq!(data, DATA_REV.as_bytes());
     }
 
+    #[cfg(feature = "compression-gzip")]
     #[tokio::test]
     async fn test_star() {
-        let ep = index.with(Compression);
+        let ep = index.with(Compression::new());
         let mut resp = ep
             .call(
                 Request::builder()
@@ -0,0 +1,240 @@
+use std::{pin::Pin, str::FromStr};
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{
+    error::DecompressionError,
+    http::{header, HeaderValue},
+    web::CompressionAlgo,
+    Endpoint, Middleware, Request, Result,
+};
+
+/// Middleware for decompressing the request body.
+///
+/// The request body is decoded according to the `Content-Encoding` header
+/// before the inner endpoint is called, so the body extractors such as
+/// [`Json`](crate::web::Json) see the decompressed bytes. The `gzip`,
+/// `deflate` and `br` encodings are supported if the `compression-gzip`,
+/// `compression-deflate` and `compression-br` features are enabled, the other
+/// encodings are rejected with `415 Unsupported Media Type`.
+///
+/// The decompressed body is buffered in memory, so it is limited by
+/// [`Decompression::max_size`] to guard against decompression bombs.
+///
+/// # Errors
+///
+/// - [`DecompressionError`]
+///
+/// # Example
+///
+/// ```
+/// use poem::{handler, middleware::Decompression, web::Json, EndpointExt, Route};
+/// use serde_json::Value;
+///
+/// #[handler]
+/// fn index(Json(value): Json<Value>) -> String {
+///     value.to_string()
+/// }
+///
+/// let app = Route::new()
+///     .at("/", index)
+///     .with(Decompression::new().max_size(1024 * 1024));
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "compression")))]
+#[derive(Debug, Copy, Clone)]
+pub struct Decompression {
+    max_size: usize,
+}
+
+/// The default maximum size of the decompressed body.
+pub(crate) const DEFAULT_MAX_SIZE: usize = 16 * 1024 * 1024;
+
+impl Default for Decompression {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_SIZE,
+        }
+    }
+}
+
+impl Decompression {
+    /// Creates a new `Decompression` middleware.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum size of the decompressed body, default is 16 MiB.
+    #[must_use]
+    pub fn max_size(self, max_size: usize) -> Self {
+        Self { max_size }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for Decompression {
+    type Output = DecompressionEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        DecompressionEndpoint {
+            inner: ep,
+            max_size: self.max_size,
+        }
+    }
+}
+
+/// Endpoint for Decompression middleware.
+#[cfg_attr(docsrs, doc(cfg(feature = "compression")))]
+pub struct DecompressionEndpoint<E> {
+    inner: E,
+    max_size: usize,
+}
+
+#[async_trait::async_trait]
+impl<E: Endpoint> Endpoint for DecompressionEndpoint<E> {
+    type Output = E::Output;
+
+    async fn call(&self, mut req: Request) -> Result<Self::Output> {
+        decompress_body(&mut req, self.max_size).await?;
+        self.inner.call(req).await
+    }
+}
+
+/// Decompresses the request body according to the `Content-Encoding` header,
+/// the decompressed body is limited by `max_size`.
+pub(crate) async fn decompress_body(
+    req: &mut Request,
+    max_size: usize,
+) -> Result<(), DecompressionError> {
+    let encodings = req
+        .headers()
+        .get_all(header::CONTENT_ENCODING)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|encoding| !encoding.is_empty() && !encoding.eq_ignore_ascii_case("identity"))
+        .map(|encoding| {
+            CompressionAlgo::from_str(&encoding.to_ascii_lowercase())
+                .map_err(|_| DecompressionError::UnsupportedEncoding(encoding.to_string()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if encodings.is_empty() {
+        return Ok(());
+    }
+
+    // The encodings are listed in the order they were applied.
+    let mut reader: Pin<Box<dyn AsyncRead + Send>> = Box::pin(req.take_body().into_async_read());
+    for algo in encodings.iter().rev() {
+        reader = algo.decompress(reader);
+    }
+
+    let mut data = Vec::new();
+    reader
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut data)
+        .await?;
+    if data.len() > max_size {
+        return Err(DecompressionError::PayloadTooLarge);
+    }
+
+    req.headers_mut().remove(header::CONTENT_ENCODING);
+    req.headers_mut()
+        .insert(header::CONTENT_LENGTH, HeaderValue::from(data.len()));
+    req.set_body(data);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::{handler, http::StatusCode, web::Json, Body, EndpointExt};
+
+    #[handler(internal)]
+    fn index(Json(value): Json<Value>) -> String {
+        value.to_string()
+    }
+
+    async fn compress(algo: CompressionAlgo, data: &[u8]) -> Vec<u8> {
+        let mut compressed = Vec::new();
+        algo.compress(data)
+            .read_to_end(&mut compressed)
+            .await
+            .unwrap();
+        compressed
+    }
+
+    #[tokio::test]
+    async fn decompress_json() {
+        let ep = index.with(Decompression::new());
+        let data = json!({ "name": "poem", "tags": ["a", "b"] }).to_string();
+
+        for algo in [
+            #[cfg(feature = "compression-gzip")]
+            CompressionAlgo::GZIP,
+            #[cfg(feature = "compression-deflate")]
+            CompressionAlgo::DEFLATE,
+            #[cfg(feature = "compression-br")]
+            CompressionAlgo::BR,
+        ] {
+            let resp = ep
+                .call(
+                    Request::builder()
+                        .header(header::CONTENT_TYPE, "application/json")
+                        .header(header::CONTENT_ENCODING, algo.as_str())
+                        .body(compress(algo, data.as_bytes()).await),
+                )
+                .await
+                .unwrap();
+            assert_eq!(resp.into_body().into_string().await.unwrap(), data);
+        }
+
+        let resp = ep
+            .call(
+                Request::builder()
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(data.clone()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.into_body().into_string().await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn unsupported_encoding() {
+        let err = index
+            .with(Decompression::new())
+            .call(
+                Request::builder()
+                    .header(header::CONTENT_ENCODING, "zstd")
+                    .body(Body::empty()),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.as_response().status(),
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+    }
+
+    #[cfg(feature = "compression-gzip")]
+    #[tokio::test]
+    async fn bomb_is_capped() {
+        let bomb = compress(CompressionAlgo::GZIP, &vec![b'0'; 1024 * 1024]).await;
+        assert!(bomb.len() < 4096);
+
+        let err = index
+            .with(Decompression::new().max_size(64 * 1024))
+            .call(
+                Request::builder()
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .header(header::CONTENT_ENCODING, "gzip")
+                    .body(bomb),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.as_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
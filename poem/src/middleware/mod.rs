@@ -1,13 +1,23 @@
 //! Commonly used middleware.
 
 mod add_data;
-#[cfg(feature = "compression")]
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
 mod compression;
 #[cfg(feature = "cookie")]
 mod cookie_jar_manager;
 mod cors;
 #[cfg(feature = "csrf")]
 mod csrf;
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
+mod decompression;
 mod force_https;
 mod normalize_path;
 #[cfg(feature = "opentelemetry")]
@@ -17,19 +27,30 @@ mod opentelemetry_tracing;
 mod propagate_header;
 mod sensitive_header;
 mod set_header;
+mod set_security_headers;
 mod size_limit;
 #[cfg(feature = "tower-compat")]
 mod tower_compat;
 mod tracing_mw;
 
 pub use add_data::{AddData, AddDataEndpoint};
-#[cfg(feature = "compression")]
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
 pub use compression::{Compression, CompressionEndpoint};
 #[cfg(feature = "cookie")]
 pub use cookie_jar_manager::{CookieJarManager, CookieJarManagerEndpoint};
 pub use cors::{Cors, CorsEndpoint};
 #[cfg(feature = "csrf")]
 pub use csrf::{Csrf, CsrfEndpoint};
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
+pub use decompression::{Decompression, DecompressionEndpoint};
 pub use force_https::ForceHttps;
 pub use normalize_path::{NormalizePath, NormalizePathEndpoint, TrailingSlash};
 #[cfg(feature = "opentelemetry")]
@@ -39,6 +60,7 @@ pub use opentelemetry_tracing::{OpenTelemetryTracing, OpenTelemetryTracingEndpoi
 pub use propagate_header::{PropagateHeader, PropagateHeaderEndpoint};
 pub use sensitive_header::{SensitiveHeader, SensitiveHeaderEndpoint};
 pub use set_header::{SetHeader, SetHeaderEndpoint};
+pub use set_security_headers::{SetSecurityHeaders, SetSecurityHeadersEndpoint};
 pub use size_limit::{SizeLimit, SizeLimitEndpoint};
 #[cfg(feature = "tower-compat")]
 pub use tower_compat::TowerLayerCompatExt;
@@ -3,7 +3,7 @@ use std::str::FromStr;
 use http::{uri::PathAndQuery, Uri};
 use regex::Regex;
 
-use crate::{Endpoint, Middleware, Request, Result};
+use crate::{web::Redirect, Endpoint, IntoResponse, Middleware, Request, Response, Result};
 
 /// Determines the behavior of the [`NormalizePath`] middleware.
 #[derive(Debug, Clone, Copy)]
@@ -27,6 +27,10 @@ impl Default for TrailingSlash {
 /// Middleware for normalizing a request's path so that routes can be matched
 /// more flexibly.
 ///
+/// By default the path of the request is rewritten before it is passed to the
+/// inner endpoint. With [`NormalizePath::redirect`], the client is redirected
+/// to the normalized path with `308 Permanent Redirect` instead.
+///
 /// # Example
 ///
 /// ```
@@ -59,13 +63,29 @@ impl Default for TrailingSlash {
 /// assert_eq!(resp.into_body().into_string().await.unwrap(), "hello");
 /// # });
 /// ```
-pub struct NormalizePath(TrailingSlash);
+pub struct NormalizePath {
+    style: TrailingSlash,
+    redirect: bool,
+}
 
 impl NormalizePath {
     /// Create new `NormalizePath` middleware with the specified trailing slash
     /// style.
     pub fn new(style: TrailingSlash) -> Self {
-        Self(style)
+        Self {
+            style,
+            redirect: false,
+        }
+    }
+
+    /// Redirect the request to the normalized path with `308 Permanent
+    /// Redirect` instead of rewriting it.
+    #[must_use]
+    pub fn redirect(self) -> Self {
+        Self {
+            redirect: true,
+            ..self
+        }
     }
 }
 
@@ -76,7 +96,8 @@ impl<E: Endpoint> Middleware<E> for NormalizePath {
         NormalizePathEndpoint {
             inner: ep,
             merge_slash: Regex::new("//+").unwrap(),
-            style: self.0,
+            style: self.style,
+            redirect: self.redirect,
         }
     }
 }
@@ -86,11 +107,29 @@ pub struct NormalizePathEndpoint<E> {
     inner: E,
     merge_slash: Regex,
     style: TrailingSlash,
+    redirect: bool,
+}
+
+impl<E> NormalizePathEndpoint<E> {
+    fn normalize(&self, path: &str) -> String {
+        let path = match self.style {
+            TrailingSlash::Always => format!("{}/", path),
+            TrailingSlash::MergeOnly => path.to_string(),
+            TrailingSlash::Trim => path.trim_end_matches('/').to_string(),
+        };
+
+        let path = self.merge_slash.replace_all(&path, "/");
+        if path.is_empty() {
+            "/".to_string()
+        } else {
+            path.into_owned()
+        }
+    }
 }
 
 #[async_trait::async_trait]
 impl<E: Endpoint> Endpoint for NormalizePathEndpoint<E> {
-    type Output = E::Output;
+    type Output = Response;
 
     async fn call(&self, mut req: Request) -> Result<Self::Output> {
         let original_path = req
@@ -100,23 +139,27 @@ impl<E: Endpoint> Endpoint for NormalizePathEndpoint<E> {
             .unwrap_or_default();
 
         if !original_path.is_empty() {
-            let path = match self.style {
-                TrailingSlash::Always => format!("{}/", original_path),
-                TrailingSlash::MergeOnly => original_path.to_string(),
-                TrailingSlash::Trim => original_path.trim_end_matches('/').to_string(),
-            };
-
-            let path = self.merge_slash.replace_all(&path, "/");
-            let path = if path.is_empty() { "/" } else { path.as_ref() };
+            let path = self.normalize(original_path);
 
             if path != original_path {
-                let (mut parts, body) = req.into_parts();
-                let mut uri_parts = parts.uri.into_parts();
-                let query = uri_parts.path_and_query.as_ref().and_then(|pq| pq.query());
-                let path = match query {
+                if self.redirect {
+                    // The path of a nested endpoint is stripped, so the location is
+                    // created from the original URI.
+                    let uri = req.original_uri();
+                    let location = match uri.query() {
+                        Some(query) => format!("{}?{}", self.normalize(uri.path()), query),
+                        None => self.normalize(uri.path()),
+                    };
+                    return Ok(Redirect::permanent(location).into_response());
+                }
+
+                let path = match req.uri().query() {
                     Some(query) => format!("{}?{}", path, query),
-                    None => path.to_string(),
+                    None => path,
                 };
+
+                let (mut parts, body) = req.into_parts();
+                let mut uri_parts = parts.uri.into_parts();
                 uri_parts.path_and_query = Some(PathAndQuery::from_str(&path).unwrap());
 
                 let new_uri = Uri::from_parts(uri_parts).unwrap();
@@ -126,7 +169,7 @@ impl<E: Endpoint> Endpoint for NormalizePathEndpoint<E> {
             }
         }
 
-        self.inner.call(req).await
+        self.inner.call(req).await.map(IntoResponse::into_response)
     }
 }
 
@@ -307,4 +350,76 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn redirect() {
+        let ep = Route::new()
+            .at("/", make_sync(|_| ()))
+            .at("/v1/something", make_sync(|_| ()))
+            .with(NormalizePath::new(TrailingSlash::Trim).redirect());
+
+        for uri in ["/", "/v1/something"] {
+            let req = Request::builder().uri(Uri::from_str(uri).unwrap()).finish();
+            let res = ep.call(req).await.unwrap();
+            assert_eq!(res.status(), StatusCode::OK, "Failed uri: {}", uri);
+        }
+
+        let test_uris = [
+            ("///", "/"),
+            ("/v1/something/", "/v1/something"),
+            ("//v1//something//?query=test", "/v1/something?query=test"),
+        ];
+
+        for (uri, location) in test_uris {
+            let req = Request::builder().uri(Uri::from_str(uri).unwrap()).finish();
+            let res = ep.call(req).await.unwrap();
+            assert_eq!(
+                res.status(),
+                StatusCode::PERMANENT_REDIRECT,
+                "Failed uri: {}",
+                uri
+            );
+            assert_eq!(
+                res.header("location"),
+                Some(location),
+                "Failed uri: {}",
+                uri
+            );
+        }
+
+        let ep = Route::new()
+            .at("/", make_sync(|_| ()))
+            .at("/v1/something/", make_sync(|_| ()))
+            .with(NormalizePath::new(TrailingSlash::Always).redirect());
+
+        for uri in ["/", "/v1/something/"] {
+            let req = Request::builder().uri(Uri::from_str(uri).unwrap()).finish();
+            let res = ep.call(req).await.unwrap();
+            assert_eq!(res.status(), StatusCode::OK, "Failed uri: {}", uri);
+        }
+
+        let req = Request::builder()
+            .uri(Uri::from_static("/v1/something"))
+            .finish();
+        let res = ep.call(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(res.header("location"), Some("/v1/something/"));
+    }
+
+    #[tokio::test]
+    async fn redirect_nested() {
+        let ep = Route::new().nest(
+            "/api",
+            Route::new()
+                .at("/users", make_sync(|_| ()))
+                .with(NormalizePath::new(TrailingSlash::Trim).redirect()),
+        );
+
+        let req = Request::builder()
+            .uri(Uri::from_static("/api/users/?page=2"))
+            .finish();
+        let res = ep.call(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(res.header("location"), Some("/api/users?page=2"));
+    }
 }
@@ -0,0 +1,251 @@
+use std::convert::TryInto;
+
+use crate::{
+    http::{
+        header::{
+            HeaderName, REFERRER_POLICY, STRICT_TRANSPORT_SECURITY, X_CONTENT_TYPE_OPTIONS,
+            X_FRAME_OPTIONS,
+        },
+        HeaderMap, HeaderValue,
+    },
+    Endpoint, Middleware, Request, Response, Result,
+};
+
+/// Middleware for adding the common security headers to response.
+///
+/// The following headers are added by default:
+///
+/// | Header                    | Value                               |
+/// |---------------------------|-------------------------------------|
+/// | Strict-Transport-Security | max-age=31536000; includeSubDomains |
+/// | X-Content-Type-Options    | nosniff                             |
+/// | X-Frame-Options           | DENY                                |
+/// | Referrer-Policy           | strict-origin-when-cross-origin     |
+///
+/// The headers already set by the inner endpoint are kept, and the headers are
+/// added to the error responses too.
+///
+/// `Content-Security-Policy` is not added by default, because a policy such
+/// as `default-src 'self'` blocks the scripts and styles that Swagger UI,
+/// Rapidoc and Redoc load from a CDN. Use
+/// [`overriding`](SetSecurityHeaders::overriding) to add it.
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     get, handler,
+///     http::{header, StatusCode},
+///     middleware::SetSecurityHeaders,
+///     Endpoint, EndpointExt, Request, Route,
+/// };
+///
+/// #[handler]
+/// fn index() -> &'static str {
+///     "hello"
+/// }
+///
+/// let app = Route::new().at("/", get(index)).with(
+///     SetSecurityHeaders::new()
+///         .overriding(header::X_FRAME_OPTIONS, "SAMEORIGIN")
+///         .overriding(header::CONTENT_SECURITY_POLICY, "default-src 'self'")
+///         .removing(header::STRICT_TRANSPORT_SECURITY),
+/// );
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = app.call(Request::default()).await.unwrap();
+/// assert_eq!(resp.status(), StatusCode::OK);
+/// assert_eq!(resp.headers()[header::X_FRAME_OPTIONS], "SAMEORIGIN");
+/// assert_eq!(resp.headers()[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+/// assert_eq!(
+///     resp.headers()[header::CONTENT_SECURITY_POLICY],
+///     "default-src 'self'"
+/// );
+/// assert!(!resp.headers().contains_key(header::STRICT_TRANSPORT_SECURITY));
+/// # });
+/// ```
+pub struct SetSecurityHeaders {
+    headers: HeaderMap,
+}
+
+impl Default for SetSecurityHeaders {
+    fn default() -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            STRICT_TRANSPORT_SECURITY,
+            HeaderValue::from_static("max-age=31536000; includeSubDomains"),
+        );
+        headers.insert(X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+        headers.insert(X_FRAME_OPTIONS, HeaderValue::from_static("DENY"));
+        headers.insert(
+            REFERRER_POLICY,
+            HeaderValue::from_static("strict-origin-when-cross-origin"),
+        );
+        Self { headers }
+    }
+}
+
+impl SetSecurityHeaders {
+    /// Create new `SetSecurityHeaders` middleware with the default headers.
+    #[must_use]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the value of a header, replacing the default value if there is
+    /// one.
+    #[must_use]
+    pub fn overriding<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: TryInto<HeaderName>,
+        V: TryInto<HeaderValue>,
+    {
+        let key = key.try_into();
+        let value = value.try_into();
+        if let (Ok(key), Ok(value)) = (key, value) {
+            self.headers.insert(key, value);
+        }
+        self
+    }
+
+    /// Removes a header, so that it is not added to response.
+    #[must_use]
+    pub fn removing<K>(mut self, key: K) -> Self
+    where
+        K: TryInto<HeaderName>,
+    {
+        if let Ok(key) = key.try_into() {
+            self.headers.remove(key);
+        }
+        self
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for SetSecurityHeaders {
+    type Output = SetSecurityHeadersEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        SetSecurityHeadersEndpoint {
+            inner: ep,
+            headers: self.headers.clone(),
+        }
+    }
+}
+
+/// Endpoint for SetSecurityHeaders middleware.
+pub struct SetSecurityHeadersEndpoint<E> {
+    inner: E,
+    headers: HeaderMap,
+}
+
+#[async_trait::async_trait]
+impl<E: Endpoint> Endpoint for SetSecurityHeadersEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> Result<Self::Output> {
+        let mut resp = self.inner.get_response(req).await;
+        let headers = resp.headers_mut();
+
+        for (name, value) in &self.headers {
+            headers.entry(name).or_insert_with(|| value.clone());
+        }
+
+        Ok(resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::NotFoundError,
+        handler,
+        http::{header::CONTENT_SECURITY_POLICY, StatusCode},
+        EndpointExt, IntoResponse,
+    };
+
+    #[tokio::test]
+    async fn default_headers() {
+        #[handler(internal)]
+        fn index() {}
+
+        let resp = index
+            .with(SetSecurityHeaders::new())
+            .call(Request::default())
+            .await
+            .unwrap();
+
+        let headers = resp.headers();
+        assert_eq!(
+            headers[STRICT_TRANSPORT_SECURITY],
+            "max-age=31536000; includeSubDomains"
+        );
+        assert!(!headers.contains_key(CONTENT_SECURITY_POLICY));
+        assert_eq!(headers[X_CONTENT_TYPE_OPTIONS], "nosniff");
+        assert_eq!(headers[X_FRAME_OPTIONS], "DENY");
+        assert_eq!(headers[REFERRER_POLICY], "strict-origin-when-cross-origin");
+    }
+
+    #[tokio::test]
+    async fn override_and_remove() {
+        #[handler(internal)]
+        fn index() {}
+
+        let resp = index
+            .with(
+                SetSecurityHeaders::new()
+                    .overriding(X_FRAME_OPTIONS, "SAMEORIGIN")
+                    .overriding("Content-Security-Policy", "default-src 'none'")
+                    .removing(STRICT_TRANSPORT_SECURITY),
+            )
+            .call(Request::default())
+            .await
+            .unwrap();
+
+        let headers = resp.headers();
+        assert_eq!(headers[X_FRAME_OPTIONS], "SAMEORIGIN");
+        assert_eq!(headers[CONTENT_SECURITY_POLICY], "default-src 'none'");
+        assert!(!headers.contains_key(STRICT_TRANSPORT_SECURITY));
+        assert_eq!(headers[X_CONTENT_TYPE_OPTIONS], "nosniff");
+    }
+
+    #[tokio::test]
+    async fn error_response() {
+        #[handler(internal)]
+        fn index() -> Result<()> {
+            Err(NotFoundError.into())
+        }
+
+        let resp = index
+            .with(SetSecurityHeaders::new())
+            .call(Request::default())
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        assert_eq!(resp.headers()[X_CONTENT_TYPE_OPTIONS], "nosniff");
+        assert_eq!(resp.headers()[X_FRAME_OPTIONS], "DENY");
+    }
+
+    #[tokio::test]
+    async fn keep_endpoint_headers() {
+        #[handler(internal)]
+        fn index() -> impl IntoResponse {
+            "hello".with_header(X_FRAME_OPTIONS, "SAMEORIGIN")
+        }
+
+        let resp = index
+            .with(SetSecurityHeaders::new())
+            .call(Request::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            resp.headers()
+                .get_all(X_FRAME_OPTIONS)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![HeaderValue::from_static("SAMEORIGIN")]
+        );
+    }
+}
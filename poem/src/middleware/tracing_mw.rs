@@ -1,24 +1,217 @@
 use std::time::Instant;
 
-use tracing::{Instrument, Level};
+use tracing::{field::Empty, Instrument, Level, Span};
 
 use crate::{Endpoint, IntoResponse, Middleware, Request, Response, Result};
 
+macro_rules! span_with_level {
+    ($level:expr, $($args:tt)*) => {
+        match $level {
+            Level::TRACE => tracing::span!(target: module_path!(), Level::TRACE, $($args)*),
+            Level::DEBUG => tracing::span!(target: module_path!(), Level::DEBUG, $($args)*),
+            Level::INFO => tracing::span!(target: module_path!(), Level::INFO, $($args)*),
+            Level::WARN => tracing::span!(target: module_path!(), Level::WARN, $($args)*),
+            Level::ERROR => tracing::span!(target: module_path!(), Level::ERROR, $($args)*),
+        }
+    };
+}
+
+macro_rules! event_with_level {
+    ($level:expr, $($args:tt)*) => {
+        match $level {
+            Level::TRACE => tracing::event!(target: module_path!(), Level::TRACE, $($args)*),
+            Level::DEBUG => tracing::event!(target: module_path!(), Level::DEBUG, $($args)*),
+            Level::INFO => tracing::event!(target: module_path!(), Level::INFO, $($args)*),
+            Level::WARN => tracing::event!(target: module_path!(), Level::WARN, $($args)*),
+            Level::ERROR => tracing::event!(target: module_path!(), Level::ERROR, $($args)*),
+        }
+    };
+}
+
 /// Middleware for [`tracing`](https://crates.io/crates/tracing).
-#[derive(Default)]
-pub struct Tracing;
+///
+/// Each request is wrapped in a `request` span carrying the remote address,
+/// HTTP version, method and path, and a `response` (or `error`) event with the
+/// status code and the elapsed duration is emitted when the inner endpoint
+/// returns. The status is recorded for errors too.
+///
+/// The duration is measured until the response head is produced, it does not
+/// include streaming the response body.
+///
+/// Applied to a single route, [`Tracing::route`] adds a `route` field with the
+/// route pattern to the span, so that the logs of a handler can be correlated
+/// even when the requested paths differ. The span itself is always named
+/// `request`, because `tracing` requires span names to be known at compile
+/// time.
+///
+/// # Example
+///
+/// ```
+/// use poem::{handler, middleware::Tracing, EndpointExt, Route};
+/// use tracing::Level;
+///
+/// #[handler]
+/// fn index() -> &'static str {
+///     "hello"
+/// }
+///
+/// let app = Route::new()
+///     .at("/", index)
+///     .at("/users/:id", index.with(Tracing::new().route("/users/:id")))
+///     .with(Tracing::new().level(Level::DEBUG).version(false));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Tracing {
+    level: Level,
+    route: Option<&'static str>,
+    remote_addr: bool,
+    version: bool,
+    method: bool,
+    path: bool,
+    status: bool,
+    duration: bool,
+}
+
+impl Default for Tracing {
+    fn default() -> Self {
+        Self {
+            level: Level::INFO,
+            route: None,
+            remote_addr: true,
+            version: true,
+            method: true,
+            path: true,
+            status: true,
+            duration: true,
+        }
+    }
+}
+
+impl Tracing {
+    /// Create `Tracing` middleware.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the level of the emitted spans and events, default is
+    /// [`Level::INFO`].
+    #[must_use]
+    pub fn level(self, level: Level) -> Self {
+        Self { level, ..self }
+    }
+
+    /// Sets the route pattern recorded in the `route` field of the span.
+    #[must_use]
+    pub fn route(self, route: &'static str) -> Self {
+        Self {
+            route: Some(route),
+            ..self
+        }
+    }
+
+    /// Sets whether to record the remote address, default is `true`.
+    #[must_use]
+    pub fn remote_addr(self, enable: bool) -> Self {
+        Self {
+            remote_addr: enable,
+            ..self
+        }
+    }
+
+    /// Sets whether to record the HTTP version, default is `true`.
+    #[must_use]
+    pub fn version(self, enable: bool) -> Self {
+        Self {
+            version: enable,
+            ..self
+        }
+    }
+
+    /// Sets whether to record the HTTP method, default is `true`.
+    #[must_use]
+    pub fn method(self, enable: bool) -> Self {
+        Self {
+            method: enable,
+            ..self
+        }
+    }
+
+    /// Sets whether to record the request path, default is `true`.
+    #[must_use]
+    pub fn path(self, enable: bool) -> Self {
+        Self {
+            path: enable,
+            ..self
+        }
+    }
+
+    /// Sets whether to record the response status, default is `true`.
+    #[must_use]
+    pub fn status(self, enable: bool) -> Self {
+        Self {
+            status: enable,
+            ..self
+        }
+    }
+
+    /// Sets whether to record the elapsed duration, default is `true`.
+    #[must_use]
+    pub fn duration(self, enable: bool) -> Self {
+        Self {
+            duration: enable,
+            ..self
+        }
+    }
+}
 
 impl<E: Endpoint> Middleware<E> for Tracing {
     type Output = TracingEndpoint<E>;
 
     fn transform(&self, ep: E) -> Self::Output {
-        TracingEndpoint { inner: ep }
+        TracingEndpoint {
+            inner: ep,
+            config: *self,
+        }
     }
 }
 
 /// Endpoint for `Tracing` middleware.
 pub struct TracingEndpoint<E> {
     inner: E,
+    config: Tracing,
+}
+
+impl<E> TracingEndpoint<E> {
+    fn create_span(&self, req: &Request) -> Span {
+        let config = &self.config;
+        let span = span_with_level!(
+            config.level,
+            "request",
+            route = Empty,
+            remote_addr = Empty,
+            version = Empty,
+            method = Empty,
+            path = Empty,
+        );
+
+        if let Some(route) = config.route {
+            span.record("route", tracing::field::display(route));
+        }
+        if config.remote_addr {
+            span.record("remote_addr", tracing::field::display(req.remote_addr()));
+        }
+        if config.version {
+            span.record("version", tracing::field::debug(req.version()));
+        }
+        if config.method {
+            span.record("method", tracing::field::display(req.method()));
+        }
+        if config.path {
+            span.record("path", tracing::field::display(req.uri()));
+        }
+
+        span
+    }
 }
 
 #[async_trait::async_trait]
@@ -26,35 +219,33 @@ impl<E: Endpoint> Endpoint for TracingEndpoint<E> {
     type Output = Response;
 
     async fn call(&self, req: Request) -> Result<Self::Output> {
-        let span = tracing::span!(
-            target: module_path!(),
-            Level::INFO,
-            "request",
-            remote_addr = %req.remote_addr(),
-            version = ?req.version(),
-            method = %req.method(),
-            path = %req.uri(),
-        );
+        let config = &self.config;
+        let span = self.create_span(&req);
 
         async move {
             let now = Instant::now();
             let res = self.inner.call(req).await;
             let duration = now.elapsed();
+            let duration = config.duration.then(|| tracing::field::debug(duration));
 
             match res {
                 Ok(resp) => {
                     let resp = resp.into_response();
-                    tracing::info!(
-                        status = %resp.status(),
-                        duration = ?duration,
-                        "response"
-                    );
+                    let status = config
+                        .status
+                        .then(|| tracing::field::display(resp.status()));
+                    event_with_level!(config.level, status, duration, "response");
                     Ok(resp)
                 }
                 Err(err) => {
-                    tracing::info!(
+                    let status = config
+                        .status
+                        .then(|| tracing::field::display(err.as_response().status()));
+                    event_with_level!(
+                        config.level,
+                        status,
                         error = %err,
-                        duration = ?duration,
+                        duration,
                         "error"
                     );
                     Err(err)
@@ -65,3 +256,171 @@ impl<E: Endpoint> Endpoint for TracingEndpoint<E> {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        fmt::Debug,
+        sync::{Arc, Mutex},
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id, Record},
+        Event, Metadata, Subscriber,
+    };
+
+    use super::*;
+    use crate::{
+        handler,
+        http::{Method, StatusCode},
+        EndpointExt, Error,
+    };
+
+    type Fields = BTreeMap<String, String>;
+
+    #[derive(Default, Clone)]
+    struct Capture {
+        span: Arc<Mutex<Fields>>,
+        events: Arc<Mutex<Vec<(Level, String, Fields)>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl<'a> Visit for FieldVisitor<'a> {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            span.record(&mut FieldVisitor(&mut self.span.lock().unwrap()));
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            values.record(&mut FieldVisitor(&mut self.span.lock().unwrap()));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            let message = fields.remove("message").unwrap_or_default();
+            self.events
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), message, fields));
+        }
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[handler(internal)]
+    fn index() -> &'static str {
+        "hello"
+    }
+
+    #[handler(internal)]
+    fn not_found() -> Result<&'static str> {
+        Err(Error::from_status(StatusCode::NOT_FOUND))
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn response_fields() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+
+        index
+            .with(Tracing::new())
+            .call(
+                Request::builder()
+                    .method(Method::GET)
+                    .uri_str("/a")
+                    .finish(),
+            )
+            .await
+            .unwrap();
+
+        let span = capture.span.lock().unwrap().clone();
+        assert_eq!(span.get("method").map(String::as_str), Some("GET"));
+        assert_eq!(span.get("path").map(String::as_str), Some("/a"));
+        assert!(span.contains_key("remote_addr"));
+        assert!(span.contains_key("version"));
+        assert!(!span.contains_key("route"));
+
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let (level, message, fields) = &events[0];
+        assert_eq!(*level, Level::INFO);
+        assert_eq!(message, "response");
+        assert_eq!(fields.get("status").map(String::as_str), Some("200 OK"));
+        assert!(fields.contains_key("duration"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn error_status_and_selected_fields() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+
+        not_found
+            .with(
+                Tracing::new()
+                    .level(Level::WARN)
+                    .remote_addr(false)
+                    .version(false)
+                    .duration(false),
+            )
+            .call(Request::builder().uri_str("/b").finish())
+            .await
+            .unwrap_err();
+
+        let span = capture.span.lock().unwrap().clone();
+        assert_eq!(span.get("path").map(String::as_str), Some("/b"));
+        assert!(!span.contains_key("remote_addr"));
+        assert!(!span.contains_key("version"));
+
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let (level, message, fields) = &events[0];
+        assert_eq!(*level, Level::WARN);
+        assert_eq!(message, "error");
+        assert_eq!(
+            fields.get("status").map(String::as_str),
+            Some("404 Not Found")
+        );
+        assert!(fields.contains_key("error"));
+        assert!(!fields.contains_key("duration"));
+    }
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn route_field() {
+        let capture = Capture::default();
+        let _guard = tracing::subscriber::set_default(capture.clone());
+
+        let app = crate::Route::new().at(
+            "/users/:id",
+            index.with(Tracing::new().level(Level::DEBUG).route("/users/:id")),
+        );
+        app.call(Request::builder().uri_str("/users/1").finish())
+            .await
+            .unwrap();
+
+        let span = capture.span.lock().unwrap().clone();
+        assert_eq!(span.get("route").map(String::as_str), Some("/users/:id"));
+        assert_eq!(span.get("path").map(String::as_str), Some("/users/1"));
+
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events[0].0, Level::DEBUG);
+    }
+}
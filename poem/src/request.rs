@@ -36,6 +36,8 @@ pub(crate) struct RequestState {
     pub(crate) scheme: Scheme,
     pub(crate) original_uri: Uri,
     pub(crate) match_params: PathParams,
+    pub(crate) domain_labels: Vec<String>,
+    pub(crate) auto_options: bool,
     #[cfg(feature = "cookie")]
     pub(crate) cookie_jar: Option<CookieJar>,
     pub(crate) on_upgrade: Mutex<Option<OnUpgrade>>,
@@ -49,6 +51,8 @@ impl Default for RequestState {
             scheme: Scheme::HTTP,
             original_uri: Default::default(),
             match_params: vec![],
+            domain_labels: vec![],
+            auto_options: false,
             #[cfg(feature = "cookie")]
             cookie_jar: None,
             on_upgrade: Default::default(),
@@ -74,6 +78,21 @@ pub struct RequestParts {
     pub(crate) state: RequestState,
 }
 
+impl RequestParts {
+    /// Get a reference from extensions, similar to `self.extensions.get()`.
+    #[inline]
+    pub fn data<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.extensions.get()
+    }
+
+    /// Inserts a value to extensions, similar to
+    /// `self.extensions.insert(data)`.
+    #[inline]
+    pub fn set_data(&mut self, data: impl Send + Sync + 'static) {
+        self.extensions.insert(data);
+    }
+}
+
 impl Debug for RequestParts {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         f.debug_struct("RequestParts")
@@ -138,6 +157,8 @@ impl From<(http::Request<hyper::Body>, LocalAddr, RemoteAddr, Scheme)> for Reque
                 scheme,
                 original_uri: parts.uri,
                 match_params: Default::default(),
+                domain_labels: Default::default(),
+                auto_options: false,
                 #[cfg(feature = "cookie")]
                 cookie_jar: None,
                 on_upgrade,
@@ -613,12 +634,15 @@ impl RequestBuilder {
     pub fn body(self, body: impl Into<Body>) -> Request {
         Request {
             method: self.method,
-            uri: self.uri,
+            uri: self.uri.clone(),
             version: self.version,
             headers: self.headers,
             extensions: self.extensions,
             body: body.into(),
-            state: Default::default(),
+            state: RequestState {
+                original_uri: self.uri,
+                ..Default::default()
+            },
         }
     }
 
@@ -3,6 +3,8 @@ use std::{
     fmt::{self, Debug, Formatter},
 };
 
+use bytes::Bytes;
+use futures_util::Stream;
 use headers::HeaderMapExt;
 
 use crate::{
@@ -217,6 +219,17 @@ impl Response {
         self.body = body.into();
     }
 
+    /// Sets the body for this response from a stream of bytes, see
+    /// [`StreamBody`](crate::web::StreamBody).
+    pub fn set_body_stream<S, O, E>(&mut self, stream: S)
+    where
+        S: Stream<Item = Result<O, E>> + Send + 'static,
+        O: Into<Bytes> + 'static,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.body = Body::from_bytes_stream(stream);
+    }
+
     /// Take the body from this response and sets the body to empty.
     #[inline]
     pub fn take_body(&mut self) -> Body {
@@ -78,33 +78,60 @@ impl<T> Trie<T> {
         }
     }
 
-    pub(crate) fn matches(&self, domain: &str) -> Option<&T> {
+    /// Returns the data of the matched pattern, and the labels captured by
+    /// the wildcards in the order they appear in the pattern.
+    ///
+    /// A domain with an empty label, such as `a..com`, is not matched. An
+    /// empty domain only matches the `*` pattern and captures nothing.
+    pub(crate) fn matches<'a>(&self, domain: &'a str) -> Option<(&T, Vec<&'a str>)> {
         if domain.is_empty() {
-            return self.root.star_child.as_ref();
+            return self.root.star_child.as_ref().map(|data| (data, Vec::new()));
         }
         let segments = domain.split('.').rev().collect::<Vec<_>>();
-        Self::internal_matches(&segments, &self.root)
+        let mut captures = Vec::new();
+        let data = Self::internal_matches(domain, &segments, &self.root, &mut captures)?;
+        captures.reverse();
+        Some((data, captures))
     }
 
-    fn internal_matches<'a>(segments: &[&str], parent_node: &'a Node<T>) -> Option<&'a T> {
+    fn internal_matches<'a, 'b>(
+        domain: &'b str,
+        segments: &[&'b str],
+        parent_node: &'a Node<T>,
+        captures: &mut Vec<&'b str>,
+    ) -> Option<&'a T> {
         let (segment, tail) = match segments.split_first() {
             Some((segment, tail)) => (*segment, tail),
             None => return parent_node.data.as_ref(),
         };
 
         if let Some(node) = parent_node.named_children.get(segment) {
-            if let Some(data) = Self::internal_matches(tail, node) {
+            if let Some(data) = Self::internal_matches(domain, tail, node, captures) {
                 return Some(data);
             }
         }
 
+        if segment.is_empty() {
+            return None;
+        }
+
         if let Some(plus_child) = &parent_node.plus_child {
-            if let Some(data) = Self::internal_matches(tail, plus_child) {
+            captures.push(segment);
+            if let Some(data) = Self::internal_matches(domain, tail, plus_child, captures) {
                 return Some(data);
             }
+            captures.pop();
         }
 
-        if let Some(data) = &parent_node.star_child {
+        if let Some(data) = parent_node
+            .star_child
+            .as_ref()
+            .filter(|_| segments.iter().all(|segment| !segment.is_empty()))
+        {
+            // The remaining segments are the leftmost labels of the domain.
+            let len =
+                segments.iter().map(|segment| segment.len()).sum::<usize>() + segments.len() - 1;
+            captures.push(&domain[..len]);
             return Some(data);
         }
 
@@ -255,7 +282,30 @@ mod tests {
         ];
 
         for (domain, id) in matches {
-            assert_eq!(tree.matches(domain), id);
+            assert_eq!(tree.matches(domain).map(|(id, _)| id), id);
+        }
+    }
+
+    #[test]
+    fn test_captures() {
+        let mut tree = Trie::default();
+        tree.add("+.example.com", 1).unwrap();
+        tree.add("b.+.+.com", 2).unwrap();
+        tree.add("*.org", 3).unwrap();
+        tree.add("*", 4).unwrap();
+
+        let matches = vec![
+            ("a.example.com", Some((&1, vec!["a"]))),
+            ("b.c.d.com", Some((&2, vec!["c", "d"]))),
+            ("a.b.rust-lang.org", Some((&3, vec!["a.b.rust-lang"]))),
+            ("localhost", Some((&4, vec!["localhost"]))),
+            ("", Some((&4, vec![]))),
+            (".example.com", None),
+            ("a..org", None),
+        ];
+
+        for (domain, res) in matches {
+            assert_eq!(tree.matches(domain), res);
         }
     }
 }
@@ -1,12 +1,16 @@
-use std::{str::FromStr, sync::Arc};
+use std::{any::Any, str::FromStr, sync::Arc};
 
 use regex::Regex;
 
 use crate::{
     endpoint::BoxEndpoint,
     error::{NotFoundError, RouteError},
-    http::{uri::PathAndQuery, Uri},
-    route::{check_result, internal::radix_tree::RadixTree},
+    http::{header, uri::PathAndQuery, Method, StatusCode, Uri},
+    route::{
+        check_result,
+        internal::radix_tree::RadixTree,
+        router_method::{allow_header, RouteMethod},
+    },
     Endpoint, EndpointExt, IntoEndpoint, IntoResponse, Request, Response, Result,
 };
 
@@ -127,6 +131,48 @@ use crate::{
 /// # });
 /// ```
 ///
+/// # Route groups
+///
+/// A group of routes shares middleware by nesting a [`Route`] wrapped with
+/// [`EndpointExt::with`], the middleware only applies to the routes of the
+/// group.
+///
+/// ```
+/// use poem::{
+///     handler,
+///     http::{StatusCode, Uri},
+///     middleware::SetHeader,
+///     Endpoint, EndpointExt, Request, Route,
+/// };
+///
+/// #[handler]
+/// fn index() -> &'static str {
+///     "hello"
+/// }
+///
+/// let admin = Route::new()
+///     .at("/users", index)
+///     .at("/settings", index)
+///     .with(SetHeader::new().appending("x-admin", "true"));
+/// let app = Route::new().at("/", index).nest("/admin", admin);
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = app
+///     .call(
+///         Request::builder()
+///             .uri(Uri::from_static("/admin/users"))
+///             .finish(),
+///     )
+///     .await
+///     .unwrap();
+/// assert_eq!(resp.header("x-admin"), Some("true"));
+///
+/// let resp = app.call(Request::default()).await.unwrap();
+/// assert_eq!(resp.status(), StatusCode::OK);
+/// assert_eq!(resp.header("x-admin"), None);
+/// # });
+/// ```
+///
 /// # Nested no strip
 ///
 /// ```
@@ -159,6 +205,9 @@ use crate::{
 #[derive(Default)]
 pub struct Route {
     tree: RadixTree<BoxEndpoint<'static>>,
+    fallback: Option<BoxEndpoint<'static>>,
+    auto_options: bool,
+    methods: Vec<Method>,
 }
 
 impl Route {
@@ -187,8 +236,11 @@ impl Route {
         E: IntoEndpoint,
         E::Endpoint: 'static,
     {
+        let ep = ep.into_endpoint();
+        let methods = endpoint_methods(&ep);
         self.tree
             .add(&normalize_path(path.as_ref()), ep.map_to_response().boxed())?;
+        self.add_methods(methods);
         Ok(self)
     }
 
@@ -240,12 +292,132 @@ impl Route {
         self.internal_nest(&normalize_path(path.as_ref()), ep, false)
     }
 
+    /// Sets the endpoint to call when no route matches the request path,
+    /// instead of returning [`NotFoundError`].
+    ///
+    /// The fallback only runs for unmatched paths, so it never shadows the
+    /// registered routes. Use [`Request::original_uri`] to get the full path
+    /// of the request when the route is nested.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poem::{
+    ///     handler,
+    ///     http::{StatusCode, Uri},
+    ///     Endpoint, IntoResponse, Request, Route,
+    /// };
+    ///
+    /// #[handler]
+    /// fn index() -> &'static str {
+    ///     "index"
+    /// }
+    ///
+    /// #[handler]
+    /// fn not_found(req: &Request) -> impl IntoResponse {
+    ///     format!("{} is not found", req.original_uri().path()).with_status(StatusCode::NOT_FOUND)
+    /// }
+    ///
+    /// let app = Route::new().at("/", index).fallback(not_found);
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let resp = app
+    ///     .call(Request::builder().uri(Uri::from_static("/abc")).finish())
+    ///     .await
+    ///     .unwrap();
+    /// assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    /// assert_eq!(
+    ///     resp.into_body().into_string().await.unwrap(),
+    ///     "/abc is not found"
+    /// );
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn fallback<E>(mut self, ep: E) -> Self
+    where
+        E: IntoEndpoint,
+        E::Endpoint: 'static,
+    {
+        self.fallback = Some(ep.map_to_response().boxed());
+        self
+    }
+
+    /// If enabled, an `OPTIONS` request to a route created with
+    /// [`RouteMethod`] that does not handle `OPTIONS` gets a `204 No Content`
+    /// response whose `Allow` header lists the methods of the route, and an
+    /// `OPTIONS *` request gets a response that lists the methods of all the
+    /// routes, default is `false`.
+    ///
+    /// Only the methods of the routes that are added as a [`RouteMethod`] or a
+    /// nested [`Route`] without middleware are listed for `OPTIONS *`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poem::{
+    ///     get, handler,
+    ///     http::{Method, StatusCode, Uri},
+    ///     Endpoint, Request, Route,
+    /// };
+    ///
+    /// #[handler]
+    /// fn index() {}
+    ///
+    /// let app = Route::new()
+    ///     .at("/a", get(index).post(index))
+    ///     .at("/b", get(index).delete(index))
+    ///     .auto_options(true);
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let resp = app
+    ///     .get_response(
+    ///         Request::builder()
+    ///             .method(Method::OPTIONS)
+    ///             .uri(Uri::from_static("/a"))
+    ///             .finish(),
+    ///     )
+    ///     .await;
+    /// assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    /// assert_eq!(resp.header("allow"), Some("GET, POST, HEAD, OPTIONS"));
+    ///
+    /// let resp = app
+    ///     .get_response(
+    ///         Request::builder()
+    ///             .method(Method::OPTIONS)
+    ///             .uri(Uri::from_static("*"))
+    ///             .finish(),
+    ///     )
+    ///     .await;
+    /// assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    /// assert_eq!(
+    ///     resp.header("allow"),
+    ///     Some("GET, POST, DELETE, HEAD, OPTIONS")
+    /// );
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn auto_options(self, enable: bool) -> Self {
+        Self {
+            auto_options: enable,
+            ..self
+        }
+    }
+
+    fn add_methods(&mut self, methods: Vec<Method>) {
+        for method in methods {
+            if !self.methods.contains(&method) {
+                self.methods.push(method);
+            }
+        }
+    }
+
     fn internal_nest<E>(mut self, path: &str, ep: E, strip: bool) -> Result<Self, RouteError>
     where
         E: IntoEndpoint,
         E::Endpoint: 'static,
     {
         let ep = Arc::new(ep.into_endpoint());
+        let methods = endpoint_methods(&*ep);
         let mut path = path.to_string();
         if !path.ends_with('/') {
             path.push('/');
@@ -314,6 +486,7 @@ impl Route {
             }),
         )?;
 
+        self.add_methods(methods);
         Ok(self)
     }
 }
@@ -323,16 +496,40 @@ impl Endpoint for Route {
     type Output = Response;
 
     async fn call(&self, mut req: Request) -> Result<Self::Output> {
+        if self.auto_options {
+            if req.method() == Method::OPTIONS && req.uri().path() == "*" {
+                return Ok(Response::builder()
+                    .status(StatusCode::NO_CONTENT)
+                    .header(header::ALLOW, allow_header(&self.methods))
+                    .finish());
+            }
+            req.state_mut().auto_options = true;
+        }
+
         match self.tree.matches(req.uri().path()) {
             Some(matches) => {
                 req.state_mut().match_params.extend(matches.params);
                 matches.data.call(req).await
             }
-            None => Err(NotFoundError.into()),
+            None => match &self.fallback {
+                Some(fallback) => fallback.call(req).await,
+                None => Err(NotFoundError.into()),
+            },
         }
     }
 }
 
+/// Returns the methods of a [`RouteMethod`] or a [`Route`].
+fn endpoint_methods(ep: &dyn Any) -> Vec<Method> {
+    if let Some(route_method) = ep.downcast_ref::<RouteMethod>() {
+        route_method.registered_methods().cloned().collect()
+    } else if let Some(route) = ep.downcast_ref::<Route>() {
+        route.methods.clone()
+    } else {
+        Vec::new()
+    }
+}
+
 fn normalize_path(path: &str) -> String {
     let re = Regex::new("//+").unwrap();
     let mut path = re.replace_all(path, "/").to_string();
@@ -399,6 +596,41 @@ mod tests {
         assert_eq!(get(&r, "/api/inner/c").await, "/c");
     }
 
+    #[tokio::test]
+    async fn nested_group_middleware() {
+        let admin = Route::new()
+            .at("/a", h)
+            .at("/b", h)
+            .around(|ep, req| async move {
+                if req.header("authorization") != Some("admin") {
+                    return Err(crate::Error::from_status(StatusCode::UNAUTHORIZED));
+                }
+                ep.call(req).await
+            });
+        let r = Route::new().at("/a", h).nest("/admin", admin);
+
+        assert_eq!(get(&r, "/a").await, "/a");
+
+        for path in ["/admin/a", "/admin/b"] {
+            let err = r
+                .call(Request::builder().uri(Uri::from_static(path)).finish())
+                .await
+                .unwrap_err();
+            assert_eq!(err.as_response().status(), StatusCode::UNAUTHORIZED);
+
+            let resp = r
+                .call(
+                    Request::builder()
+                        .uri(Uri::from_static(path))
+                        .header("authorization", "admin")
+                        .finish(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), StatusCode::OK);
+        }
+    }
+
     #[tokio::test]
     async fn nested_no_strip() {
         let r = Route::new().nest_no_strip(
@@ -454,6 +686,35 @@ mod tests {
         assert_eq!(get(&r, "/a?a=1").await, "/?a=1");
     }
 
+    #[tokio::test]
+    async fn fallback() {
+        let r = Route::new()
+            .at("/a", h)
+            .at("/b/:id", h)
+            .nest(
+                "/c",
+                Route::new()
+                    .at("/d", h)
+                    .fallback(make_sync(|req| format!("c:{}", req.original_uri()))),
+            )
+            .fallback(make_sync(|req| format!("root:{}", req.uri())));
+
+        assert_eq!(get(&r, "/a").await, "/a");
+        assert_eq!(get(&r, "/b/1").await, "/b/1");
+        assert_eq!(get(&r, "/c/d").await, "/d");
+        assert_eq!(get(&r, "/c/e").await, "c:/c/e");
+        assert_eq!(get(&r, "/x/y").await, "root:/x/y");
+
+        let resp = Route::new()
+            .at("/a", h)
+            .call(Request::builder().uri(Uri::from_static("/b")).finish())
+            .await;
+        assert_eq!(
+            resp.unwrap_err().as_response().status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
     #[test]
     #[should_panic]
     fn duplicate_1() {
@@ -484,6 +745,48 @@ mod tests {
         let _ = Route::new().at("/a/*:v", h).at("/a/*", h);
     }
 
+    #[tokio::test]
+    async fn auto_options() {
+        let options = |uri: &'static str| {
+            Request::builder()
+                .method(Method::OPTIONS)
+                .uri(Uri::from_static(uri))
+                .finish()
+        };
+        let app = || {
+            Route::new()
+                .at("/a", crate::get(h).put(h))
+                .at("/b", crate::post(h).options(h))
+                .nest("/c", Route::new().at("/d", crate::delete(h)))
+        };
+
+        let resp = app().get_response(options("/a")).await;
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+        let resp = app().get_response(options("*")).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let app = app().auto_options(true);
+
+        let resp = app.get_response(options("/a")).await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(resp.header(header::ALLOW), Some("GET, PUT, HEAD, OPTIONS"));
+
+        let resp = app.get_response(options("/b")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "/b");
+
+        let resp = app.get_response(options("/c/d")).await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(resp.header(header::ALLOW), Some("DELETE, OPTIONS"));
+
+        let resp = app.get_response(options("*")).await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(
+            resp.header(header::ALLOW),
+            Some("GET, PUT, POST, OPTIONS, DELETE, HEAD")
+        );
+    }
+
     #[tokio::test]
     async fn issue_174() {
         let app = Route::new().nest("/", make_sync(|_| "hello"));
@@ -8,6 +8,12 @@ use crate::{
 
 /// Routing object for `HOST` header
 ///
+/// The port of the `HOST` header is ignored. A `+` in the pattern matches one
+/// label, and a `*` at the beginning matches one or more labels, the labels
+/// captured by them can be extracted with
+/// [`DomainLabels`](crate::web::DomainLabels). A pattern of `*` is the
+/// fallback for all other hosts.
+///
 /// # Errors
 ///
 /// - [`NotFoundError`]
@@ -93,14 +99,34 @@ impl Endpoint for RouteDomain {
             .headers()
             .get(header::HOST)
             .and_then(|host| host.to_str().ok())
+            .map(strip_port)
+            // A fully qualified domain name may end with the root label.
+            .map(|host| host.strip_suffix('.').unwrap_or(host))
             .unwrap_or_default();
         match self.tree.matches(host) {
-            Some(ep) => ep.call(req).await,
+            Some((ep, labels)) => {
+                let labels = labels.into_iter().map(ToString::to_string).collect();
+                let mut req = req;
+                req.state_mut().domain_labels = labels;
+                ep.call(req).await
+            }
             None => Err(NotFoundError.into()),
         }
     }
 }
 
+fn strip_port(host: &str) -> &str {
+    if host.starts_with('[') {
+        // IPv6 address, such as `[::1]:8080`.
+        match host.find(']') {
+            Some(idx) => &host[..=idx],
+            None => host,
+        }
+    } else {
+        host.split(':').next().unwrap_or_default()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,6 +173,33 @@ mod tests {
         check(&r, "abc.com", "4").await;
         check(&r, "rust-lang.org", "5").await;
         check(&r, "", "5").await;
+        check(&r, "example.com.", "1").await;
+        check(&r, "www.abc.com.:8080", "3").await;
+        check(&r, "rust-lang.org.", "5").await;
+    }
+
+    #[tokio::test]
+    async fn domain_labels() {
+        use crate::web::DomainLabels;
+
+        #[handler(internal)]
+        fn labels(DomainLabels(labels): DomainLabels) -> String {
+            labels.join(",")
+        }
+
+        let r = RouteDomain::new()
+            .at("example.com", make_sync(|_| "exact"))
+            .at("+.+.example.com", labels)
+            .at("*.example.com", labels)
+            .at("*", make_sync(|_| "default"));
+
+        check(&r, "example.com", "exact").await;
+        check(&r, "example.com:8080", "exact").await;
+        check(&r, "a.b.example.com", "a,b").await;
+        check(&r, "acme.example.com:8080", "acme").await;
+        check(&r, "a.b.c.example.com", "a.b.c").await;
+        check(&r, "[::1]:8080", "default").await;
+        check(&r, "rust-lang.org", "default").await;
     }
 
     #[tokio::test]
@@ -1,6 +1,8 @@
 use crate::{
-    endpoint::BoxEndpoint, error::MethodNotAllowedError, http::Method, Endpoint, EndpointExt,
-    IntoEndpoint, Request, Response, Result,
+    endpoint::BoxEndpoint,
+    error::MethodNotAllowedError,
+    http::{header, HeaderValue, Method, StatusCode},
+    Endpoint, EndpointExt, IntoEndpoint, Request, Response, Result,
 };
 
 /// Routing object for HTTP methods
@@ -52,6 +54,7 @@ use crate::{
 #[derive(Default)]
 pub struct RouteMethod {
     methods: Vec<(Method, BoxEndpoint<'static>)>,
+    auto_options: bool,
 }
 
 impl RouteMethod {
@@ -161,6 +164,65 @@ impl RouteMethod {
     {
         self.method(Method::TRACE, ep)
     }
+
+    /// If enabled, an `OPTIONS` request without a registered endpoint gets a
+    /// `204 No Content` response whose `Allow` header lists the registered
+    /// methods, default is `false`.
+    ///
+    /// It is also enabled by [`Route::auto_options`](crate::Route::auto_options)
+    /// for all the routes of the router.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use poem::{
+    ///     handler,
+    ///     http::{Method, StatusCode},
+    ///     Endpoint, Request, RouteMethod,
+    /// };
+    ///
+    /// #[handler]
+    /// fn index() {}
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let route_method = RouteMethod::new().get(index).post(index).auto_options(true);
+    ///
+    /// let resp = route_method
+    ///     .get_response(Request::builder().method(Method::OPTIONS).finish())
+    ///     .await;
+    /// assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+    /// assert_eq!(resp.header("allow"), Some("GET, POST, HEAD, OPTIONS"));
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn auto_options(self, enable: bool) -> Self {
+        Self {
+            auto_options: enable,
+            ..self
+        }
+    }
+
+    pub(crate) fn registered_methods(&self) -> impl Iterator<Item = &Method> {
+        self.methods.iter().map(|(method, _)| method)
+    }
+}
+
+/// Returns the `Allow` header for the registered methods, `HEAD` is allowed
+/// if `GET` is registered and `OPTIONS` is always allowed.
+pub(crate) fn allow_header<'a>(registered: impl IntoIterator<Item = &'a Method>) -> HeaderValue {
+    let mut methods = Vec::new();
+    for method in registered {
+        if !methods.contains(&method.as_str()) {
+            methods.push(method.as_str());
+        }
+    }
+    if methods.contains(&"GET") && !methods.contains(&"HEAD") {
+        methods.push("HEAD");
+    }
+    if !methods.contains(&"OPTIONS") {
+        methods.push("OPTIONS");
+    }
+    HeaderValue::from_str(&methods.join(", ")).expect("valid header value")
 }
 
 #[async_trait::async_trait]
@@ -182,6 +244,14 @@ impl Endpoint for RouteMethod {
                     resp.set_body(());
                     return Ok(resp);
                 }
+                if (self.auto_options || req.state().auto_options)
+                    && req.method() == Method::OPTIONS
+                {
+                    return Ok(Response::builder()
+                        .status(StatusCode::NO_CONTENT)
+                        .header(header::ALLOW, allow_header(self.registered_methods()))
+                        .finish());
+                }
                 Err(MethodNotAllowedError.into())
             }
         }
@@ -284,6 +354,41 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
     }
 
+    #[tokio::test]
+    async fn auto_options() {
+        #[handler(internal)]
+        fn index() -> &'static str {
+            "hello"
+        }
+
+        let options = || Request::builder().method(Method::OPTIONS).finish();
+
+        let resp = RouteMethod::new()
+            .get(index)
+            .put(index)
+            .get_response(options())
+            .await;
+        assert_eq!(resp.status(), StatusCode::METHOD_NOT_ALLOWED);
+
+        let resp = RouteMethod::new()
+            .get(index)
+            .put(index)
+            .auto_options(true)
+            .get_response(options())
+            .await;
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(resp.header(header::ALLOW), Some("GET, PUT, HEAD, OPTIONS"));
+
+        let resp = RouteMethod::new()
+            .post(index)
+            .options(index)
+            .auto_options(true)
+            .get_response(options())
+            .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "hello");
+    }
+
     #[tokio::test]
     async fn route_method() {
         #[handler(internal)]
@@ -2,23 +2,24 @@ use std::{
     convert::Infallible,
     future::Future,
     sync::{
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
 };
 
-use http::uri::Scheme;
+use http::{header, uri::Scheme, HeaderValue};
 use hyper::server::conn::Http;
 use tokio::{
     io::{AsyncRead, AsyncWrite, Result as IoResult},
-    sync::Notify,
+    sync::{Notify, OwnedSemaphorePermit, Semaphore},
     time::Duration,
 };
 
 use crate::{
+    error::{RejectionHandler, ServerError},
     listener::{Acceptor, AcceptorExt, Listener},
-    web::{LocalAddr, RemoteAddr},
-    Endpoint, EndpointExt, IntoEndpoint, Response,
+    web::{LocalAddr, RemoteAddr, TrustedProxies},
+    Endpoint, EndpointExt, Error, IntoEndpoint, Request, Response,
 };
 
 enum Either<L, A> {
@@ -30,6 +31,13 @@ enum Either<L, A> {
 pub struct Server<L, A> {
     listener: Either<L, A>,
     name: Option<String>,
+    trusted_proxies: Option<TrustedProxies>,
+    rejection_handler: Option<RejectionHandler>,
+    max_connections: Option<usize>,
+    reject_excess_connections: bool,
+    keep_alive_timeout: Option<Duration>,
+    max_requests_per_connection: Option<usize>,
+    http: Http,
 }
 
 impl<L: Listener> Server<L, Infallible> {
@@ -38,6 +46,13 @@ impl<L: Listener> Server<L, Infallible> {
         Self {
             listener: Either::Listener(listener),
             name: None,
+            trusted_proxies: None,
+            rejection_handler: None,
+            max_connections: None,
+            reject_excess_connections: false,
+            keep_alive_timeout: None,
+            max_requests_per_connection: None,
+            http: Http::new(),
         }
     }
 }
@@ -48,6 +63,13 @@ impl<A: Acceptor> Server<Infallible, A> {
         Self {
             listener: Either::Acceptor(acceptor),
             name: None,
+            trusted_proxies: None,
+            rejection_handler: None,
+            max_connections: None,
+            reject_excess_connections: false,
+            keep_alive_timeout: None,
+            max_requests_per_connection: None,
+            http: Http::new(),
         }
     }
 }
@@ -67,6 +89,117 @@ where
         }
     }
 
+    /// Specify the proxies whose forwarding headers are trusted, they are
+    /// added to the extensions of every request and consulted by
+    /// [`RealIp`](crate::web::RealIp).
+    #[must_use]
+    pub fn trusted_proxies(self, proxies: TrustedProxies) -> Self {
+        Self {
+            trusted_proxies: Some(proxies),
+            ..self
+        }
+    }
+
+    /// Specify the handler that formats the errors returned by the extractors
+    /// of the handlers, it is added to the extensions of every request.
+    ///
+    /// See [`RejectionHandler`] for more details.
+    #[must_use]
+    pub fn rejection_handler<F>(self, f: F) -> Self
+    where
+        F: Fn(Error, &Request) -> Response + Send + Sync + 'static,
+    {
+        Self {
+            rejection_handler: Some(RejectionHandler::new(f)),
+            ..self
+        }
+    }
+
+    /// Specify the maximum number of connections that are handled at the same
+    /// time.
+    ///
+    /// By default, the server stops accepting new connections until one of the
+    /// active connections is closed, use
+    /// [`Server::reject_excess_connections`] to close them immediately
+    /// instead.
+    #[must_use]
+    pub fn max_connections(self, max_connections: usize) -> Self {
+        Self {
+            max_connections: Some(max_connections),
+            ..self
+        }
+    }
+
+    /// Specify whether connections exceeding [`Server::max_connections`] are
+    /// closed immediately instead of waiting, default is `false`.
+    ///
+    /// The rejected connections are dropped right after being accepted,
+    /// without sending any response, and are only logged at the `debug`
+    /// level.
+    #[must_use]
+    pub fn reject_excess_connections(self, reject: bool) -> Self {
+        Self {
+            reject_excess_connections: reject,
+            ..self
+        }
+    }
+
+    /// Specify how long an idle HTTP/1 connection is kept open while waiting
+    /// for the next request.
+    ///
+    /// A zero duration disables keep-alive, every response is sent with
+    /// `Connection: close` and the connection is closed afterwards. The
+    /// HTTP/2 connections are not affected.
+    #[must_use]
+    pub fn keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.http.http1_keep_alive(!timeout.is_zero());
+        self.keep_alive_timeout = Some(timeout);
+        self
+    }
+
+    /// Specify the maximum number of requests handled by a HTTP/1
+    /// connection, the response of the last request is sent with
+    /// `Connection: close`.
+    ///
+    /// Zero disables keep-alive. The HTTP/2 connections are not affected.
+    #[must_use]
+    pub fn max_requests_per_connection(mut self, max: usize) -> Self {
+        self.max_requests_per_connection = Some(max);
+        self
+    }
+
+    /// Specify whether the connections only speak HTTP/2, default is `false`.
+    ///
+    /// Without TLS, the clients must use HTTP/2 over cleartext with prior
+    /// knowledge (h2c).
+    #[must_use]
+    pub fn http2_only(mut self, enable: bool) -> Self {
+        self.http.http2_only(enable);
+        self
+    }
+
+    /// Specify the initial window size of the HTTP/2 streams, in bytes.
+    #[must_use]
+    pub fn http2_initial_stream_window_size(mut self, size: u32) -> Self {
+        self.http.http2_initial_stream_window_size(size);
+        self
+    }
+
+    /// Specify the initial window size of the HTTP/2 connections, in bytes.
+    #[must_use]
+    pub fn http2_initial_connection_window_size(mut self, size: u32) -> Self {
+        self.http.http2_initial_connection_window_size(size);
+        self
+    }
+
+    /// Specify the maximum number of concurrent streams of a HTTP/2
+    /// connection.
+    #[must_use]
+    pub fn http2_max_concurrent_streams(mut self, max: u32) -> Self {
+        self.http.http2_max_concurrent_streams(max);
+        self
+    }
+
     /// Run this server.
     pub async fn run<E>(self, ep: E) -> IoResult<()>
     where
@@ -84,19 +217,90 @@ where
         signal: impl Future<Output = ()>,
         timeout: Option<Duration>,
     ) -> IoResult<()>
+    where
+        E: IntoEndpoint,
+        E::Endpoint: 'static,
+    {
+        self.try_run_with_graceful_shutdown(ep, signal, timeout)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Run this server, the errors of binding the listener or loading its TLS
+    /// config are returned as a [`ServerError`] instead of an IO error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use poem::{error::ServerError, handler, listener::TcpListener, Server};
+    ///
+    /// #[handler]
+    /// fn index() -> &'static str {
+    ///     "hello"
+    /// }
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// match Server::new(TcpListener::bind("127.0.0.1:3000"))
+    ///     .try_run(index)
+    ///     .await
+    /// {
+    ///     Ok(()) => {}
+    ///     Err(ServerError::AddrInUse(_)) => eprintln!("the port is already used"),
+    ///     Err(err) => eprintln!("{}", err),
+    /// }
+    /// # });
+    /// ```
+    pub async fn try_run<E>(self, ep: E) -> Result<(), ServerError>
+    where
+        E: IntoEndpoint,
+        E::Endpoint: 'static,
+    {
+        self.try_run_with_graceful_shutdown(ep, futures_util::future::pending(), None)
+            .await
+    }
+
+    /// Run this server and a signal to initiate graceful shutdown, the errors
+    /// of binding the listener or loading its TLS config are returned as a
+    /// [`ServerError`].
+    pub async fn try_run_with_graceful_shutdown<E>(
+        self,
+        ep: E,
+        signal: impl Future<Output = ()>,
+        timeout: Option<Duration>,
+    ) -> Result<(), ServerError>
     where
         E: IntoEndpoint,
         E::Endpoint: 'static,
     {
         let ep = Arc::new(ep.into_endpoint().map_to_response());
-        let Server { listener, name } = self;
+        let Server {
+            listener,
+            name,
+            trusted_proxies,
+            rejection_handler,
+            max_connections,
+            reject_excess_connections,
+            keep_alive_timeout,
+            max_requests_per_connection,
+            http,
+        } = self;
         let name = name.as_deref();
+        let connection_limit = max_connections.map(|max| (Arc::new(Semaphore::new(max)), max));
         let alive_connections = Arc::new(AtomicUsize::new(0));
         let notify = Arc::new(Notify::new());
         let timeout_notify = Arc::new(Notify::new());
+        let connection_options = ConnectionOptions {
+            http,
+            keep_alive_timeout,
+            max_requests: max_requests_per_connection,
+        };
 
         let mut acceptor = match listener {
-            Either::Listener(listener) => listener.into_acceptor().await?.boxed(),
+            Either::Listener(listener) => listener
+                .into_acceptor()
+                .await
+                .map_err(ServerError::bind)?
+                .boxed(),
             Either::Acceptor(acceptor) => acceptor.boxed(),
         };
 
@@ -127,25 +331,56 @@ where
                     }
                     break;
                 },
-                res = acceptor.accept() => {
-                    if let Ok((socket, local_addr, remote_addr, scheme)) = res {
+                res = async {
+                    // Wait for a free slot before accepting, so that the excess connections
+                    // are queued by the listener.
+                    let permit = match &connection_limit {
+                        Some((semaphore, max)) if !reject_excess_connections => {
+                            Some(acquire_connection_permit(semaphore, *max, name).await)
+                        }
+                        _ => None,
+                    };
+                    acceptor.accept().await.map(|conn| (conn, permit))
+                } => {
+                    if let Ok(((socket, local_addr, remote_addr, scheme), mut permit)) = res {
+                        if let (Some((semaphore, max)), true) = (&connection_limit, reject_excess_connections) {
+                            match semaphore.clone().try_acquire_owned() {
+                                Ok(p) => permit = Some(p),
+                                Err(_) => {
+                                    tracing::debug!(
+                                        name = name,
+                                        max_connections = max,
+                                        remote_addr = %remote_addr,
+                                        "connection limit reached, the connection is closed",
+                                    );
+                                    continue;
+                                }
+                            }
+                        }
+
                         let ep = ep.clone();
                         let alive_connections = alive_connections.clone();
                         let notify = notify.clone();
                         let timeout_notify = timeout_notify.clone();
+                        let connection_options = connection_options.clone();
+                        let extensions = RequestExtensions {
+                            trusted_proxies: trusted_proxies.clone(),
+                            rejection_handler: rejection_handler.clone(),
+                        };
 
                         tokio::spawn(async move {
                             alive_connections.fetch_add(1, Ordering::SeqCst);
 
                             if timeout.is_some() {
                                 tokio::select! {
-                                    _ = serve_connection(socket, local_addr, remote_addr, scheme, ep) => {}
+                                    _ = serve_connection(socket, local_addr, remote_addr, scheme, extensions, connection_options, ep) => {}
                                     _ = timeout_notify.notified() => {}
                                 }
                             } else {
-                                serve_connection(socket, local_addr, remote_addr, scheme, ep).await;
+                                serve_connection(socket, local_addr, remote_addr, scheme, extensions, connection_options, ep).await;
                             }
 
+                            drop(permit);
                             if alive_connections.fetch_sub(1, Ordering::SeqCst) == 1 {
                                 notify.notify_one();
                             }
@@ -166,31 +401,427 @@ where
     }
 }
 
+async fn acquire_connection_permit(
+    semaphore: &Arc<Semaphore>,
+    max_connections: usize,
+    name: Option<&str>,
+) -> OwnedSemaphorePermit {
+    match semaphore.clone().try_acquire_owned() {
+        Ok(permit) => permit,
+        Err(_) => {
+            tracing::debug!(
+                name = name,
+                max_connections = max_connections,
+                "connection limit reached, waiting for a connection to close",
+            );
+            semaphore
+                .clone()
+                .acquire_owned()
+                .await
+                .expect("the semaphore is never closed")
+        }
+    }
+}
+
+/// The options of every connection.
+#[derive(Clone)]
+struct ConnectionOptions {
+    http: Http,
+    keep_alive_timeout: Option<Duration>,
+    max_requests: Option<usize>,
+}
+
+impl ConnectionOptions {
+    fn max_requests(&self) -> Option<usize> {
+        match self.keep_alive_timeout {
+            Some(timeout) if timeout.is_zero() => Some(0),
+            _ => self.max_requests,
+        }
+    }
+
+    fn idle_timeout(&self) -> Option<Duration> {
+        self.keep_alive_timeout.filter(|timeout| !timeout.is_zero())
+    }
+}
+
+/// The values added to the extensions of every request.
+#[derive(Clone)]
+struct RequestExtensions {
+    trusted_proxies: Option<TrustedProxies>,
+    rejection_handler: Option<RejectionHandler>,
+}
+
 async fn serve_connection(
     socket: impl AsyncRead + AsyncWrite + Send + Unpin + 'static,
     local_addr: LocalAddr,
     remote_addr: RemoteAddr,
     scheme: Scheme,
+    extensions: RequestExtensions,
+    options: ConnectionOptions,
     ep: Arc<dyn Endpoint<Output = Response>>,
 ) {
+    let max_requests = options.max_requests();
+    let requests = Arc::new(AtomicUsize::new(0));
+    let pending_requests = Arc::new(AtomicUsize::new(0));
+    let activity = Arc::new(Notify::new());
+    let http2 = Arc::new(AtomicBool::new(false));
+
     let service = hyper::service::service_fn({
+        let pending_requests = pending_requests.clone();
+        let activity = activity.clone();
+        let http2 = http2.clone();
         move |req: hyper::Request<hyper::Body>| {
             let ep = ep.clone();
             let local_addr = local_addr.clone();
             let remote_addr = remote_addr.clone();
             let scheme = scheme.clone();
+            let extensions = extensions.clone();
+            let pending_requests = pending_requests.clone();
+            let activity = activity.clone();
+            // `Connection: close` and the keep-alive settings only exist in HTTP/1
+            let http1 = req.version() < http::Version::HTTP_2;
+            if !http1 {
+                http2.store(true, Ordering::SeqCst);
+            }
+            let close = http1
+                && max_requests
+                    .map(|max| requests.fetch_add(1, Ordering::SeqCst) + 1 >= max)
+                    .unwrap_or_default();
+            pending_requests.fetch_add(1, Ordering::SeqCst);
+            activity.notify_one();
+
             async move {
-                Ok::<http::Response<_>, Infallible>(
-                    ep.get_response((req, local_addr, remote_addr, scheme).into())
-                        .await
-                        .into(),
-                )
+                let mut req: Request = (req, local_addr, remote_addr, scheme).into();
+                if let Some(trusted_proxies) = extensions.trusted_proxies {
+                    req.extensions_mut().insert(trusted_proxies);
+                }
+                if let Some(rejection_handler) = extensions.rejection_handler {
+                    req.extensions_mut().insert(rejection_handler);
+                }
+                let mut resp = ep.get_response(req).await;
+                if close {
+                    // hyper closes the connection after writing this response
+                    resp.headers_mut()
+                        .insert(header::CONNECTION, HeaderValue::from_static("close"));
+                }
+
+                pending_requests.fetch_sub(1, Ordering::SeqCst);
+                activity.notify_one();
+                Ok::<http::Response<_>, Infallible>(resp.into())
             }
         }
     });
 
-    let conn = Http::new()
+    let conn = options
+        .http
         .serve_connection(socket, service)
         .with_upgrades();
+    tokio::pin!(conn);
+
+    let idle_timeout = match options.idle_timeout() {
+        Some(idle_timeout) => idle_timeout,
+        None => {
+            let _ = conn.await;
+            return;
+        }
+    };
+
+    loop {
+        if http2.load(Ordering::SeqCst) {
+            let _ = conn.await;
+            return;
+        }
+
+        let idle = pending_requests.load(Ordering::SeqCst) == 0;
+        tokio::select! {
+            _ = &mut conn => return,
+            _ = activity.notified() => {}
+            _ = tokio::time::sleep(idle_timeout), if idle => break,
+        }
+    }
+
+    // the connection is idle for too long, close it after the current response
+    conn.as_mut().graceful_shutdown();
     let _ = conn.await;
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpStream,
+    };
+
+    use super::*;
+    use crate::{
+        handler,
+        listener::{TcpAcceptor, TcpListener},
+        web::RealIp,
+    };
+
+    #[handler(internal)]
+    fn index(RealIp(ip): RealIp) -> String {
+        ip.map(|ip| ip.to_string()).unwrap_or_default()
+    }
+
+    async fn request_real_ip(trusted_proxies: Option<TrustedProxies>) -> String {
+        let acceptor = TcpListener::bind("127.0.0.1:0")
+            .into_acceptor()
+            .await
+            .unwrap();
+        let addr = *acceptor.local_addr()[0].as_socket_addr().unwrap();
+        let mut server = Server::new_with_acceptor(acceptor);
+        if let Some(trusted_proxies) = trusted_proxies {
+            server = server.trusted_proxies(trusted_proxies);
+        }
+        tokio::spawn(server.run(index));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nhost: localhost\r\nx-forwarded-for: 1.2.3.4\r\nconnection: close\r\n\r\n")
+            .await
+            .unwrap();
+        let mut resp = String::new();
+        stream.read_to_string(&mut resp).await.unwrap();
+        resp.rsplit("\r\n\r\n").next().unwrap().to_string()
+    }
+
+    #[handler(internal)]
+    fn hello() -> &'static str {
+        "hello"
+    }
+
+    async fn start_limited_server(reject: bool) -> std::net::SocketAddr {
+        let acceptor = TcpListener::bind("127.0.0.1:0")
+            .into_acceptor()
+            .await
+            .unwrap();
+        let addr = *acceptor.local_addr()[0].as_socket_addr().unwrap();
+        tokio::spawn(
+            Server::new_with_acceptor(acceptor)
+                .max_connections(1)
+                .reject_excess_connections(reject)
+                .run(hello),
+        );
+        addr
+    }
+
+    async fn send_request(stream: &mut TcpStream) -> IoResult<String> {
+        stream
+            .write_all(b"GET / HTTP/1.1\r\nhost: localhost\r\n\r\n")
+            .await?;
+        let mut resp = Vec::new();
+        let mut buf = [0; 1024];
+        while !resp.ends_with(b"hello") {
+            let n = stream.read(&mut buf).await?;
+            if n == 0 {
+                break;
+            }
+            resp.extend_from_slice(&buf[..n]);
+        }
+        Ok(String::from_utf8(resp).unwrap())
+    }
+
+    #[tokio::test]
+    async fn max_connections_reject() {
+        let addr = start_limited_server(true).await;
+
+        let mut first = TcpStream::connect(addr).await.unwrap();
+        assert!(send_request(&mut first).await.unwrap().ends_with("hello"));
+
+        // the second connection is closed without a response
+        let mut second = TcpStream::connect(addr).await.unwrap();
+        assert!(!send_request(&mut second)
+            .await
+            .map(|resp| resp.ends_with("hello"))
+            .unwrap_or_default());
+
+        drop(first);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let mut third = TcpStream::connect(addr).await.unwrap();
+        assert!(send_request(&mut third).await.unwrap().ends_with("hello"));
+    }
+
+    #[tokio::test]
+    async fn max_connections_queue() {
+        let addr = start_limited_server(false).await;
+
+        let mut first = TcpStream::connect(addr).await.unwrap();
+        assert!(send_request(&mut first).await.unwrap().ends_with("hello"));
+
+        // the second connection waits until the first one is closed
+        let mut second = TcpStream::connect(addr).await.unwrap();
+        let mut second = tokio::spawn(async move { send_request(&mut second).await.unwrap() });
+        assert!(
+            tokio::time::timeout(Duration::from_millis(100), &mut second)
+                .await
+                .is_err()
+        );
+
+        drop(first);
+        let resp = tokio::time::timeout(Duration::from_secs(5), second)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(resp.ends_with("hello"));
+    }
+
+    #[tokio::test]
+    async fn http2_only() {
+        let acceptor = TcpListener::bind("127.0.0.1:0")
+            .into_acceptor()
+            .await
+            .unwrap();
+        let addr = *acceptor.local_addr()[0].as_socket_addr().unwrap();
+        tokio::spawn(
+            Server::new_with_acceptor(acceptor)
+                .http2_only(true)
+                .http2_initial_stream_window_size(1024 * 1024)
+                .http2_initial_connection_window_size(4 * 1024 * 1024)
+                .http2_max_concurrent_streams(16)
+                .run(hello),
+        );
+
+        let client = hyper::Client::builder()
+            .http2_only(true)
+            .build_http::<hyper::Body>();
+        let resp = client
+            .get(format!("http://{}/", addr).parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(resp.version(), http::Version::HTTP_2);
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        assert_eq!(&body[..], b"hello");
+
+        // HTTP/1 requests are rejected
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        assert!(!send_request(&mut stream)
+            .await
+            .map(|resp| resp.ends_with("hello"))
+            .unwrap_or_default());
+    }
+
+    #[tokio::test]
+    async fn trusted_proxies() {
+        assert_eq!(
+            request_real_ip(Some(TrustedProxies::new(["127.0.0.0/8"]).unwrap())).await,
+            "1.2.3.4"
+        );
+        assert_eq!(request_real_ip(None).await, "127.0.0.1");
+    }
+
+    async fn start_keep_alive_server(
+        f: impl FnOnce(Server<Infallible, TcpAcceptor>) -> Server<Infallible, TcpAcceptor>,
+    ) -> std::net::SocketAddr {
+        let acceptor = TcpListener::bind("127.0.0.1:0")
+            .into_acceptor()
+            .await
+            .unwrap();
+        let addr = *acceptor.local_addr()[0].as_socket_addr().unwrap();
+        tokio::spawn(f(Server::new_with_acceptor(acceptor)).run(hello));
+        addr
+    }
+
+    async fn assert_closed(stream: &mut TcpStream) {
+        let mut buf = [0; 1024];
+        let n = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf))
+            .await
+            .unwrap()
+            .unwrap_or_default();
+        assert_eq!(n, 0);
+    }
+
+    #[tokio::test]
+    async fn keep_alive_disabled() {
+        let addr =
+            start_keep_alive_server(|server| server.keep_alive_timeout(Duration::ZERO)).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let resp = send_request(&mut stream).await.unwrap();
+        assert!(resp.contains("connection: close\r\n"));
+        assert!(resp.ends_with("hello"));
+        assert_closed(&mut stream).await;
+    }
+
+    #[tokio::test]
+    async fn keep_alive_timeout() {
+        let addr =
+            start_keep_alive_server(|server| server.keep_alive_timeout(Duration::from_millis(200)))
+                .await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let resp = send_request(&mut stream).await.unwrap();
+        assert!(!resp.contains("connection: close\r\n"));
+        assert!(resp.ends_with("hello"));
+
+        // the idle connection is closed after the timeout
+        assert_closed(&mut stream).await;
+    }
+
+    #[tokio::test]
+    async fn max_requests_per_connection() {
+        let addr = start_keep_alive_server(|server| server.max_requests_per_connection(2)).await;
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let resp = send_request(&mut stream).await.unwrap();
+        assert!(!resp.contains("connection: close\r\n"));
+        assert!(resp.ends_with("hello"));
+
+        let resp = send_request(&mut stream).await.unwrap();
+        assert!(resp.contains("connection: close\r\n"));
+        assert!(resp.ends_with("hello"));
+        assert_closed(&mut stream).await;
+
+        let addr = start_keep_alive_server(|server| server.max_requests_per_connection(0)).await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let resp = send_request(&mut stream).await.unwrap();
+        assert!(resp.contains("connection: close\r\n"));
+        assert_closed(&mut stream).await;
+    }
+
+    #[tokio::test]
+    async fn keep_alive_http2() {
+        let addr = start_keep_alive_server(|server| {
+            server
+                .http2_only(true)
+                .keep_alive_timeout(Duration::from_millis(200))
+                .max_requests_per_connection(1)
+        })
+        .await;
+
+        let client = hyper::Client::builder()
+            .http2_only(true)
+            .build_http::<hyper::Body>();
+        for _ in 0..2 {
+            let resp = client
+                .get(format!("http://{}/", addr).parse().unwrap())
+                .await
+                .unwrap();
+            assert_eq!(resp.version(), http::Version::HTTP_2);
+            assert!(!resp.headers().contains_key(header::CONNECTION));
+            tokio::time::sleep(Duration::from_millis(400)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn try_run_addr_in_use() {
+        let acceptor = TcpListener::bind("127.0.0.1:0")
+            .into_acceptor()
+            .await
+            .unwrap();
+        let addr = *acceptor.local_addr()[0].as_socket_addr().unwrap();
+
+        let err = Server::new(TcpListener::bind(addr))
+            .try_run(hello)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ServerError::AddrInUse(_)));
+
+        let err = Server::new(TcpListener::bind(addr))
+            .run(hello)
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse);
+        drop(acceptor);
+    }
+}
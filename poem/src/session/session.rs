@@ -8,7 +8,7 @@ use parking_lot::RwLock;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use crate::{FromRequest, Request, RequestBody, Result};
+use crate::{FromRequestParts, Request, Result};
 
 /// Status of the Session.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -148,8 +148,8 @@ impl Session {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a Session {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a Session {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req
             .extensions()
             .get::<Session>()
@@ -0,0 +1,209 @@
+use crate::{error::NotAcceptableError, http::header, FromRequestParts, Request, Result};
+
+/// An extractor that parses the `Accept` header and negotiates the response
+/// media type.
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     handler,
+///     http::{header, StatusCode},
+///     web::{Accept, Json},
+///     Endpoint, IntoResponse, Request, Response, Result,
+/// };
+///
+/// #[handler]
+/// fn index(accept: Accept) -> Result<Response> {
+///     Ok(match accept.negotiate(&["application/json", "text/plain"])? {
+///         "application/json" => Json("hello").into_response(),
+///         _ => "hello".into_response(),
+///     })
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = index
+///     .call(
+///         Request::builder()
+///             .header(header::ACCEPT, "text/*, application/json;q=0.5")
+///             .finish(),
+///     )
+///     .await
+///     .unwrap();
+/// assert_eq!(resp.into_body().into_string().await.unwrap(), "hello");
+///
+/// let err = index
+///     .call(Request::builder().header(header::ACCEPT, "image/png").finish())
+///     .await
+///     .unwrap_err();
+/// assert_eq!(err.as_response().status(), StatusCode::NOT_ACCEPTABLE);
+/// # });
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Accept {
+    ranges: Vec<(String, u16)>,
+}
+
+impl Accept {
+    /// Parses the value of an `Accept` header.
+    pub fn parse(value: &str) -> Self {
+        Self {
+            ranges: value.split(',').filter_map(parse_media_range).collect(),
+        }
+    }
+
+    /// Returns the media type from `offered` that is most preferred by the
+    /// client, or [`NotAcceptableError`] if none of them is acceptable.
+    ///
+    /// Media types with equal quality are resolved in the order of `offered`.
+    /// If the request does not have an `Accept` header, the first offered
+    /// type is returned.
+    pub fn negotiate<'b>(&self, offered: &[&'b str]) -> Result<&'b str, NotAcceptableError> {
+        let mut best: Option<(&'b str, u16)> = None;
+
+        for ty in offered {
+            let quality = match self.quality(ty) {
+                Some(quality) if quality > 0 => quality,
+                _ => continue,
+            };
+            if best.map(|(_, q)| quality > q).unwrap_or(true) {
+                best = Some((ty, quality));
+            }
+        }
+
+        best.map(|(ty, _)| ty).ok_or(NotAcceptableError)
+    }
+
+    fn quality(&self, ty: &str) -> Option<u16> {
+        if self.ranges.is_empty() {
+            return Some(1000);
+        }
+
+        let (main, sub) = ty.split_once('/')?;
+        let mut matched: Option<(u8, u16)> = None;
+
+        for (range, quality) in &self.ranges {
+            let (range_main, range_sub) = match range.split_once('/') {
+                Some(parts) => parts,
+                None => continue,
+            };
+            let specificity = match (range_main, range_sub) {
+                ("*", "*") => 0,
+                (m, "*") if m.eq_ignore_ascii_case(main) => 1,
+                (m, s) if m.eq_ignore_ascii_case(main) && s.eq_ignore_ascii_case(sub) => 2,
+                _ => continue,
+            };
+            if matched.map(|(s, _)| specificity > s).unwrap_or(true) {
+                matched = Some((specificity, *quality));
+            }
+        }
+
+        matched.map(|(_, quality)| quality)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequestParts<'a> for Accept {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
+        Ok(req
+            .headers()
+            .get_all(header::ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .map(Accept::parse)
+            .fold(Accept::default(), |mut acc, accept| {
+                acc.ranges.extend(accept.ranges);
+                acc
+            }))
+    }
+}
+
+fn parse_media_range(value: &str) -> Option<(String, u16)> {
+    let mut parts = value.split(';');
+    let range = parts.next()?.trim();
+    if range.is_empty() {
+        return None;
+    }
+    // A malformed quality is treated as `q=0`, so the range is not acceptable.
+    let quality = parts
+        .find_map(|param| {
+            let (name, q) = param.split_once('=')?;
+            (name.trim() == "q").then(|| parse_quality(q).unwrap_or_default())
+        })
+        .unwrap_or(1000);
+    Some((range.to_string(), quality))
+}
+
+fn parse_quality(value: &str) -> Option<u16> {
+    let q = value.trim().parse::<f32>().ok()?;
+    Some((q.clamp(0.0, 1.0) * 1000.0) as u16)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negotiate_quality() {
+        let accept = Accept::parse("text/html;q=0.5, application/json;q=0.8, text/plain");
+        assert_eq!(
+            accept.negotiate(&["text/html", "application/json", "text/plain"]),
+            Ok("text/plain")
+        );
+        assert_eq!(
+            accept.negotiate(&["text/html", "application/json"]),
+            Ok("application/json")
+        );
+        assert_eq!(accept.negotiate(&["text/html"]), Ok("text/html"));
+    }
+
+    #[test]
+    fn test_negotiate_wildcard() {
+        let accept = Accept::parse("application/*;q=0.5, */*;q=0.1, application/xml;q=0");
+        assert_eq!(
+            accept.negotiate(&["text/plain", "application/json"]),
+            Ok("application/json")
+        );
+        assert_eq!(
+            accept.negotiate(&["application/xml", "text/plain"]),
+            Ok("text/plain")
+        );
+
+        let accept = Accept::parse("*/*");
+        assert_eq!(
+            accept.negotiate(&["application/json", "text/plain"]),
+            Ok("application/json")
+        );
+    }
+
+    #[test]
+    fn test_negotiate_not_acceptable() {
+        let accept = Accept::parse("image/png, text/*;q=0");
+        assert_eq!(
+            accept.negotiate(&["text/plain", "application/json"]),
+            Err(NotAcceptableError)
+        );
+        assert_eq!(Accept::default().negotiate(&[]), Err(NotAcceptableError));
+    }
+
+    #[test]
+    fn test_negotiate_malformed_quality() {
+        let accept = Accept::parse("application/json;q=abc, text/plain;q=0.5");
+        assert_eq!(
+            accept.negotiate(&["application/json", "text/plain"]),
+            Ok("text/plain")
+        );
+        assert_eq!(
+            accept.negotiate(&["application/json"]),
+            Err(NotAcceptableError)
+        );
+    }
+
+    #[test]
+    fn test_negotiate_missing_header() {
+        assert_eq!(
+            Accept::default().negotiate(&["application/json", "text/plain"]),
+            Ok("application/json")
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+use crate::{
+    http::{header, HeaderValue},
+    Body, IntoResponse, Response,
+};
+
+/// The characters that are percent-encoded in the `filename*` parameter,
+/// everything except `attr-char` defined in RFC 5987.
+const FILENAME_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'!')
+    .remove(b'#')
+    .remove(b'$')
+    .remove(b'&')
+    .remove(b'+')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'^')
+    .remove(b'_')
+    .remove(b'`')
+    .remove(b'|')
+    .remove(b'~');
+
+/// A response that is downloaded as a file by the browser.
+///
+/// It sets the `Content-Disposition` header to `attachment`. A filename with
+/// non-ASCII characters is sent with the `filename*` parameter, as described
+/// in RFC 6266, together with an ASCII fallback.
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     get, handler,
+///     http::{header, StatusCode},
+///     web::Attachment,
+///     Endpoint, Request, Route,
+/// };
+///
+/// #[handler]
+/// async fn index() -> Attachment<&'static str> {
+///     Attachment::new("a,b,c")
+///         .filename("report.csv")
+///         .content_type("text/csv")
+/// }
+///
+/// let app = Route::new().at("/", get(index));
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = app.call(Request::default()).await.unwrap();
+/// assert_eq!(resp.status(), StatusCode::OK);
+/// assert_eq!(
+///     resp.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+///     "attachment; filename=\"report.csv\""
+/// );
+/// assert_eq!(resp.content_type(), Some("text/csv"));
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct Attachment<T> {
+    data: T,
+    filename: Option<String>,
+    content_type: Option<String>,
+}
+
+impl<T: Into<Body> + Send> Attachment<T> {
+    /// Create an attachment with the content, the content type defaults to
+    /// `application/octet-stream`.
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            filename: None,
+            content_type: None,
+        }
+    }
+
+    /// Sets the filename suggested to the browser.
+    #[must_use]
+    pub fn filename(self, filename: impl Into<String>) -> Self {
+        Self {
+            filename: Some(filename.into()),
+            ..self
+        }
+    }
+
+    /// Sets the content type of the attachment.
+    #[must_use]
+    pub fn content_type(self, content_type: impl Into<String>) -> Self {
+        Self {
+            content_type: Some(content_type.into()),
+            ..self
+        }
+    }
+}
+
+fn content_disposition(filename: Option<&str>) -> String {
+    let filename = match filename {
+        Some(filename) => filename,
+        None => return "attachment".to_string(),
+    };
+
+    let fallback = filename
+        .chars()
+        .map(|c| match c {
+            '"' | '\\' => '_',
+            c if c.is_ascii() && !c.is_ascii_control() => c,
+            _ => '_',
+        })
+        .collect::<String>();
+
+    if fallback == filename {
+        format!("attachment; filename=\"{}\"", filename)
+    } else {
+        format!(
+            "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+            fallback,
+            utf8_percent_encode(filename, FILENAME_ENCODE_SET)
+        )
+    }
+}
+
+impl<T: Into<Body> + Send> IntoResponse for Attachment<T> {
+    fn into_response(self) -> Response {
+        let content_disposition = content_disposition(self.filename.as_deref());
+        let content_disposition = match HeaderValue::from_str(&content_disposition) {
+            Ok(value) => value,
+            Err(_) => HeaderValue::from_static("attachment"),
+        };
+
+        Response::builder()
+            .header(header::CONTENT_DISPOSITION, content_disposition)
+            .content_type(
+                self.content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream"),
+            )
+            .body(self.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn disposition(resp: &Response) -> Option<&str> {
+        resp.headers()
+            .get(header::CONTENT_DISPOSITION)
+            .and_then(|value| value.to_str().ok())
+    }
+
+    #[tokio::test]
+    async fn without_filename() {
+        let resp = Attachment::new(vec![1u8, 2, 3]).into_response();
+        assert_eq!(disposition(&resp), Some("attachment"));
+        assert_eq!(resp.content_type(), Some("application/octet-stream"));
+        assert_eq!(resp.into_body().into_vec().await.unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn quoted_filename() {
+        let resp = Attachment::new("a")
+            .filename("my \"report\".csv")
+            .content_type("text/csv")
+            .into_response();
+        assert_eq!(
+            disposition(&resp),
+            Some(
+                "attachment; filename=\"my _report_.csv\"; filename*=UTF-8''my%20%22report%22.csv"
+            )
+        );
+        assert_eq!(resp.content_type(), Some("text/csv"));
+    }
+
+    #[test]
+    fn utf8_filename() {
+        let resp = Attachment::new("a")
+            .filename("报告 2022.csv")
+            .into_response();
+        assert_eq!(
+            disposition(&resp),
+            Some("attachment; filename=\"__ 2022.csv\"; filename*=UTF-8''%E6%8A%A5%E5%91%8A%202022.csv")
+        );
+    }
+}
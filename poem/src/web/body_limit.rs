@@ -0,0 +1,170 @@
+use std::ops::Deref;
+
+use bytes::{Bytes, BytesMut};
+use tokio::io::AsyncReadExt;
+
+use crate::{
+    error::{BodyLimitError, ReadBodyError},
+    http::header,
+    web::RequestBody,
+    FromRequest, Request, Result,
+};
+
+/// An extractor that reads the request body into [`Bytes`], and rejects the
+/// bodies that are larger than `N` bytes.
+///
+/// Unlike [`Body::into_bytes_limit`](crate::Body::into_bytes_limit), the
+/// error states both the actual size of the body and the limit. If the
+/// request has no `Content-Length` header, reading stops as soon as the body
+/// exceeds the limit, so the error only states that it is larger.
+///
+/// # Errors
+///
+/// - [`ReadBodyError`]
+/// - [`BodyLimitError`]
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     handler,
+///     http::{header, Method, StatusCode},
+///     post,
+///     web::BodyLimit,
+///     Endpoint, Request, Route,
+/// };
+///
+/// #[handler]
+/// async fn index(BodyLimit(data): BodyLimit<5>) -> String {
+///     format!("{} bytes", data.len())
+/// }
+///
+/// let app = Route::new().at("/", post(index));
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let mut resp = app
+///     .call(
+///         Request::builder()
+///             .method(Method::POST)
+///             .header(header::CONTENT_LENGTH, 6)
+///             .body("123456"),
+///     )
+///     .await
+///     .unwrap_err()
+///     .as_response();
+/// assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+/// assert_eq!(
+///     resp.take_body().into_string().await.unwrap(),
+///     "payload too large: the body is 6 bytes, the limit is 5 bytes"
+/// );
+/// # });
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BodyLimit<const N: usize>(pub Bytes);
+
+impl<const N: usize> Deref for BodyLimit<N> {
+    type Target = Bytes;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a, const N: usize> FromRequest<'a> for BodyLimit<N> {
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> Result<Self> {
+        let content_length = req
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<usize>().ok());
+        if let Some(content_length) = content_length.filter(|size| *size > N) {
+            return Err(BodyLimitError {
+                actual: Some(content_length),
+                limit: N,
+            }
+            .into());
+        }
+
+        let mut reader = body.take()?.into_async_read();
+        let mut buf = [0; 4096];
+        let mut data = BytesMut::new();
+
+        loop {
+            let sz = reader.read(&mut buf).await.map_err(ReadBodyError::Io)?;
+            if sz == 0 {
+                break;
+            }
+            if data.len() + sz > N {
+                return Err(BodyLimitError {
+                    actual: None,
+                    limit: N,
+                }
+                .into());
+            }
+            data.extend_from_slice(&buf[..sz]);
+        }
+
+        Ok(Self(data.freeze()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::stream;
+
+    use super::*;
+    use crate::{handler, http::StatusCode, Body, Endpoint};
+
+    #[handler(internal)]
+    fn index(BodyLimit(data): BodyLimit<10>) -> String {
+        String::from_utf8(data.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn within_limit() {
+        let resp = index
+            .call(Request::builder().body("0123456789"))
+            .await
+            .unwrap();
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "0123456789");
+    }
+
+    #[tokio::test]
+    async fn content_length_too_large() {
+        let err = index
+            .call(
+                Request::builder()
+                    .header(header::CONTENT_LENGTH, 12)
+                    .body("012345678901"),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<BodyLimitError>(),
+            Some(&BodyLimitError {
+                actual: Some(12),
+                limit: 10
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn streaming_body_too_large() {
+        let body = Body::from_bytes_stream(stream::iter(
+            (0..3).map(|_| Ok::<_, std::io::Error>(Bytes::from_static(b"0123456789"))),
+        ));
+        let err = index.call(Request::builder().body(body)).await.unwrap_err();
+        let resp = err.as_response();
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(
+            resp.headers()
+                .get(header::CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok()),
+            Some("49")
+        );
+        assert_eq!(
+            resp.into_body().into_string().await.unwrap(),
+            "payload too large: the body is more than 10 bytes"
+        );
+    }
+}
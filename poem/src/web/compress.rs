@@ -16,12 +16,15 @@ use crate::{
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum CompressionAlgo {
     /// brotli
+    #[cfg(feature = "compression-br")]
     BR,
 
     /// deflate
+    #[cfg(feature = "compression-deflate")]
     DEFLATE,
 
     /// gzip
+    #[cfg(feature = "compression-gzip")]
     GZIP,
 }
 
@@ -30,8 +33,11 @@ impl FromStr for CompressionAlgo {
 
     fn from_str(s: &str) -> std::prelude::rust_2015::Result<Self, Self::Err> {
         Ok(match s {
+            #[cfg(feature = "compression-br")]
             "br" => CompressionAlgo::BR,
+            #[cfg(feature = "compression-deflate")]
             "deflate" => CompressionAlgo::DEFLATE,
+            #[cfg(feature = "compression-gzip")]
             "gzip" => CompressionAlgo::GZIP,
             _ => return Err(()),
         })
@@ -41,8 +47,11 @@ impl FromStr for CompressionAlgo {
 impl CompressionAlgo {
     pub(crate) fn as_str(&self) -> &'static str {
         match self {
+            #[cfg(feature = "compression-br")]
             CompressionAlgo::BR => "br",
+            #[cfg(feature = "compression-deflate")]
             CompressionAlgo::DEFLATE => "deflate",
+            #[cfg(feature = "compression-gzip")]
             CompressionAlgo::GZIP => "gzip",
         }
     }
@@ -52,12 +61,15 @@ impl CompressionAlgo {
         reader: impl AsyncRead + Send + Unpin + 'a,
     ) -> Pin<Box<dyn AsyncRead + Send + 'a>> {
         match self {
+            #[cfg(feature = "compression-br")]
             CompressionAlgo::BR => Box::pin(async_compression::tokio::bufread::BrotliEncoder::new(
                 BufReader::new(reader),
             )),
+            #[cfg(feature = "compression-deflate")]
             CompressionAlgo::DEFLATE => Box::pin(
                 async_compression::tokio::bufread::DeflateEncoder::new(BufReader::new(reader)),
             ),
+            #[cfg(feature = "compression-gzip")]
             CompressionAlgo::GZIP => Box::pin(async_compression::tokio::bufread::GzipEncoder::new(
                 BufReader::new(reader),
             )),
@@ -69,12 +81,15 @@ impl CompressionAlgo {
         reader: impl AsyncRead + Send + Unpin + 'a,
     ) -> Pin<Box<dyn AsyncRead + Send + 'a>> {
         match self {
+            #[cfg(feature = "compression-br")]
             CompressionAlgo::BR => Box::pin(async_compression::tokio::bufread::BrotliDecoder::new(
                 BufReader::new(reader),
             )),
+            #[cfg(feature = "compression-deflate")]
             CompressionAlgo::DEFLATE => Box::pin(
                 async_compression::tokio::bufread::DeflateDecoder::new(BufReader::new(reader)),
             ),
+            #[cfg(feature = "compression-gzip")]
             CompressionAlgo::GZIP => Box::pin(async_compression::tokio::bufread::GzipDecoder::new(
                 BufReader::new(reader),
             )),
@@ -175,8 +190,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_compress() {
+        #[cfg(feature = "compression-br")]
         test_algo(CompressionAlgo::BR).await;
+        #[cfg(feature = "compression-deflate")]
         test_algo(CompressionAlgo::DEFLATE).await;
+        #[cfg(feature = "compression-gzip")]
         test_algo(CompressionAlgo::GZIP).await;
     }
 }
@@ -0,0 +1,168 @@
+use headers::{ETag, HeaderMapExt, IfMatch, IfNoneMatch};
+
+use crate::{error::ConditionalError, http::Method, FromRequestParts, Request, Result};
+
+/// An extractor for the `If-Match` and `If-None-Match` headers of conditional
+/// requests.
+///
+/// [`Conditional::check`] compares them with the current `ETag` of the
+/// resource, so that handlers can implement optimistic locking for unsafe
+/// methods and caching for `GET` and `HEAD`.
+///
+/// `If-Match` uses the strong comparison and `If-None-Match` uses the weak
+/// comparison, `*` matches any current representation.
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     handler,
+///     http::{header, StatusCode},
+///     web::{headers::ETag, Conditional},
+///     Endpoint, Request, Result,
+/// };
+///
+/// #[handler]
+/// fn index(cond: Conditional) -> Result<&'static str> {
+///     let etag: ETag = "\"v1\"".parse().unwrap();
+///     cond.check(Some(&etag))?;
+///     Ok("hello")
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let err = index
+///     .call(
+///         Request::builder()
+///             .header(header::IF_NONE_MATCH, "\"v1\"")
+///             .finish(),
+///     )
+///     .await
+///     .unwrap_err();
+/// assert_eq!(err.as_response().status(), StatusCode::NOT_MODIFIED);
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct Conditional {
+    method: Method,
+    if_match: Option<IfMatch>,
+    if_none_match: Option<IfNoneMatch>,
+}
+
+#[async_trait::async_trait]
+impl<'a> FromRequestParts<'a> for Conditional {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
+        Ok(Self {
+            method: req.method().clone(),
+            if_match: req.headers().typed_get::<IfMatch>(),
+            if_none_match: req.headers().typed_get::<IfNoneMatch>(),
+        })
+    }
+}
+
+impl Conditional {
+    /// Evaluates the preconditions against the current `ETag` of the
+    /// resource, `None` means the resource does not exist.
+    ///
+    /// Returns [`ConditionalError::NotModified`] if `If-None-Match` matches
+    /// for `GET` or `HEAD`, and [`ConditionalError::PreconditionFailed`] if
+    /// `If-Match` does not match or `If-None-Match` matches for other methods.
+    pub fn check(&self, etag: Option<&ETag>) -> Result<(), ConditionalError> {
+        if let Some(if_match) = &self.if_match {
+            let passed = match etag {
+                Some(etag) => if_match.precondition_passes(etag),
+                None => false,
+            };
+            if !passed {
+                return Err(ConditionalError::PreconditionFailed);
+            }
+        }
+
+        if let (Some(if_none_match), Some(etag)) = (&self.if_none_match, etag) {
+            if !if_none_match.precondition_passes(etag) {
+                return Err(
+                    if self.method == Method::GET || self.method == Method::HEAD {
+                        ConditionalError::NotModified(etag.clone())
+                    } else {
+                        ConditionalError::PreconditionFailed
+                    },
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::header;
+
+    async fn conditional(method: Method, name: &'static str, value: &'static str) -> Conditional {
+        let req = Request::builder()
+            .method(method)
+            .header(name, value)
+            .finish();
+        Conditional::from_request_parts(&req).await.unwrap()
+    }
+
+    fn etag(value: &str) -> ETag {
+        value.parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn get_if_none_match() {
+        let cond = conditional(Method::GET, "if-none-match", "\"a\", W/\"b\"").await;
+        assert_eq!(
+            cond.check(Some(&etag("\"b\""))),
+            Err(ConditionalError::NotModified(etag("\"b\"")))
+        );
+        assert_eq!(cond.check(Some(&etag("\"c\""))), Ok(()));
+        assert_eq!(cond.check(None), Ok(()));
+
+        let cond = conditional(Method::GET, "if-none-match", "*").await;
+        assert_eq!(
+            cond.check(Some(&etag("\"c\""))),
+            Err(ConditionalError::NotModified(etag("\"c\"")))
+        );
+
+        let resp =
+            crate::error::ResponseError::as_response(&ConditionalError::NotModified(etag("\"b\"")));
+        assert_eq!(resp.status(), crate::http::StatusCode::NOT_MODIFIED);
+        assert_eq!(
+            resp.headers()
+                .get(header::ETAG)
+                .and_then(|value| value.to_str().ok()),
+            Some("\"b\"")
+        );
+    }
+
+    #[tokio::test]
+    async fn put_if_match() {
+        let cond = conditional(Method::PUT, "if-match", "\"a\"").await;
+        assert_eq!(cond.check(Some(&etag("\"a\""))), Ok(()));
+        assert_eq!(
+            cond.check(Some(&etag("\"b\""))),
+            Err(ConditionalError::PreconditionFailed)
+        );
+        assert_eq!(
+            cond.check(Some(&etag("W/\"a\""))),
+            Err(ConditionalError::PreconditionFailed)
+        );
+        assert_eq!(cond.check(None), Err(ConditionalError::PreconditionFailed));
+
+        let cond = conditional(Method::PUT, "if-match", "*").await;
+        assert_eq!(cond.check(Some(&etag("\"b\""))), Ok(()));
+        assert_eq!(cond.check(None), Err(ConditionalError::PreconditionFailed));
+    }
+
+    #[tokio::test]
+    async fn put_if_none_match() {
+        let cond = conditional(Method::PUT, "if-none-match", "*").await;
+        assert_eq!(cond.check(None), Ok(()));
+        assert_eq!(
+            cond.check(Some(&etag("\"a\""))),
+            Err(ConditionalError::PreconditionFailed)
+        );
+    }
+}
@@ -16,7 +16,7 @@ use serde::{Deserialize, Serialize};
 use crate::{
     error::ParseCookieError,
     http::{header, HeaderMap},
-    FromRequest, Request, RequestBody, Result,
+    FromRequestParts, Request, Result,
 };
 
 /// The `SameSite` cookie attribute.
@@ -292,8 +292,8 @@ impl Cookie {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for Cookie {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for Cookie {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         let value = req
             .headers()
             .get(header::COOKIE)
@@ -491,8 +491,8 @@ impl FromStr for CookieJar {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a CookieJar {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a CookieJar {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req.cookie())
     }
 }
@@ -652,8 +652,7 @@ mod tests {
         let req = Request::builder()
             .header(header::COOKIE, Cookie::new_with_str("a", "1").to_string())
             .finish();
-        let (req, mut body) = req.split();
-        let cookie = Cookie::from_request(&req, &mut body).await.unwrap();
+        let cookie = Cookie::from_request_parts(&req).await.unwrap();
         assert_eq!(cookie.name(), "a");
         assert_eq!(cookie.value_str(), "1");
     }
@@ -2,7 +2,7 @@ use std::{ops::Deref, sync::Arc};
 
 use libcsrf::{AesGcmCsrfProtection, CsrfProtection, UnencryptedCsrfCookie};
 
-use crate::{FromRequest, Request, RequestBody, Result};
+use crate::{FromRequestParts, Request, Result};
 
 /// A CSRF Token for the next request.
 ///
@@ -20,8 +20,8 @@ impl Deref for CsrfToken {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a CsrfToken {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a CsrfToken {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req
             .extensions()
             .get::<CsrfToken>()
@@ -48,8 +48,8 @@ impl CsrfVerifier {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a CsrfVerifier {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a CsrfVerifier {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req
             .extensions()
             .get::<CsrfVerifier>()
@@ -1,9 +1,14 @@
 use std::ops::Deref;
 
-use crate::{error::GetDataError, FromRequest, Request, RequestBody, Result};
+use crate::{error::GetDataError, FromRequestParts, Request, Result};
 
 /// An extractor that can extract data from the request extension.
 ///
+/// The extensions belong to a single request, so a middleware can pass values
+/// such as the authenticated user down to the handlers with
+/// [`Request::set_data`], while [`AddData`](crate::middleware::AddData)
+/// inserts the same value into every request.
+///
 /// # Errors
 ///
 /// - [`GetDataError`]
@@ -38,8 +43,8 @@ impl<T> Deref for Data<T> {
 }
 
 #[async_trait::async_trait]
-impl<'a, T: Send + Sync + 'static> FromRequest<'a> for Data<&'a T> {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a, T: Send + Sync + 'static> FromRequestParts<'a> for Data<&'a T> {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(Data(
             req.extensions()
                 .get::<T>()
@@ -51,7 +56,9 @@ impl<'a, T: Send + Sync + 'static> FromRequest<'a> for Data<&'a T> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{handler, middleware::AddData, Endpoint, EndpointExt};
+    use crate::{
+        handler, http::StatusCode, middleware::AddData, Endpoint, EndpointExt, Error, IntoResponse,
+    };
 
     #[tokio::test]
     async fn test_data_extractor() {
@@ -81,6 +88,36 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_data_from_middleware() {
+        #[derive(Debug, Eq, PartialEq)]
+        struct User(String);
+
+        #[handler(internal)]
+        async fn index(user: Data<&User>) -> String {
+            user.0 .0.clone()
+        }
+
+        let app = index.before(|mut req: Request| async move {
+            let user = req
+                .header("authorization")
+                .map(|name| User(name.to_string()))
+                .ok_or_else(|| Error::from_status(StatusCode::UNAUTHORIZED))?;
+            req.set_data(user);
+            Ok(req)
+        });
+
+        let resp = app
+            .call(Request::builder().header("authorization", "sunli").finish())
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "sunli");
+
+        let err = app.call(Request::default()).await.unwrap_err();
+        assert_eq!(err.as_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test]
     async fn test_data_extractor_deref() {
         #[handler(internal)]
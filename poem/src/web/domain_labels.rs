@@ -0,0 +1,45 @@
+use crate::{FromRequestParts, Request, Result};
+
+/// An extractor for the labels of the `HOST` header captured by the wildcards
+/// of [`RouteDomain`](crate::RouteDomain), in the order they appear in the
+/// pattern.
+///
+/// A `+` captures one label, and a `*` captures all the labels it matches, so
+/// `a.b.example.com` matched by `*.example.com` is captured as `a.b`. It is
+/// empty if the request is not routed by [`RouteDomain`](crate::RouteDomain).
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     handler, http::header, web::DomainLabels, Endpoint, Request, RouteDomain,
+/// };
+///
+/// #[handler]
+/// fn tenant(DomainLabels(labels): DomainLabels) -> String {
+///     labels.join(",")
+/// }
+///
+/// let app = RouteDomain::new().at("+.example.com", tenant);
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = app
+///     .call(
+///         Request::builder()
+///             .header(header::HOST, "acme.example.com")
+///             .finish(),
+///     )
+///     .await
+///     .unwrap();
+/// assert_eq!(resp.into_body().into_string().await.unwrap(), "acme");
+/// # });
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct DomainLabels(pub Vec<String>);
+
+#[async_trait::async_trait]
+impl<'a> FromRequestParts<'a> for DomainLabels {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
+        Ok(DomainLabels(req.state().domain_labels.clone()))
+    }
+}
@@ -4,8 +4,10 @@ use http::StatusCode;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    error::ParseJsonError, http::header, web::RequestBody, FromRequest, IntoResponse, Request,
-    Response, Result,
+    error::{ParseJsonError, ReadBodyError},
+    http::header,
+    web::RequestBody,
+    FromRequest, IntoResponse, Request, Response, Result,
 };
 
 /// JSON extractor and response.
@@ -128,6 +130,71 @@ impl<T: Serialize + Send> IntoResponse for Json<T> {
     }
 }
 
+/// JSON extractor that rejects a body larger than `N` bytes.
+///
+/// The body is read with a bounded reader, so a request larger than the limit
+/// is rejected with `413 Payload Too Large` without buffering it. If the
+/// request has a `Content-Length` header, it is checked before reading the
+/// body. The limit is independent of the
+/// [`SizeLimit`](crate::middleware::SizeLimit) middleware.
+///
+/// # Errors
+///
+/// - [`ReadBodyError`]
+/// - [`ParseJsonError`]
+///
+/// # Example
+///
+/// ```
+/// use poem::{handler, post, web::JsonLimit, Route};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// #[handler]
+/// async fn index(JsonLimit(user): JsonLimit<User, 1024>) -> String {
+///     format!("welcome {}!", user.name)
+/// }
+///
+/// let app = Route::new().at("/", post(index));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct JsonLimit<T, const N: usize>(pub T);
+
+impl<T, const N: usize> Deref for JsonLimit<T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for JsonLimit<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a, T: DeserializeOwned, const N: usize> FromRequest<'a> for JsonLimit<T, N> {
+    async fn from_request(req: &'a Request, body: &mut RequestBody) -> Result<Self> {
+        let content_length = req
+            .headers()
+            .get(header::CONTENT_LENGTH)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<usize>().ok());
+        if matches!(content_length, Some(content_length) if content_length > N) {
+            return Err(ReadBodyError::PayloadTooLarge.into());
+        }
+
+        let data = body.take()?.into_bytes_limit(N).await?;
+        Ok(Self(serde_json::from_slice(&data).map_err(ParseJsonError)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
@@ -192,4 +259,40 @@ mod tests {
             }
         );
     }
+
+    #[tokio::test]
+    async fn test_json_limit() {
+        #[handler(internal)]
+        async fn index(JsonLimit(value): JsonLimit<CreateResource, 32>) -> String {
+            value.name
+        }
+
+        let under = r#"{"name":"abcdefghi","value":100}"#;
+        let over = r#"{"name":"abcdefghij","value":100}"#;
+        assert_eq!(under.len(), 32);
+        assert_eq!(over.len(), 33);
+
+        let resp = index
+            .call(Request::builder().method(Method::POST).body(under))
+            .await
+            .unwrap();
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "abcdefghi");
+
+        let err = index
+            .call(Request::builder().method(Method::POST).body(over))
+            .await
+            .unwrap_err();
+        assert_eq!(err.as_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let err = index
+            .call(
+                Request::builder()
+                    .method(Method::POST)
+                    .header(header::CONTENT_LENGTH, "33")
+                    .body(under),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.as_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
 }
@@ -1,24 +1,36 @@
 //! Commonly used as the type of extractor or response.
 
+mod accept;
 mod addr;
-#[cfg(feature = "compression")]
+mod attachment;
+mod body_limit;
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
 mod compress;
+mod conditional;
 #[cfg(feature = "cookie")]
 #[cfg_attr(docsrs, doc(cfg(feature = "cookie")))]
 pub mod cookie;
 mod data;
+mod domain_labels;
 mod form;
 mod json;
 #[cfg(feature = "multipart")]
 mod multipart;
 mod path;
 mod query;
+mod real_ip;
 mod redirect;
+mod service_unavailable;
 #[cfg(feature = "sse")]
 #[cfg_attr(docsrs, doc(cfg(feature = "sse")))]
 pub mod sse;
 #[cfg(feature = "static-files")]
 mod static_file;
+mod stream_body;
 #[cfg(feature = "tempfile")]
 mod tempfile;
 #[doc(inline)]
@@ -32,23 +44,35 @@ pub mod websocket;
 
 use std::{convert::Infallible, fmt::Debug};
 
+pub use accept::Accept;
 pub use addr::{LocalAddr, RemoteAddr};
+pub use attachment::Attachment;
+pub use body_limit::BodyLimit;
 use bytes::Bytes;
-#[cfg(feature = "compression")]
+#[cfg(any(
+    feature = "compression-gzip",
+    feature = "compression-deflate",
+    feature = "compression-br"
+))]
 pub use compress::{Compress, CompressionAlgo};
+pub use conditional::Conditional;
 #[cfg(feature = "csrf")]
 pub use csrf::{CsrfToken, CsrfVerifier};
 pub use data::Data;
+pub use domain_labels::DomainLabels;
 pub use form::Form;
-pub use json::Json;
+pub use json::{Json, JsonLimit};
 #[cfg(feature = "multipart")]
 pub use multipart::{Field, Multipart};
 pub use path::Path;
 pub(crate) use path::PathDeserializer;
 pub use query::Query;
+pub use real_ip::{InvalidCidrError, RealIp, TrustedProxies};
 pub use redirect::Redirect;
+pub use service_unavailable::ServiceUnavailable;
 #[cfg(feature = "static-files")]
 pub use static_file::{StaticFileRequest, StaticFileResponse};
+pub use stream_body::StreamBody;
 pub use typed_header::TypedHeader;
 
 #[cfg(feature = "tempfile")]
@@ -138,6 +162,18 @@ impl RequestBody {
 ///
 ///    Extracts the [`TypedHeader`] from the incoming request.
 ///
+/// - **Accept**
+///
+///    Extracts the [`Accept`] from the incoming request.
+///
+/// - **Conditional**
+///
+///    Extracts the [`Conditional`] from the incoming request.
+///
+/// - **RealIp**
+///
+///    Extracts the client address [`RealIp`] from the incoming request.
+///
 /// - **Path&lt;T>**
 ///
 ///    Extracts the [`Path`] from the incoming request.
@@ -233,7 +269,8 @@ impl RequestBody {
 /// # Create your own extractor
 ///
 /// The following is an example of a custom token extractor, which extracts the
-/// token from the `MyToken` header.
+/// token from the `MyToken` header. An extractor that never reads the body
+/// can implement [`FromRequestParts`] instead.
 ///
 /// ```
 /// use std::fmt::{self, Display, Formatter};
@@ -288,6 +325,69 @@ pub trait FromRequest<'a>: Sized {
     }
 }
 
+/// Represents an type that can be extracted from the request head, without
+/// the body.
+///
+/// Every type that implements `FromRequestParts` also implements
+/// [`FromRequest`], so a handler can have any number of these extractors, for
+/// example [`Path`], [`Query`] and [`TypedHeader`], together with at most one
+/// extractor that consumes the body, such as [`Json`]. A second extractor that
+/// consumes the body fails with [`ReadBodyError::BodyHasBeenTaken`].
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     handler, http::StatusCode, web::Json, Endpoint, Error, FromRequestParts, Request, Result,
+/// };
+///
+/// struct Token(String);
+///
+/// #[poem::async_trait]
+/// impl<'a> FromRequestParts<'a> for Token {
+///     async fn from_request_parts(req: &'a Request) -> Result<Self> {
+///         let token = req
+///             .header("MyToken")
+///             .ok_or_else(|| Error::from_string("missing token", StatusCode::BAD_REQUEST))?;
+///         Ok(Token(token.to_string()))
+///     }
+/// }
+///
+/// #[handler]
+/// async fn index(token: Token, Json(value): Json<i32>) -> String {
+///     format!("{}: {}", token.0, value)
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = index
+///     .call(
+///         Request::builder()
+///             .header("MyToken", "token123")
+///             .content_type("application/json")
+///             .body("100"),
+///     )
+///     .await
+///     .unwrap();
+/// assert_eq!(resp.into_body().into_string().await.unwrap(), "token123: 100");
+/// # });
+/// ```
+#[async_trait::async_trait]
+pub trait FromRequestParts<'a>: Sized {
+    /// Extract from request head.
+    async fn from_request_parts(req: &'a Request) -> Result<Self>;
+}
+
+#[async_trait::async_trait]
+impl<'a, T: FromRequestParts<'a>> FromRequest<'a> for T {
+    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+        T::from_request_parts(req).await
+    }
+
+    async fn from_request_without_body(req: &'a Request) -> Result<Self> {
+        T::from_request_parts(req).await
+    }
+}
+
 /// Represents a type that can convert into response.
 ///
 /// # Provided Implementations
@@ -659,36 +759,36 @@ impl<T: Into<String> + Send> IntoResponse for Html<T> {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a Request {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a Request {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req)
     }
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a Uri {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a Uri {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req.uri())
     }
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for Method {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for Method {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req.method().clone())
     }
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for Version {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for Version {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req.version())
     }
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a HeaderMap {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a HeaderMap {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(req.headers())
     }
 }
@@ -723,15 +823,15 @@ impl<'a> FromRequest<'a> for Vec<u8> {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a RemoteAddr {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a RemoteAddr {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(&req.state().remote_addr)
     }
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for &'a LocalAddr {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for &'a LocalAddr {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(&req.state().local_addr)
     }
 }
@@ -967,4 +1067,57 @@ mod tests {
             Bytes::from_static(b"abc")
         );
     }
+
+    #[tokio::test]
+    async fn parts_and_body_extractors() {
+        use std::collections::HashMap;
+
+        use crate::{handler, post, Endpoint, Route};
+
+        #[handler(internal)]
+        fn update(
+            Path(id): Path<u32>,
+            method: Method,
+            Query(params): Query<HashMap<String, String>>,
+            Json(value): Json<i32>,
+        ) -> String {
+            format!("{} {} {} {}", method, id, params["name"], value)
+        }
+
+        #[handler(internal)]
+        fn two_bodies(_a: String, _b: String) {}
+
+        let app = Route::new()
+            .at("/users/:id", post(update))
+            .at("/two", post(two_bodies));
+
+        let resp = app
+            .call(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri_str("/users/7?name=abc")
+                    .content_type("application/json")
+                    .body("100"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            resp.into_body().into_string().await.unwrap(),
+            "POST 7 abc 100"
+        );
+
+        let err = app
+            .call(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri_str("/two")
+                    .body("abc"),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ReadBodyError>().map(ToString::to_string),
+            Some(ReadBodyError::BodyHasBeenTaken.to_string())
+        );
+    }
 }
@@ -5,7 +5,7 @@ use std::ops::{Deref, DerefMut};
 pub(crate) use de::PathDeserializer;
 use serde::de::DeserializeOwned;
 
-use crate::{error::ParsePathError, FromRequest, Request, RequestBody, Result};
+use crate::{error::ParsePathError, FromRequestParts, Request, Result};
 
 /// An extractor that will get captures from the URL and parse them using
 /// `serde`.
@@ -138,8 +138,72 @@ impl<T: DeserializeOwned> Path<T> {
 }
 
 #[async_trait::async_trait]
-impl<'a, T: DeserializeOwned> FromRequest<'a> for Path<T> {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a, T: DeserializeOwned> FromRequestParts<'a> for Path<T> {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Self::internal_from_request(req).await.map_err(Into::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{handler, http::StatusCode, Endpoint, Route};
+
+    async fn call(app: &impl Endpoint, uri: &'static str) -> (StatusCode, String) {
+        let resp = app
+            .get_response(Request::builder().uri_str(uri).finish())
+            .await;
+        let status = resp.status();
+        (status, resp.into_body().into_string().await.unwrap())
+    }
+
+    #[tokio::test]
+    async fn tuple() {
+        #[handler(internal)]
+        fn index(Path((uid, pid)): Path<(u32, u32)>) -> String {
+            format!("{}:{}", uid, pid)
+        }
+
+        let app = Route::new().at("/users/:uid/posts/:pid", index);
+        assert_eq!(
+            call(&app, "/users/1/posts/2").await,
+            (StatusCode::OK, "1:2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn named_struct() {
+        #[derive(Deserialize)]
+        struct Params {
+            pid: u32,
+            uid: u32,
+        }
+
+        #[handler(internal)]
+        fn index(Path(Params { uid, pid }): Path<Params>) -> String {
+            format!("{}:{}", uid, pid)
+        }
+
+        let app = Route::new().at("/users/:uid/posts/:pid", index);
+        assert_eq!(
+            call(&app, "/users/1/posts/2").await,
+            (StatusCode::OK, "1:2".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn type_mismatch() {
+        #[handler(internal)]
+        fn index(Path((uid, pid)): Path<(u32, u32)>) -> String {
+            format!("{}:{}", uid, pid)
+        }
+
+        let app = Route::new().at("/users/:uid/posts/:pid", index);
+        assert_eq!(
+            call(&app, "/users/1/posts/abc").await.0,
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
@@ -0,0 +1,397 @@
+use std::collections::HashMap;
+
+use serde::{
+    de::{
+        value::{BorrowedStrDeserializer, Error},
+        DeserializeSeed, Error as _, MapAccess, SeqAccess, Visitor,
+    },
+    forward_to_deserialize_any, Deserializer,
+};
+
+/// Groups the decoded query pairs by key, keeping the order in which keys
+/// first appear.
+pub(crate) fn group_params(pairs: &[(String, String)]) -> Vec<(String, Vec<String>)> {
+    let mut params: Vec<(String, Vec<String>)> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for (key, value) in pairs {
+        match index.get(key.as_str()) {
+            Some(idx) => params[*idx].1.push(value.clone()),
+            None => {
+                index.insert(key, params.len());
+                params.push((key.clone(), vec![value.clone()]));
+            }
+        }
+    }
+    params
+}
+
+pub(crate) struct QueryDeserializer<'de> {
+    pairs: &'de [(String, String)],
+    params: &'de [(String, Vec<String>)],
+}
+
+impl<'de> QueryDeserializer<'de> {
+    #[inline]
+    pub(crate) fn new(
+        pairs: &'de [(String, String)],
+        params: &'de [(String, Vec<String>)],
+    ) -> Self {
+        QueryDeserializer { pairs, params }
+    }
+}
+
+impl<'de> Deserializer<'de> for QueryDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_map(MapDeserializer {
+            params: self.params,
+            current: None,
+        })
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(PairsDeserializer { pairs: self.pairs })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
+        byte_buf unit_struct tuple tuple_struct map struct enum
+        identifier ignored_any
+    }
+}
+
+/// Yields the query pairs in their original order, for example to deserialize
+/// a `Vec<(String, String)>`.
+struct PairsDeserializer<'de> {
+    pairs: &'de [(String, String)],
+}
+
+impl<'de> SeqAccess<'de> for PairsDeserializer<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.pairs.split_first() {
+            Some((pair, tail)) => {
+                self.pairs = tail;
+                Ok(Some(seed.deserialize(PairDeserializer { pair })?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.pairs.len())
+    }
+}
+
+struct PairDeserializer<'de> {
+    pair: &'de (String, String),
+}
+
+impl<'de> Deserializer<'de> for PairDeserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        let (key, value) = self.pair;
+        visitor.visit_seq(SeqDeserializer {
+            values: std::iter::once(key).chain(std::iter::once(value)),
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64 char str string bytes
+        byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+struct MapDeserializer<'de> {
+    params: &'de [(String, Vec<String>)],
+    current: Option<&'de (String, Vec<String>)>,
+}
+
+impl<'de> MapAccess<'de> for MapDeserializer<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.params.split_first() {
+            Some((param, tail)) => {
+                self.current = Some(param);
+                self.params = tail;
+                seed.deserialize(BorrowedStrDeserializer::new(&param.0))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.current.take() {
+            Some((key, values)) => seed
+                .deserialize(ValueDeserializer { values })
+                .map_err(|err| Error::custom(format!("invalid value for `{}`: {}", key, err))),
+            None => Err(Error::custom("value is missing")),
+        }
+    }
+}
+
+macro_rules! parse_value {
+    ($trait_fn:ident, $visit_fn:ident, $ty:literal) => {
+        fn $trait_fn<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: Visitor<'de>,
+        {
+            let value = self.single()?;
+            let v = value
+                .parse()
+                .map_err(|_| Error::custom(format!("can not parse `{}` to a `{}`", value, $ty)))?;
+            visitor.$visit_fn(v)
+        }
+    };
+}
+
+struct ValueDeserializer<'de> {
+    values: &'de [String],
+}
+
+impl<'de> ValueDeserializer<'de> {
+    /// Returns the value of a scalar, the last one wins if the key is
+    /// repeated.
+    fn single(&self) -> Result<&'de str, Error> {
+        match self.values.last() {
+            Some(value) => Ok(value),
+            None => Err(Error::custom("value is missing")),
+        }
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = Error;
+
+    parse_value!(deserialize_bool, visit_bool, "bool");
+    parse_value!(deserialize_i8, visit_i8, "i8");
+    parse_value!(deserialize_i16, visit_i16, "i16");
+    parse_value!(deserialize_i32, visit_i32, "i32");
+    parse_value!(deserialize_i64, visit_i64, "i64");
+    parse_value!(deserialize_u8, visit_u8, "u8");
+    parse_value!(deserialize_u16, visit_u16, "u16");
+    parse_value!(deserialize_u32, visit_u32, "u32");
+    parse_value!(deserialize_u64, visit_u64, "u64");
+    parse_value!(deserialize_f32, visit_f32, "f32");
+    parse_value!(deserialize_f64, visit_f64, "f64");
+    parse_value!(deserialize_char, visit_char, "char");
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.values {
+            [value] => visitor.visit_borrowed_str(value),
+            _ => self.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_borrowed_str(self.single()?)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_borrowed_bytes(self.single()?.as_bytes())
+    }
+
+    fn deserialize_byte_buf<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_seq(SeqDeserializer {
+            values: self.values.iter(),
+        })
+    }
+
+    fn deserialize_tuple<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::custom("unsupported type: map"))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        _fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        Err(Error::custom("unsupported type: struct"))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_enum(BorrowedStrDeserializer::new(self.single()?))
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+}
+
+struct SeqDeserializer<I> {
+    values: I,
+}
+
+impl<'de, I> SeqAccess<'de> for SeqDeserializer<I>
+where
+    I: Iterator<Item = &'de String>,
+{
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.values.next() {
+            Some(value) => Ok(Some(seed.deserialize(ValueDeserializer {
+                values: std::slice::from_ref(value),
+            })?)),
+            None => Ok(None),
+        }
+    }
+}
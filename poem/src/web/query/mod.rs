@@ -0,0 +1,271 @@
+mod de;
+
+use std::ops::{Deref, DerefMut};
+
+use serde::de::DeserializeOwned;
+
+use crate::{error::ParseQueryError, FromRequestParts, Request, Result};
+
+/// An extractor that can deserialize some type from query string.
+///
+/// Optional fields can be declared with `Option<T>`, and a key that appears
+/// more than once can be collected into a `Vec<T>`. If a repeated key is
+/// deserialized into a single value, the last one wins.
+///
+/// Arbitrary query parameters can be extracted into a map. With
+/// `Query<HashMap<String, String>>` a repeated key keeps its last value, while
+/// `Query<HashMap<String, Vec<String>>>` collects all the values of a
+/// repeated key in order.
+///
+/// The value is only deserialized, the validators of a `poem-openapi` object
+/// are not run, use `poem_openapi::param::QueryObject` to extract it instead.
+///
+/// # Errors
+///
+/// - [`ParseQueryError`]
+///
+/// # Example
+///
+/// ```
+/// use poem::{
+///     get, handler,
+///     http::{Method, StatusCode, Uri},
+///     web::Query,
+///     Endpoint, Request, Route,
+/// };
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct CreateDocument {
+///     title: String,
+///     content: String,
+/// }
+///
+/// #[handler]
+/// fn index(Query(CreateDocument { title, content }): Query<CreateDocument>) -> String {
+///     format!("{}:{}", title, content)
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let app = Route::new().at("/", get(index).post(index));
+///
+/// let resp = app
+///     .call(
+///         Request::builder()
+///             .uri(Uri::from_static("/?title=foo&content=bar"))
+///             .finish(),
+///     )
+///     .await
+///     .unwrap();
+/// assert_eq!(resp.status(), StatusCode::OK);
+/// assert_eq!(resp.into_body().into_string().await.unwrap(), "foo:bar");
+/// # });
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Query<T>(pub T);
+
+impl<T> Deref for Query<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Query<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: DeserializeOwned> Query<T> {
+    async fn internal_from_request(req: &Request) -> Result<Self, ParseQueryError> {
+        let pairs: Vec<(String, String)> =
+            serde_urlencoded::from_str(req.uri().query().unwrap_or_default())?;
+        let params = de::group_params(&pairs);
+        Ok(T::deserialize(de::QueryDeserializer::new(&pairs, &params)).map(Self)?)
+    }
+}
+
+#[async_trait::async_trait]
+impl<'a, T: DeserializeOwned> FromRequestParts<'a> for Query<T> {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
+        Self::internal_from_request(req).await.map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::{
+        handler,
+        http::{StatusCode, Uri},
+        Endpoint,
+    };
+
+    #[tokio::test]
+    async fn test_query_extractor() {
+        #[derive(Deserialize)]
+        struct CreateResource {
+            name: String,
+            value: i32,
+        }
+
+        #[handler(internal)]
+        async fn index(query: Query<CreateResource>) {
+            assert_eq!(query.name, "abc");
+            assert_eq!(query.value, 100);
+        }
+
+        index
+            .call(
+                Request::builder()
+                    .uri(Uri::from_static("/?name=abc&value=100"))
+                    .finish(),
+            )
+            .await
+            .unwrap();
+    }
+
+    #[derive(Debug, Deserialize, Eq, PartialEq)]
+    struct Params {
+        name: String,
+        value: i32,
+        #[serde(default)]
+        tags: Vec<String>,
+        limit: Option<u32>,
+    }
+
+    async fn parse(uri: &'static str) -> Result<Params, ParseQueryError> {
+        let req = Request::builder().uri(Uri::from_static(uri)).finish();
+        Query::<Params>::internal_from_request(&req)
+            .await
+            .map(|query| query.0)
+    }
+
+    #[tokio::test]
+    async fn test_query_repeated_keys() {
+        assert_eq!(
+            parse("/?name=abc&tags=a&value=1&tags=b&limit=5")
+                .await
+                .unwrap(),
+            Params {
+                name: "abc".to_string(),
+                value: 1,
+                tags: vec!["a".to_string(), "b".to_string()],
+                limit: Some(5),
+            }
+        );
+        assert_eq!(
+            parse("/?name=abc&value=1").await.unwrap(),
+            Params {
+                name: "abc".to_string(),
+                value: 1,
+                tags: vec![],
+                limit: None,
+            }
+        );
+        assert_eq!(
+            parse("/?name=abc&name=def&value=1").await.unwrap(),
+            Params {
+                name: "def".to_string(),
+                value: 1,
+                tags: vec![],
+                limit: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_pairs() {
+        let req = Request::builder()
+            .uri(Uri::from_static("/?a=1&b=2&a=3"))
+            .finish();
+        let Query(pairs) = Query::<Vec<(String, String)>>::internal_from_request(&req)
+            .await
+            .unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "2".to_string()),
+                ("a".to_string(), "3".to_string()),
+            ]
+        );
+
+        let Query(pairs) = Query::<Vec<(String, i32)>>::internal_from_request(&req)
+            .await
+            .unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("a".to_string(), 1),
+                ("b".to_string(), 2),
+                ("a".to_string(), 3)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_map() {
+        let req = Request::builder()
+            .uri(Uri::from_static("/?utm_source=a&b=1&tag=x&tag=y"))
+            .finish();
+
+        let Query(map) = Query::<HashMap<String, Vec<String>>>::internal_from_request(&req)
+            .await
+            .unwrap();
+        assert_eq!(map.len(), 3);
+        assert_eq!(map["utm_source"], vec!["a".to_string()]);
+        assert_eq!(map["b"], vec!["1".to_string()]);
+        assert_eq!(map["tag"], vec!["x".to_string(), "y".to_string()]);
+
+        let Query(map) = Query::<HashMap<String, String>>::internal_from_request(&req)
+            .await
+            .unwrap();
+        assert_eq!(
+            map,
+            [("utm_source", "a"), ("b", "1"), ("tag", "y")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        );
+
+        let req = Request::builder()
+            .uri(Uri::from_static("/?utm_source=a&b=1"))
+            .finish();
+        let Query(map) = Query::<HashMap<String, String>>::internal_from_request(&req)
+            .await
+            .unwrap();
+        assert_eq!(
+            map,
+            [("utm_source", "a"), ("b", "1")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_missing_field() {
+        let err = parse("/?name=abc").await.unwrap_err();
+        assert_eq!(err.to_string(), "missing field `value`");
+    }
+
+    #[tokio::test]
+    async fn test_query_type_mismatch() {
+        let err = parse("/?name=abc&value=abc").await.unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid value for `value`: can not parse `abc` to a `i32`"
+        );
+        assert_eq!(
+            crate::Error::from(err).as_response().status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
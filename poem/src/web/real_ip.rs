@@ -0,0 +1,248 @@
+use std::{net::IpAddr, str::FromStr, sync::Arc};
+
+use crate::{http::HeaderMap, FromRequestParts, Request, Result};
+
+/// An error that occurs when parsing a CIDR range.
+#[derive(Debug, thiserror::Error, Eq, PartialEq)]
+#[error("invalid CIDR range: {0}")]
+pub struct InvalidCidrError(pub String);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct IpRange {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl FromStr for IpRange {
+    type Err = InvalidCidrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || InvalidCidrError(s.to_string());
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, prefix_len)) => (
+                addr.trim().parse::<IpAddr>().map_err(|_| err())?,
+                Some(prefix_len.trim().parse::<u8>().map_err(|_| err())?),
+            ),
+            None => (s.trim().parse::<IpAddr>().map_err(|_| err())?, None),
+        };
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        let prefix_len = prefix_len.unwrap_or(max_len);
+        if prefix_len > max_len {
+            return Err(err());
+        }
+        Ok(Self { addr, prefix_len })
+    }
+}
+
+impl IpRange {
+    fn contains(&self, ip: IpAddr) -> bool {
+        match (self.addr, ip) {
+            (IpAddr::V4(range), IpAddr::V4(ip)) => {
+                prefix_eq(&range.octets(), &ip.octets(), self.prefix_len)
+            }
+            (IpAddr::V6(range), IpAddr::V6(ip)) => {
+                prefix_eq(&range.octets(), &ip.octets(), self.prefix_len)
+            }
+            // an IPv4-mapped IPv6 address, `::ffff:a.b.c.d`
+            (IpAddr::V4(range), IpAddr::V6(ip)) => match ip.segments() {
+                [0, 0, 0, 0, 0, 0xffff, hi, lo] => {
+                    let ip = [(hi >> 8) as u8, hi as u8, (lo >> 8) as u8, lo as u8];
+                    prefix_eq(&range.octets(), &ip, self.prefix_len)
+                }
+                _ => false,
+            },
+            (IpAddr::V6(_), IpAddr::V4(_)) => false,
+        }
+    }
+}
+
+fn prefix_eq(a: &[u8], b: &[u8], prefix_len: u8) -> bool {
+    let bytes = (prefix_len / 8) as usize;
+    let bits = prefix_len % 8;
+    if a[..bytes] != b[..bytes] {
+        return false;
+    }
+    if bits == 0 {
+        return true;
+    }
+    let mask = 0xffu8 << (8 - bits);
+    a[bytes] & mask == b[bytes] & mask
+}
+
+/// The ranges of proxies whose forwarding headers are trusted.
+///
+/// It is usually configured once with
+/// [`Server::trusted_proxies`](crate::Server::trusted_proxies), which stores
+/// it in the extensions of every request, so that [`RealIp`] can consult it.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies {
+    ranges: Arc<Vec<IpRange>>,
+}
+
+impl TrustedProxies {
+    /// Parses a list of CIDR ranges, such as `10.0.0.0/8` or `::1/128`. An
+    /// address without the prefix length matches only itself.
+    pub fn new<I, T>(cidrs: I) -> Result<Self, InvalidCidrError>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        Ok(Self {
+            ranges: Arc::new(
+                cidrs
+                    .into_iter()
+                    .map(|cidr| cidr.as_ref().parse())
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+
+    /// Returns `true` if the address belongs to one of the trusted ranges.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        self.ranges.iter().any(|range| range.contains(ip))
+    }
+
+    /// Returns the client address for a request received from `peer`.
+    ///
+    /// If the peer is trusted, the `X-Forwarded-For` header is walked from
+    /// right to left and the first untrusted address is returned, the
+    /// `X-Real-IP` header is used if there is no `X-Forwarded-For` header.
+    /// Otherwise the address of the peer is returned.
+    pub fn client_ip(&self, peer: Option<IpAddr>, headers: &HeaderMap) -> Option<IpAddr> {
+        let peer = peer?;
+        if !self.contains(peer) {
+            return Some(peer);
+        }
+
+        let forwarded = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(|ip| ip.trim().parse::<IpAddr>().ok())
+            .collect::<Option<Vec<_>>>();
+        match forwarded {
+            Some(forwarded) if !forwarded.is_empty() => Some(
+                forwarded
+                    .iter()
+                    .rev()
+                    .copied()
+                    .find(|ip| !self.contains(*ip))
+                    .unwrap_or(forwarded[0]),
+            ),
+            Some(_) => headers
+                .get("x-real-ip")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok())
+                .or(Some(peer)),
+            None => Some(peer),
+        }
+    }
+}
+
+/// An extractor for the address of the client.
+///
+/// The forwarding headers are only consulted if the request is received from
+/// one of the [`TrustedProxies`] configured with
+/// [`Server::trusted_proxies`](crate::Server::trusted_proxies), otherwise it
+/// is the address of the remote peer. It is `None` if the remote peer does
+/// not have an IP address, for example a unix socket.
+///
+/// # Example
+///
+/// ```no_run
+/// use poem::{
+///     handler,
+///     listener::TcpListener,
+///     web::{RealIp, TrustedProxies},
+///     Route, Server,
+/// };
+///
+/// #[handler]
+/// fn index(RealIp(ip): RealIp) -> String {
+///     ip.map(|ip| ip.to_string()).unwrap_or_default()
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// Server::new(TcpListener::bind("127.0.0.1:3000"))
+///     .trusted_proxies(TrustedProxies::new(["10.0.0.0/8", "127.0.0.1"]).unwrap())
+///     .run(Route::new().at("/", index))
+///     .await
+/// # });
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RealIp(pub Option<IpAddr>);
+
+#[async_trait::async_trait]
+impl<'a> FromRequestParts<'a> for RealIp {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
+        let peer = req.remote_addr().as_socket_addr().map(|addr| addr.ip());
+        Ok(RealIp(match req.extensions().get::<TrustedProxies>() {
+            Some(proxies) => proxies.client_ip(peer, req.headers()),
+            None => peer,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(items: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in items {
+            headers.append(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn parse_ranges() {
+        let proxies = TrustedProxies::new(["10.0.0.0/8", "192.168.1.1", "fd00::/8"]).unwrap();
+        assert!(proxies.contains("10.1.2.3".parse().unwrap()));
+        assert!(!proxies.contains("11.0.0.1".parse().unwrap()));
+        assert!(proxies.contains("192.168.1.1".parse().unwrap()));
+        assert!(!proxies.contains("192.168.1.2".parse().unwrap()));
+        assert!(proxies.contains("fd12::1".parse().unwrap()));
+        assert!(proxies.contains("::ffff:10.0.0.1".parse().unwrap()));
+
+        assert_eq!(
+            TrustedProxies::new(["10.0.0.0/33"]).unwrap_err(),
+            InvalidCidrError("10.0.0.0/33".to_string())
+        );
+        assert!(TrustedProxies::new(["abc"]).is_err());
+    }
+
+    #[test]
+    fn client_ip() {
+        let proxies = TrustedProxies::new(["10.0.0.0/8"]).unwrap();
+        let peer = Some("10.0.0.1".parse().unwrap());
+
+        assert_eq!(
+            proxies.client_ip(
+                peer,
+                &headers(&[("x-forwarded-for", "1.1.1.1, 2.2.2.2, 10.0.0.2")])
+            ),
+            Some("2.2.2.2".parse().unwrap())
+        );
+        assert_eq!(
+            proxies.client_ip(peer, &headers(&[("x-forwarded-for", "10.0.0.3")])),
+            Some("10.0.0.3".parse().unwrap())
+        );
+        assert_eq!(
+            proxies.client_ip(peer, &headers(&[("x-real-ip", "3.3.3.3")])),
+            Some("3.3.3.3".parse().unwrap())
+        );
+        assert_eq!(
+            proxies.client_ip(peer, &headers(&[("x-forwarded-for", "garbage")])),
+            peer
+        );
+
+        let untrusted = Some("4.4.4.4".parse().unwrap());
+        assert_eq!(
+            proxies.client_ip(untrusted, &headers(&[("x-forwarded-for", "1.1.1.1")])),
+            untrusted
+        );
+        assert_eq!(proxies.client_ip(None, &HeaderMap::new()), None);
+    }
+}
@@ -1,12 +1,16 @@
-use std::fmt::Display;
+use std::{convert::TryFrom, fmt::Display};
 
 use crate::{
-    http::{header, StatusCode},
+    http::{header, HeaderValue, StatusCode},
     IntoResponse, Response,
 };
 
 /// A redirect response.
 ///
+/// The location can be an absolute URI or a relative reference. If it is
+/// empty or is not a valid header value, `500 Internal Server Error` is
+/// returned instead.
+///
 /// # Example
 ///
 /// ```
@@ -55,6 +59,14 @@ impl Redirect {
         }
     }
 
+    /// A simple `302` redirect to a different location.
+    pub fn found(uri: impl Display) -> Self {
+        Self {
+            status: StatusCode::FOUND,
+            uri: uri.to_string(),
+        }
+    }
+
     /// A simple `303` redirect to a different location.
     pub fn see_other(uri: impl Display) -> Self {
         Self {
@@ -74,8 +86,13 @@ impl Redirect {
 
 impl IntoResponse for Redirect {
     fn into_response(self) -> Response {
+        let location = match HeaderValue::try_from(self.uri) {
+            Ok(location) if !location.is_empty() => location,
+            _ => return StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+        };
+
         self.status
-            .with_header(header::LOCATION, self.uri)
+            .with_header(header::LOCATION, location)
             .into_response()
     }
 }
@@ -102,6 +119,30 @@ mod tests {
 
     test_redirect!(permanent, PERMANENT_REDIRECT);
     test_redirect!(moved_permanent, MOVED_PERMANENTLY);
+    test_redirect!(found, FOUND);
     test_redirect!(see_other, SEE_OTHER);
     test_redirect!(temporary, TEMPORARY_REDIRECT);
+
+    #[test]
+    fn relative_location() {
+        for uri in ["/login", "../a", "?page=2", "//example.com/"] {
+            let resp = Redirect::see_other(uri).into_response();
+            assert_eq!(resp.status(), StatusCode::SEE_OTHER);
+            assert_eq!(
+                resp.headers()
+                    .get(header::LOCATION)
+                    .and_then(|value| value.to_str().ok()),
+                Some(uri)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_location() {
+        for uri in ["", "https://example.com/\r\nSet-Cookie: a=b"] {
+            let resp = Redirect::see_other(uri).into_response();
+            assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+            assert!(resp.headers().get(header::LOCATION).is_none());
+        }
+    }
 }
@@ -0,0 +1,108 @@
+use std::time::{Duration, SystemTime};
+
+use headers::{HeaderMapExt, RetryAfter};
+
+use crate::{http::StatusCode, IntoResponse, Response};
+
+/// A `503 Service Unavailable` response, with an optional `Retry-After`
+/// header that tells the client when to try again.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use poem::{
+///     get, handler,
+///     http::{header, StatusCode},
+///     web::ServiceUnavailable,
+///     Endpoint, Request, Route,
+/// };
+///
+/// #[handler]
+/// async fn index() -> ServiceUnavailable {
+///     ServiceUnavailable::retry_after(Duration::from_secs(120))
+/// }
+///
+/// let app = Route::new().at("/", get(index));
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = app.call(Request::default()).await.unwrap();
+/// assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+/// assert_eq!(resp.headers().get(header::RETRY_AFTER).unwrap(), "120");
+/// # });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ServiceUnavailable {
+    retry_after: Option<RetryAfter>,
+}
+
+impl ServiceUnavailable {
+    /// A `503` response without the `Retry-After` header.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A `503` response that asks the client to retry after a delay, the
+    /// header is sent in seconds.
+    pub fn retry_after(delay: Duration) -> Self {
+        Self {
+            retry_after: Some(RetryAfter::delay(delay)),
+        }
+    }
+
+    /// A `503` response that asks the client to retry after a date, the
+    /// header is sent as an HTTP-date.
+    pub fn retry_at(date: SystemTime) -> Self {
+        Self {
+            retry_after: Some(RetryAfter::date(date)),
+        }
+    }
+}
+
+impl IntoResponse for ServiceUnavailable {
+    fn into_response(self) -> Response {
+        let mut resp = StatusCode::SERVICE_UNAVAILABLE.into_response();
+        if let Some(retry_after) = self.retry_after {
+            resp.headers_mut().typed_insert(retry_after);
+        }
+        resp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::header;
+
+    #[test]
+    fn without_retry_after() {
+        let resp = ServiceUnavailable::new().into_response();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(resp.headers().get(header::RETRY_AFTER).is_none());
+    }
+
+    #[test]
+    fn retry_after_delay() {
+        let resp = ServiceUnavailable::retry_after(Duration::from_secs(30)).into_response();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            resp.headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok()),
+            Some("30")
+        );
+    }
+
+    #[test]
+    fn retry_after_date() {
+        let date = SystemTime::UNIX_EPOCH + Duration::from_secs(784111777);
+        let resp = ServiceUnavailable::retry_at(date).into_response();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            resp.headers()
+                .get(header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok()),
+            Some("Sun, 06 Nov 1994 08:49:37 GMT")
+        );
+    }
+}
@@ -17,7 +17,7 @@ use mime::Mime;
 use tokio::{fs::File, io::AsyncReadExt};
 
 use crate::{
-    error::StaticFileError, Body, FromRequest, IntoResponse, Request, RequestBody, Response, Result,
+    error::StaticFileError, Body, FromRequestParts, IntoResponse, Request, Response, Result,
 };
 
 /// A response for static file extractor.
@@ -86,8 +86,8 @@ pub struct StaticFileRequest {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for StaticFileRequest {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for StaticFileRequest {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Ok(Self {
             if_match: req.headers().typed_get::<IfMatch>(),
             if_unmodified_since: req.headers().typed_get::<IfUnmodifiedSince>(),
@@ -302,9 +302,7 @@ mod tests {
     }
 
     async fn check_response(req: Request) -> Result<StaticFileResponse, StaticFileError> {
-        let static_file = StaticFileRequest::from_request_without_body(&req)
-            .await
-            .unwrap();
+        let static_file = StaticFileRequest::from_request_parts(&req).await.unwrap();
         static_file.create_response(Path::new("Cargo.toml"), false)
     }
 
@@ -422,7 +420,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_range_partial_content() {
-        let static_file = StaticFileRequest::from_request_without_body(
+        let static_file = StaticFileRequest::from_request_parts(
             &Request::builder()
                 .typed_header(Range::bytes(0..10).unwrap())
                 .finish(),
@@ -444,7 +442,7 @@ mod tests {
     async fn test_range_full_content() {
         let md = std::fs::metadata("Cargo.toml").unwrap();
 
-        let static_file = StaticFileRequest::from_request_without_body(
+        let static_file = StaticFileRequest::from_request_parts(
             &Request::builder()
                 .typed_header(Range::bytes(0..md.len()).unwrap())
                 .finish(),
@@ -466,7 +464,7 @@ mod tests {
     async fn test_range_413() {
         let md = std::fs::metadata("Cargo.toml").unwrap();
 
-        let static_file = StaticFileRequest::from_request_without_body(
+        let static_file = StaticFileRequest::from_request_parts(
             &Request::builder()
                 .typed_header(Range::bytes(0..md.len() + 1).unwrap())
                 .finish(),
@@ -0,0 +1,98 @@
+use bytes::Bytes;
+use futures_util::Stream;
+
+use crate::{Body, IntoResponse, Response};
+
+/// A response with a body produced by a stream of bytes.
+///
+/// The stream is only polled when the connection is ready to send more data,
+/// and the default content type is `application/octet-stream`.
+///
+/// # Example
+///
+/// ```
+/// use futures_util::stream;
+/// use poem::{handler, web::StreamBody, Endpoint, Request};
+///
+/// #[handler]
+/// fn index() -> StreamBody<impl futures_util::Stream<Item = Result<&'static str, std::io::Error>>> {
+///     StreamBody::new(stream::iter(vec![Ok("a"), Ok("b"), Ok("c")])).content_type("text/plain")
+/// }
+///
+/// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+/// let resp = index.call(Request::default()).await.unwrap();
+/// assert_eq!(resp.content_type(), Some("text/plain"));
+/// assert_eq!(resp.into_body().into_string().await.unwrap(), "abc");
+/// # });
+/// ```
+pub struct StreamBody<S> {
+    stream: S,
+    content_type: Option<String>,
+}
+
+impl<S> StreamBody<S> {
+    /// Create a response from a stream of bytes.
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            content_type: None,
+        }
+    }
+
+    /// Sets the content type of the response.
+    #[must_use]
+    pub fn content_type(self, content_type: impl Into<String>) -> Self {
+        Self {
+            content_type: Some(content_type.into()),
+            ..self
+        }
+    }
+}
+
+impl<S, O, E> IntoResponse for StreamBody<S>
+where
+    S: Stream<Item = Result<O, E>> + Send + 'static,
+    O: Into<Bytes> + 'static,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn into_response(self) -> Response {
+        Response::builder()
+            .content_type(
+                self.content_type
+                    .as_deref()
+                    .unwrap_or("application/octet-stream"),
+            )
+            .body(Body::from_bytes_stream(self.stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::stream;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn stream_body() {
+        let chunks = || {
+            stream::iter(vec![
+                Ok::<_, std::io::Error>(Bytes::from_static(b"a")),
+                Ok(Bytes::from_static(b"bc")),
+                Ok(Bytes::from_static(b"def")),
+            ])
+        };
+
+        let resp = StreamBody::new(chunks()).into_response();
+        assert_eq!(resp.content_type(), Some("application/octet-stream"));
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "abcdef");
+
+        let resp = StreamBody::new(chunks())
+            .content_type("text/plain")
+            .into_response();
+        assert_eq!(resp.content_type(), Some("text/plain"));
+
+        let mut resp = Response::default();
+        resp.set_body_stream(chunks());
+        assert_eq!(resp.into_body().into_string().await.unwrap(), "abcdef");
+    }
+}
@@ -2,7 +2,7 @@ use std::ops::{Deref, DerefMut};
 
 use headers::{Header, HeaderMapExt};
 
-use crate::{error::ParseTypedHeaderError, FromRequest, Request, RequestBody, Result};
+use crate::{error::ParseTypedHeaderError, FromRequestParts, Request, Result};
 
 /// An extractor that extracts a typed header value.
 ///
@@ -67,8 +67,8 @@ impl<T: Header> TypedHeader<T> {
 }
 
 #[async_trait::async_trait]
-impl<'a, T: Header> FromRequest<'a> for TypedHeader<T> {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a, T: Header> FromRequestParts<'a> for TypedHeader<T> {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Self::internal_from_request(req).await.map_err(Into::into)
     }
 }
@@ -97,8 +97,8 @@ mod tests {
 
     #[tokio::test]
     async fn test_typed_header_extractor_error() {
-        let (req, mut body) = Request::builder().body("abc").split();
-        let res = TypedHeader::<Host>::from_request(&req, &mut body).await;
+        let req = Request::builder().finish();
+        let res = TypedHeader::<Host>::from_request_parts(&req).await;
 
         match res.unwrap_err().downcast_ref::<ParseTypedHeaderError>() {
             Some(ParseTypedHeaderError::HeaderRequired(name)) if name == "host" => {}
@@ -10,7 +10,7 @@ use crate::{
         header::{self, HeaderValue},
         Method, StatusCode,
     },
-    Body, FromRequest, IntoResponse, OnUpgrade, Request, RequestBody, Response, Result,
+    Body, FromRequestParts, IntoResponse, OnUpgrade, Request, Response, Result,
 };
 
 /// An extractor that can accept websocket connections.
@@ -62,8 +62,8 @@ impl WebSocket {
 }
 
 #[async_trait::async_trait]
-impl<'a> FromRequest<'a> for WebSocket {
-    async fn from_request(req: &'a Request, _body: &mut RequestBody) -> Result<Self> {
+impl<'a> FromRequestParts<'a> for WebSocket {
+    async fn from_request_parts(req: &'a Request) -> Result<Self> {
         Self::internal_from_request(req).await.map_err(Into::into)
     }
 }